@@ -36,9 +36,215 @@ use legacy as macros;
 /// ```sh
 /// error: symbol `napi_register_module_v1` is already defined
 /// ```
+///
+/// With the `export-macro` feature of `neon`, the main function is optional and a second
+/// one instead throws an `Error` when the module is loaded.
 pub fn main(
     attr: proc_macro::TokenStream,
     item: proc_macro::TokenStream,
 ) -> proc_macro::TokenStream {
     macros::main(attr, item)
 }
+
+#[cfg(feature = "napi")]
+#[proc_macro_attribute]
+/// Defines a JavaScript class whose instances wrap a Rust struct.
+///
+/// The attribute is applied to both the `struct` and an `impl` block for it. On the
+/// `struct`, it declares a handle type named after the struct with a `Js` prefix, e.g.,
+/// `JsCounter` for `Counter`, which implements [`Class`] and can be exported with
+/// `ModuleContext::export_class`. The class and its `Symbol.toStringTag` are named
+/// after the struct.
+///
+/// In the `impl` block, each associated function becomes a member of the class:
+///
+/// * `#[neon(constructor)]` marks the function that creates the Rust data of an
+///   instance. It is called by `new` and is required.
+/// * A function taking `&self` or `&mut self` is a method of the prototype.
+/// * A function without a receiver is a static method of the class.
+/// * `#[neon(getter)]` and `#[neon(setter)]` mark the getter and setter of an accessor
+///   property. A setter takes the assigned value as its last argument and its name
+///   defaults to the function name without a `set_` prefix.
+/// * `#[neon(name = "...")]` overrides the JavaScript name of a member.
+/// * `#[neon(skip)]` excludes a function from the class.
+///
+/// Every member takes the context of the call by value. Methods and accessors borrow
+/// the instance for the duration of the call; if the instance is already borrowed
+/// incompatibly, e.g., by a method that called back into JavaScript, an `Error` is
+/// thrown instead.
+///
+/// ```ignore
+/// #[neon::class]
+/// pub struct Counter {
+///     count: f64,
+/// }
+///
+/// #[neon::class]
+/// impl Counter {
+///     #[neon(constructor)]
+///     fn new(mut cx: FunctionContext) -> NeonResult<Self> {
+///         let count = cx.argument::<JsNumber>(0)?.value(&mut cx);
+///         Ok(Counter { count })
+///     }
+///
+///     fn increment<'a>(&mut self, mut cx: FunctionContext<'a>) -> JsResult<'a, JsNumber> {
+///         self.count += 1.0;
+///         Ok(cx.number(self.count))
+///     }
+///
+///     #[neon(getter)]
+///     fn count<'a>(&self, mut cx: FunctionContext<'a>) -> JsResult<'a, JsNumber> {
+///         Ok(cx.number(self.count))
+///     }
+///
+///     #[neon(name = "fromString")]
+///     fn from_string(mut cx: FunctionContext) -> JsResult<JsCounter> {
+///         let count = cx.argument::<JsString>(0)?.value(&mut cx);
+///         let count = cx.number(count.parse::<f64>().unwrap_or(0.0));
+///         JsCounter::new(&mut cx, vec![count])
+///     }
+/// }
+///
+/// #[neon::main]
+/// fn main(mut cx: ModuleContext) -> NeonResult<()> {
+///     cx.export_class::<JsCounter>("Counter")
+/// }
+/// ```
+///
+/// [`Class`]: https://docs.rs/neon/latest/neon/object/trait.Class.html
+pub fn class(
+    attr: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    macros::class(attr, item)
+}
+
+#[cfg(feature = "napi")]
+#[proc_macro_attribute]
+/// Exports a function from the module under its own name, or under the name given with
+/// `#[neon::export(name = "...")]`.
+///
+/// Requires the `export-macro` feature of `neon`. Exported functions are collected from
+/// the whole crate when it is linked and are exported when the module is initialized,
+/// before the `#[neon::main]` function, if any, is called. Exports made by the main
+/// function take precedence.
+///
+/// ```ignore
+/// #[neon::export]
+/// fn hello(mut cx: FunctionContext) -> JsResult<JsString> {
+///     Ok(cx.string("hello node"))
+/// }
+///
+/// #[neon::export(name = "addOne")]
+/// fn add_one(mut cx: FunctionContext) -> JsResult<JsNumber> {
+///     let n = cx.argument::<JsNumber>(0)?.value(&mut cx);
+///     Ok(cx.number(n + 1.0))
+/// }
+/// ```
+///
+/// A function that does not take a `FunctionContext` by value may instead take Rust
+/// values, which are extracted from its arguments with `TryFromJs`, and return a Rust
+/// value, which is converted with `TryIntoJs`. It may take a `&mut FunctionContext` as
+/// its first parameter, which does not count as an argument.
+///
+/// ```ignore
+/// #[neon::export]
+/// fn add(a: f64, b: f64) -> f64 {
+///     a + b
+/// }
+///
+/// #[neon::export]
+/// fn describe<'a>(cx: &mut FunctionContext<'a>, name: String) -> JsResult<'a, JsString> {
+///     Ok(cx.string(format!("{} is {} characters long", name, name.len())))
+/// }
+/// ```
+///
+/// An `async fn` returns a `Promise`. Its arguments are extracted on the JavaScript
+/// thread and the future is spawned on the runtime of the module instance (see
+/// `neon::executor`), keeping the event loop alive until the promise is settled. The
+/// promise resolves with the output converted with `TryIntoJs`, or, if the function
+/// returns a `Result`, rejects with an `Error` whose message is the `Display` of an
+/// `Err`. A panic also rejects the promise with an `Error`. Async functions cannot take
+/// a context and require the `napi-6` and `channel-api` features.
+///
+/// ```ignore
+/// #[neon::export]
+/// async fn read_config(path: String) -> Result<String, std::io::Error> {
+///     std::fs::read_to_string(path)
+/// }
+/// ```
+pub fn export(
+    attr: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    macros::export(attr, item)
+}
+
+#[cfg(feature = "napi")]
+#[proc_macro_derive(FromJs, attributes(neon))]
+/// Implements `TryFromJs` for a struct or enum, converting it from a JavaScript value.
+///
+/// A struct with named fields is converted from an object, a tuple struct from an array
+/// and a struct with a single unnamed field from the value of that field. Properties
+/// are named after the fields in `camelCase`, which can be changed for the whole struct
+/// with `#[neon(rename_all = "...")]`, e.g., `"snake_case"` or `"kebab-case"`.
+///
+/// Fields accept the following attributes:
+///
+/// * `#[neon(name = "...")]` overrides the property name.
+/// * `#[neon(default)]` uses `Default::default()` if the property is `undefined`.
+/// * `#[neon(default = "path")]` calls the function at `path` instead.
+/// * `#[neon(skip)]` ignores the property and uses `Default::default()`.
+///
+/// Enums are externally tagged by default: a unit variant is converted from its name and
+/// any other variant from an object with a single property named after the variant,
+/// e.g., `{ "circle": 1 }`. Variants are named in `camelCase`, which can be changed with
+/// `#[neon(rename_all = "...")]` on the enum or `#[neon(name = "...")]` on a variant.
+/// `#[neon(tag = "type")]` converts an enum from an object whose `type` property names
+/// the variant and whose other properties are its fields. Adding `content = "value"`
+/// instead reads the fields from the `value` property.
+///
+/// Errors name the path of the value that could not be converted, e.g.,
+/// `argument 0.retry.maxAttempts: expected a number, found string`.
+///
+/// ```ignore
+/// #[derive(FromJs)]
+/// struct Options {
+///     name: String,
+///     #[neon(default)]
+///     max_attempts: u32,
+/// }
+///
+/// #[derive(FromJs)]
+/// #[neon(tag = "kind")]
+/// enum Shape {
+///     Circle { radius: f64 },
+///     Square { side: f64 },
+/// }
+/// ```
+pub fn from_js(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    macros::from_js(item)
+}
+
+#[cfg(feature = "napi")]
+#[proc_macro_derive(IntoJs, attributes(neon))]
+/// Implements `TryIntoJs` for a struct or enum, converting it to a JavaScript value.
+///
+/// The conversion is the reverse of `#[derive(FromJs)]` and accepts the same attributes.
+/// Skipped fields are not converted and `None` is converted to `null`.
+///
+/// ```ignore
+/// #[derive(IntoJs)]
+/// struct Point {
+///     x: f64,
+///     y: f64,
+/// }
+///
+/// #[neon::export]
+/// fn origin() -> Point {
+///     Point { x: 0.0, y: 0.0 }
+/// }
+/// ```
+pub fn into_js(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    macros::into_js(item)
+}
@@ -0,0 +1,414 @@
+use quote::{format_ident, quote};
+use syn::spanned::Spanned;
+
+/// The role of an associated function of a `#[neon::class]` impl block.
+#[derive(Clone, Copy, PartialEq)]
+enum Kind {
+    Constructor,
+    Method,
+    Static,
+    Getter,
+    Setter,
+    Skip,
+}
+
+/// How a member borrows the instance.
+#[derive(Clone, Copy)]
+enum Receiver {
+    Ref,
+    Mut,
+}
+
+struct Member {
+    kind: Kind,
+    /// Name of the member in JavaScript
+    name: String,
+    ident: syn::Ident,
+    receiver: Option<Receiver>,
+}
+
+pub(crate) fn class(
+    attr: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    if !attr.is_empty() {
+        let attr = proc_macro2::TokenStream::from(attr);
+        return syn::Error::new(attr.span(), "`#[neon::class]` does not take arguments")
+            .to_compile_error()
+            .into();
+    }
+
+    let result = match syn::parse_macro_input!(item as syn::Item) {
+        syn::Item::Struct(item) => class_struct(item),
+        syn::Item::Impl(item) => class_impl(item),
+        item => Err(syn::Error::new(
+            item.span(),
+            "`#[neon::class]` can only be applied to a `struct` and its `impl` block",
+        )),
+    };
+
+    result.unwrap_or_else(|err| err.to_compile_error()).into()
+}
+
+fn handle_ident(ident: &syn::Ident) -> syn::Ident {
+    format_ident!("Js{}", ident)
+}
+
+fn class_struct(item: syn::ItemStruct) -> syn::Result<proc_macro2::TokenStream> {
+    if !item.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            item.generics.span(),
+            "`#[neon::class]` does not support generic types",
+        ));
+    }
+
+    let vis = &item.vis;
+    let handle = handle_ident(&item.ident);
+    let doc = format!("A handle to an instance of the [`{}`] class.", item.ident);
+
+    Ok(quote! {
+        #item
+
+        #[doc = #doc]
+        #[derive(Clone, Copy)]
+        #[repr(C)]
+        #vis struct #handle(::neon::macro_internal::runtime::raw::Local);
+
+        impl ::neon::handle::Managed for #handle {
+            fn to_raw(self) -> ::neon::macro_internal::runtime::raw::Local {
+                self.0
+            }
+
+            fn from_raw(
+                _env: ::neon::macro_internal::Env,
+                raw: ::neon::macro_internal::runtime::raw::Local,
+            ) -> Self {
+                #handle(raw)
+            }
+        }
+    })
+}
+
+/// Removes the `#[neon(...)]` attributes of a function and parses them.
+fn member(method: &mut syn::ImplItemMethod) -> syn::Result<Member> {
+    let ident = method.sig.ident.clone();
+    let mut kind = None;
+    let mut name = None;
+
+    let (attrs, others) = method
+        .attrs
+        .drain(..)
+        .partition::<Vec<_>, _>(|attr| attr.path.is_ident("neon"));
+
+    method.attrs = others;
+
+    for attr in attrs {
+        let list = match attr.parse_meta()? {
+            syn::Meta::List(list) => list,
+            meta => return Err(syn::Error::new(meta.span(), "expected `#[neon(...)]`")),
+        };
+
+        for nested in list.nested {
+            match nested {
+                syn::NestedMeta::Meta(syn::Meta::Path(path)) => {
+                    let role = if path.is_ident("constructor") {
+                        Kind::Constructor
+                    } else if path.is_ident("getter") {
+                        Kind::Getter
+                    } else if path.is_ident("setter") {
+                        Kind::Setter
+                    } else if path.is_ident("skip") {
+                        Kind::Skip
+                    } else {
+                        return Err(syn::Error::new(path.span(), "unknown `neon` attribute"));
+                    };
+
+                    if kind.replace(role).is_some() {
+                        return Err(syn::Error::new(
+                            path.span(),
+                            "conflicting `neon` attributes",
+                        ));
+                    }
+                }
+                syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                    path,
+                    lit: syn::Lit::Str(lit),
+                    ..
+                })) if path.is_ident("name") => {
+                    name = Some(lit.value());
+                }
+                nested => {
+                    return Err(syn::Error::new(nested.span(), "unknown `neon` attribute"));
+                }
+            }
+        }
+    }
+
+    let receiver = match method.sig.receiver() {
+        Some(syn::FnArg::Receiver(syn::Receiver {
+            reference: Some(_),
+            mutability,
+            ..
+        })) => Some(if mutability.is_some() {
+            Receiver::Mut
+        } else {
+            Receiver::Ref
+        }),
+        Some(receiver) => {
+            return Err(syn::Error::new(
+                receiver.span(),
+                "`#[neon::class]` methods must take `&self` or `&mut self`",
+            ))
+        }
+        None => None,
+    };
+
+    let kind = match (kind, receiver) {
+        (Some(Kind::Skip), _) => Kind::Skip,
+        (Some(Kind::Constructor), None) => Kind::Constructor,
+        (Some(kind @ Kind::Getter), Some(_)) | (Some(kind @ Kind::Setter), Some(_)) => kind,
+        (None, Some(_)) => Kind::Method,
+        (None, None) => Kind::Static,
+        (Some(Kind::Constructor), Some(_)) => {
+            return Err(syn::Error::new(
+                method.sig.span(),
+                "a constructor must not take `self`",
+            ))
+        }
+        _ => {
+            return Err(syn::Error::new(
+                method.sig.span(),
+                "an accessor must take `&self` or `&mut self`",
+            ))
+        }
+    };
+
+    let name = name.unwrap_or_else(|| {
+        let ident = ident.to_string();
+
+        match kind {
+            Kind::Setter => ident.strip_prefix("set_").unwrap_or(&ident).to_string(),
+            _ => ident,
+        }
+    });
+
+    Ok(Member {
+        kind,
+        name,
+        ident,
+        receiver,
+    })
+}
+
+fn class_impl(mut item: syn::ItemImpl) -> syn::Result<proc_macro2::TokenStream> {
+    if let Some((_, path, _)) = &item.trait_ {
+        return Err(syn::Error::new(
+            path.span(),
+            "`#[neon::class]` can not be applied to a trait implementation",
+        ));
+    }
+
+    if !item.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            item.generics.span(),
+            "`#[neon::class]` does not support generic types",
+        ));
+    }
+
+    let ident = match &*item.self_ty {
+        syn::Type::Path(syn::TypePath { qself: None, path }) => match path.segments.last() {
+            Some(segment) => segment.ident.clone(),
+            None => return Err(syn::Error::new(path.span(), "expected a type name")),
+        },
+        ty => return Err(syn::Error::new(ty.span(), "expected a type name")),
+    };
+
+    let mut members = Vec::new();
+
+    for impl_item in item.items.iter_mut() {
+        if let syn::ImplItem::Method(method) = impl_item {
+            members.push(member(method)?);
+        }
+    }
+
+    let self_ty = &item.self_ty;
+    let handle = handle_ident(&ident);
+    let class_name = ident.to_string();
+
+    let mut constructors = members.iter().filter(|m| m.kind == Kind::Constructor);
+    let constructor = match (constructors.next(), constructors.next()) {
+        (Some(constructor), None) => &constructor.ident,
+        (_, Some(constructor)) => {
+            return Err(syn::Error::new(
+                constructor.ident.span(),
+                "a class can only have one `#[neon(constructor)]`",
+            ))
+        }
+        (None, None) => {
+            return Err(syn::Error::new(
+                item.self_ty.span(),
+                "a `#[neon::class]` impl block must have a `#[neon(constructor)]` function",
+            ))
+        }
+    };
+
+    let mut kernels = Vec::new();
+    let mut descriptor = Vec::new();
+    // Getters and setters of the same name are combined into a single accessor
+    let mut accessors: Vec<(String, Option<syn::Ident>, Option<syn::Ident>)> = Vec::new();
+
+    for member in &members {
+        let name = &member.name;
+        let method = &member.ident;
+        let kernel = format_ident!("__neon_member_{}", method);
+        let borrow = match member.receiver {
+            Some(Receiver::Ref) => quote! {
+                let instance = unsafe { ::neon::macro_internal::this_internals(&mut cx)? };
+                let instance = match instance.try_borrow() {
+                    Ok(instance) => instance,
+                    Err(_) => return ::neon::context::Context::throw_error(
+                        &mut cx,
+                        concat!(#class_name, " is already mutably borrowed"),
+                    ),
+                };
+            },
+            Some(Receiver::Mut) => quote! {
+                let instance = unsafe { ::neon::macro_internal::this_internals(&mut cx)? };
+                let mut instance = match instance.try_borrow_mut() {
+                    Ok(instance) => instance,
+                    Err(_) => return ::neon::context::Context::throw_error(
+                        &mut cx,
+                        concat!(#class_name, " is already borrowed"),
+                    ),
+                };
+            },
+            None => quote!(),
+        };
+        let this = match member.receiver {
+            Some(Receiver::Mut) => quote!(&mut *instance),
+            _ => quote!(&*instance),
+        };
+
+        match member.kind {
+            Kind::Constructor | Kind::Skip => {}
+            Kind::Method | Kind::Getter => {
+                kernels.push(quote! {
+                    fn #kernel(
+                        mut cx: ::neon::context::CallContext<#handle>,
+                    ) -> ::neon::result::JsResult<::neon::types::JsValue> {
+                        #borrow
+                        let cx = ::neon::macro_internal::member_context(cx);
+                        <#self_ty>::#method(#this, cx).map(|value| value.upcast())
+                    }
+                });
+
+                if member.kind == Kind::Method {
+                    descriptor.push(quote! {
+                        .method(#name, ::neon::macro_internal::MethodCallback(#kernel))
+                    });
+                } else {
+                    match accessors.iter_mut().find(|(n, _, _)| n == name) {
+                        Some((_, getter @ None, _)) => *getter = Some(kernel),
+                        Some(_) => {
+                            return Err(syn::Error::new(
+                                method.span(),
+                                format!("duplicate getter for `{}`", name),
+                            ))
+                        }
+                        None => accessors.push((name.clone(), Some(kernel), None)),
+                    }
+                }
+            }
+            Kind::Setter => {
+                kernels.push(quote! {
+                    fn #kernel(
+                        mut cx: ::neon::context::CallContext<#handle>,
+                    ) -> ::neon::result::JsResult<::neon::types::JsValue> {
+                        #borrow
+                        let undefined = ::neon::context::Context::undefined(&mut cx);
+                        let mut cx = ::neon::macro_internal::member_context(cx);
+                        let value = cx.argument(0)?;
+                        <#self_ty>::#method(#this, cx, value)?;
+                        ::std::result::Result::Ok(undefined.upcast())
+                    }
+                });
+
+                match accessors.iter_mut().find(|(n, _, _)| n == name) {
+                    Some((_, _, setter @ None)) => *setter = Some(kernel),
+                    Some(_) => {
+                        return Err(syn::Error::new(
+                            method.span(),
+                            format!("duplicate setter for `{}`", name),
+                        ))
+                    }
+                    None => accessors.push((name.clone(), None, Some(kernel))),
+                }
+            }
+            Kind::Static => {
+                kernels.push(quote! {
+                    fn #kernel(
+                        cx: ::neon::context::FunctionContext,
+                    ) -> ::neon::result::JsResult<::neon::types::JsValue> {
+                        <#self_ty>::#method(cx).map(|value| value.upcast())
+                    }
+                });
+
+                descriptor.push(quote! {
+                    .static_method(#name, ::neon::macro_internal::FunctionCallback(#kernel))
+                });
+            }
+        }
+    }
+
+    for (i, (name, getter, setter)) in accessors.iter().enumerate() {
+        let accessor = format_ident!("__NEON_ACCESSOR_{}", i);
+        let getter = match getter {
+            Some(kernel) => quote!(Some(::neon::macro_internal::MethodCallback(#kernel))),
+            None => quote!(None),
+        };
+        let setter = match setter {
+            Some(kernel) => quote!(Some(::neon::macro_internal::MethodCallback(#kernel))),
+            None => quote!(None),
+        };
+
+        kernels.push(quote! {
+            static #accessor: ::neon::macro_internal::Accessor<#handle> =
+                ::neon::macro_internal::Accessor {
+                    get: #getter,
+                    set: #setter,
+                };
+        });
+
+        descriptor.push(quote! {
+            .accessor(#name, &#accessor)
+        });
+    }
+
+    Ok(quote! {
+        #item
+
+        impl ::neon::object::Class for #handle {
+            type Internals = ::std::cell::RefCell<#self_ty>;
+
+            fn setup<'a, C: ::neon::context::Context<'a>>(
+                _: &mut C,
+            ) -> ::neon::result::NeonResult<::neon::object::ClassDescriptor<'a, Self>> {
+                fn __neon_allocate(
+                    cx: ::neon::context::CallContext<::neon::types::JsUndefined>,
+                ) -> ::neon::result::NeonResult<::std::cell::RefCell<#self_ty>> {
+                    let cx = ::neon::macro_internal::member_context(cx);
+                    <#self_ty>::#constructor(cx).map(::std::cell::RefCell::new)
+                }
+
+                #(#kernels)*
+
+                ::std::result::Result::Ok(::neon::object::ClassDescriptor::new(
+                    #class_name,
+                    ::neon::macro_internal::AllocateCallback(__neon_allocate),
+                )
+                #(#descriptor)*
+                .to_string_tag(#class_name))
+            }
+        }
+    })
+}
@@ -0,0 +1,890 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::ext::IdentExt;
+use syn::spanned::Spanned;
+
+/// A naming convention for `#[neon(rename_all = "...")]`.
+#[derive(Clone, Copy)]
+enum RenameRule {
+    Camel,
+    Pascal,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    Lower,
+    Upper,
+}
+
+impl RenameRule {
+    fn parse(lit: &syn::LitStr) -> syn::Result<Self> {
+        Ok(match lit.value().as_str() {
+            "camelCase" => RenameRule::Camel,
+            "PascalCase" => RenameRule::Pascal,
+            "snake_case" => RenameRule::Snake,
+            "SCREAMING_SNAKE_CASE" => RenameRule::ScreamingSnake,
+            "kebab-case" => RenameRule::Kebab,
+            "lowercase" => RenameRule::Lower,
+            "UPPERCASE" => RenameRule::Upper,
+            _ => {
+                return Err(syn::Error::new(
+                    lit.span(),
+                    "expected one of \"camelCase\", \"PascalCase\", \"snake_case\", \
+                     \"SCREAMING_SNAKE_CASE\", \"kebab-case\", \"lowercase\" or \"UPPERCASE\"",
+                ))
+            }
+        })
+    }
+
+    fn apply(self, ident: &syn::Ident) -> String {
+        let words = split_words(&ident.unraw().to_string());
+
+        match self {
+            RenameRule::Camel => words
+                .iter()
+                .enumerate()
+                .map(|(i, word)| {
+                    if i == 0 {
+                        word.clone()
+                    } else {
+                        capitalize(word)
+                    }
+                })
+                .collect(),
+            RenameRule::Pascal => words.iter().map(|word| capitalize(word)).collect(),
+            RenameRule::Snake => words.join("_"),
+            RenameRule::ScreamingSnake => words.join("_").to_uppercase(),
+            RenameRule::Kebab => words.join("-"),
+            RenameRule::Lower => words.concat(),
+            RenameRule::Upper => words.concat().to_uppercase(),
+        }
+    }
+}
+
+/// Splits a `snake_case` or `PascalCase` identifier into lowercase words.
+fn split_words(name: &str) -> Vec<String> {
+    let mut words = Vec::new();
+
+    for part in name.split('_').filter(|part| !part.is_empty()) {
+        let mut word = String::new();
+        let mut after_lowercase = false;
+
+        for c in part.chars() {
+            if c.is_uppercase() && after_lowercase {
+                words.push(std::mem::take(&mut word));
+            }
+
+            after_lowercase = c.is_lowercase() || c.is_ascii_digit();
+            word.extend(c.to_lowercase());
+        }
+
+        words.push(word);
+    }
+
+    words
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// The arguments of all `#[neon(...)]` attributes in `attrs`.
+fn neon_attrs(attrs: &[syn::Attribute]) -> syn::Result<Vec<syn::NestedMeta>> {
+    let mut nested = Vec::new();
+
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("neon")) {
+        match attr.parse_meta()? {
+            syn::Meta::List(list) => nested.extend(list.nested),
+            meta => return Err(syn::Error::new(meta.span(), "expected `#[neon(...)]`")),
+        }
+    }
+
+    Ok(nested)
+}
+
+fn unknown(nested: &syn::NestedMeta) -> syn::Error {
+    syn::Error::new(nested.span(), "unknown `neon` attribute")
+}
+
+/// Attributes of the `struct` or `enum`, or of an enum variant.
+#[derive(Default)]
+struct Container {
+    name: Option<String>,
+    rename_all: Option<RenameRule>,
+    tag: Option<String>,
+    content: Option<String>,
+}
+
+impl Container {
+    fn parse(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut container = Container::default();
+
+        for nested in neon_attrs(attrs)? {
+            match &nested {
+                syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                    path,
+                    lit: syn::Lit::Str(lit),
+                    ..
+                })) => {
+                    if path.is_ident("name") {
+                        container.name = Some(lit.value());
+                    } else if path.is_ident("rename_all") {
+                        container.rename_all = Some(RenameRule::parse(lit)?);
+                    } else if path.is_ident("tag") {
+                        container.tag = Some(lit.value());
+                    } else if path.is_ident("content") {
+                        container.content = Some(lit.value());
+                    } else {
+                        return Err(unknown(&nested));
+                    }
+                }
+                _ => return Err(unknown(&nested)),
+            }
+        }
+
+        Ok(container)
+    }
+}
+
+/// How a missing field is filled in.
+enum DefaultValue {
+    Trait,
+    Path(syn::ExprPath),
+}
+
+struct Field {
+    member: syn::Member,
+    binding: syn::Ident,
+    ty: syn::Type,
+    key: String,
+    skip: bool,
+    default: Option<DefaultValue>,
+}
+
+impl Field {
+    fn parse(index: usize, field: &syn::Field, rule: RenameRule) -> syn::Result<Self> {
+        let mut name = None;
+        let mut skip = false;
+        let mut default = None;
+
+        for nested in neon_attrs(&field.attrs)? {
+            match &nested {
+                syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("skip") => {
+                    skip = true;
+                }
+                syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("default") => {
+                    default = Some(DefaultValue::Trait);
+                }
+                syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                    path,
+                    lit: syn::Lit::Str(lit),
+                    ..
+                })) => {
+                    if path.is_ident("name") {
+                        name = Some(lit.value());
+                    } else if path.is_ident("default") {
+                        default = Some(DefaultValue::Path(lit.parse()?));
+                    } else {
+                        return Err(unknown(&nested));
+                    }
+                }
+                _ => return Err(unknown(&nested)),
+            }
+        }
+
+        let (member, key) = match &field.ident {
+            Some(ident) => (
+                syn::Member::Named(ident.clone()),
+                name.unwrap_or_else(|| rule.apply(ident)),
+            ),
+            None if name.is_some() || skip || default.is_some() => {
+                return Err(syn::Error::new(
+                    field.span(),
+                    "`neon` attributes are only supported on named fields",
+                ))
+            }
+            None => (syn::Member::Unnamed(syn::Index::from(index)), String::new()),
+        };
+
+        Ok(Field {
+            member,
+            binding: format_ident!("__field{}", index),
+            ty: field.ty.clone(),
+            key,
+            skip,
+            default,
+        })
+    }
+}
+
+enum Shape {
+    Named(Vec<Field>),
+    /// A tuple struct or variant with exactly one field, converted as that field
+    Newtype(Box<Field>),
+    Tuple(Vec<Field>),
+    Unit,
+}
+
+impl Shape {
+    fn parse(fields: &syn::Fields, rule: RenameRule) -> syn::Result<Self> {
+        let mut parsed = fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| Field::parse(i, field, rule))
+            .collect::<syn::Result<Vec<_>>>()?;
+
+        Ok(match fields {
+            syn::Fields::Named(_) => Shape::Named(parsed),
+            syn::Fields::Unnamed(_) if parsed.len() == 1 => {
+                Shape::Newtype(Box::new(parsed.remove(0)))
+            }
+            syn::Fields::Unnamed(_) => Shape::Tuple(parsed),
+            syn::Fields::Unit => Shape::Unit,
+        })
+    }
+
+    fn fields(&self) -> Vec<&Field> {
+        match self {
+            Shape::Named(fields) | Shape::Tuple(fields) => fields.iter().collect(),
+            Shape::Newtype(field) => vec![field.as_ref()],
+            Shape::Unit => vec![],
+        }
+    }
+}
+
+struct Variant {
+    ident: syn::Ident,
+    key: String,
+    shape: Shape,
+}
+
+/// How an enum is represented in JavaScript.
+enum Tagging {
+    /// `"unit"` or `{ "variant": content }`
+    External,
+    /// `{ [tag]: "variant", ...fields }`
+    Internal(String),
+    /// `{ [tag]: "variant", [content]: content }`
+    Adjacent(String, String),
+}
+
+enum Data {
+    Struct(Shape),
+    Enum(Tagging, Vec<Variant>),
+}
+
+fn parse(input: &syn::DeriveInput) -> syn::Result<Data> {
+    let container = Container::parse(&input.attrs)?;
+    let rule = container.rename_all.unwrap_or(RenameRule::Camel);
+
+    if container.name.is_some() {
+        return Err(syn::Error::new(
+            input.span(),
+            "`name` is only supported on fields and variants",
+        ));
+    }
+
+    let data = match &input.data {
+        syn::Data::Struct(data) => {
+            if container.tag.is_some() || container.content.is_some() {
+                return Err(syn::Error::new(
+                    input.span(),
+                    "`tag` and `content` are only supported on enums",
+                ));
+            }
+
+            match Shape::parse(&data.fields, rule)? {
+                Shape::Unit => {
+                    return Err(syn::Error::new(
+                        input.span(),
+                        "unit structs cannot be converted",
+                    ))
+                }
+                shape => Data::Struct(shape),
+            }
+        }
+        syn::Data::Enum(data) => {
+            let tagging = match (container.tag, container.content) {
+                (None, None) => Tagging::External,
+                (Some(tag), None) => Tagging::Internal(tag),
+                (Some(tag), Some(content)) => Tagging::Adjacent(tag, content),
+                (None, Some(_)) => {
+                    return Err(syn::Error::new(input.span(), "`content` requires a `tag`"))
+                }
+            };
+
+            let variants = data
+                .variants
+                .iter()
+                .map(|variant| {
+                    let attrs = Container::parse(&variant.attrs)?;
+
+                    if attrs.tag.is_some() || attrs.content.is_some() {
+                        return Err(syn::Error::new(
+                            variant.span(),
+                            "`tag` and `content` are only supported on enums",
+                        ));
+                    }
+
+                    let fields_rule = attrs.rename_all.unwrap_or(RenameRule::Camel);
+                    let shape = Shape::parse(&variant.fields, fields_rule)?;
+
+                    if let (Tagging::Internal(_), Shape::Tuple(_)) = (&tagging, &shape) {
+                        return Err(syn::Error::new(
+                            variant.span(),
+                            "tuple variants cannot be internally tagged",
+                        ));
+                    }
+
+                    Ok(Variant {
+                        ident: variant.ident.clone(),
+                        key: attrs.name.unwrap_or_else(|| rule.apply(&variant.ident)),
+                        shape,
+                    })
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
+
+            Data::Enum(tagging, variants)
+        }
+        syn::Data::Union(_) => {
+            return Err(syn::Error::new(input.span(), "unions cannot be converted"))
+        }
+    };
+
+    Ok(data)
+}
+
+/// Adds a lifetime parameter `'__cx` and bounds every type parameter by `bound`.
+fn split_generics(
+    generics: &syn::Generics,
+    bound: TokenStream,
+) -> (TokenStream, TokenStream, TokenStream) {
+    let mut extended = generics.clone();
+
+    extended.params.insert(0, syn::parse_quote!('__cx));
+
+    let where_clause = extended.make_where_clause();
+
+    for param in generics.type_params() {
+        let ident = &param.ident;
+        where_clause
+            .predicates
+            .push(syn::parse_quote!(#ident: #bound));
+    }
+
+    let (impl_generics, _, where_clause) = extended.split_for_impl();
+    let (_, ty_generics, _) = generics.split_for_impl();
+
+    (
+        quote!(#impl_generics),
+        quote!(#ty_generics),
+        quote!(#where_clause),
+    )
+}
+
+fn expected_variants(variants: &[&Variant]) -> String {
+    let keys = variants
+        .iter()
+        .map(|variant| format!("{:?}", variant.key))
+        .collect::<Vec<_>>();
+
+    format!("one of {}", keys.join(", "))
+}
+
+/// Unwraps a `Result<T, ConversionError>`, returning the error.
+fn attempt(expr: TokenStream) -> TokenStream {
+    quote! {
+        match #expr {
+            ::std::result::Result::Ok(v) => v,
+            ::std::result::Result::Err(err) => {
+                return ::std::result::Result::Ok(::std::result::Result::Err(err))
+            }
+        }
+    }
+}
+
+/// Unwraps a `NeonResult<Result<T, ConversionError>>`, returning the error or throw.
+fn attempt_neon(expr: TokenStream) -> TokenStream {
+    attempt(quote!((#expr)?))
+}
+
+/// Converts the fields of a named struct or variant from `object`.
+fn from_named(fields: &[Field], object: &TokenStream, path: TokenStream) -> TokenStream {
+    let inits = fields.iter().map(|field| {
+        let member = &field.member;
+        let key = &field.key;
+        let value = if field.skip {
+            quote!(::std::default::Default::default())
+        } else {
+            attempt_neon(match &field.default {
+                None => quote!(::neon::macro_internal::extract::field(cx, #object, #key)),
+                Some(DefaultValue::Trait) => quote! {
+                    ::neon::macro_internal::extract::field_or_else(
+                        cx,
+                        #object,
+                        #key,
+                        ::std::default::Default::default,
+                    )
+                },
+                Some(DefaultValue::Path(default)) => quote! {
+                    ::neon::macro_internal::extract::field_or_else(cx, #object, #key, #default)
+                },
+            })
+        };
+
+        quote!(#member: #value)
+    });
+
+    quote!(#path { #(#inits),* })
+}
+
+/// Converts `value` to a struct or variant at `path` with the given shape.
+fn from_shape(shape: &Shape, value: &TokenStream, path: TokenStream) -> TokenStream {
+    match shape {
+        Shape::Named(fields) => {
+            let object = attempt(quote!(::neon::macro_internal::extract::object(cx, #value)));
+            let init = from_named(fields, &quote!(object), path);
+
+            quote! {{
+                let object = #object;
+
+                #init
+            }}
+        }
+        Shape::Newtype(_) => {
+            let inner = attempt_neon(quote! {
+                ::neon::types::extract::TryFromJs::try_from_js(cx, #value)
+            });
+
+            quote!(#path { 0: #inner })
+        }
+        Shape::Tuple(fields) => {
+            let array = attempt(quote!(::neon::macro_internal::extract::array(cx, #value)));
+            let inits = fields.iter().map(|field| {
+                let member = &field.member;
+                let index = match member {
+                    syn::Member::Unnamed(index) => index.index,
+                    syn::Member::Named(_) => unreachable!(),
+                };
+                let element = attempt_neon(quote! {
+                    ::neon::macro_internal::extract::element(cx, array, #index)
+                });
+
+                quote!(#member: #element)
+            });
+
+            quote! {{
+                let array = #array;
+
+                #path { #(#inits),* }
+            }}
+        }
+        Shape::Unit => path,
+    }
+}
+
+/// Converts `value` to a variant, marking errors as coming from the property `key`.
+fn from_content(variant: &Variant, value: TokenStream, key: &str) -> TokenStream {
+    let ident = &variant.ident;
+    let init = from_shape(&variant.shape, &value, quote!(Self::#ident));
+
+    quote! {{
+        let result = (|| -> ::neon::result::NeonResult<
+            ::std::result::Result<Self, ::neon::types::extract::ConversionError>,
+        > {
+            ::std::result::Result::Ok(::std::result::Result::Ok(#init))
+        })()?;
+
+        ::std::result::Result::Ok(result.map_err(|err| err.at_key(#key)))
+    }}
+}
+
+fn from_enum(tagging: &Tagging, variants: &[Variant]) -> TokenStream {
+    let all = variants.iter().collect::<Vec<_>>();
+    let expected = expected_variants(&all);
+    let unknown = quote! {
+        ::std::result::Result::Ok(::std::result::Result::Err(
+            ::neon::macro_internal::extract::unknown_variant(cx, value, #expected),
+        ))
+    };
+
+    match tagging {
+        Tagging::External => {
+            let (units, others) = variants
+                .iter()
+                .partition::<Vec<_>, _>(|variant| matches!(variant.shape, Shape::Unit));
+            let unit_arms = units.iter().map(|variant| {
+                let ident = &variant.ident;
+                let key = &variant.key;
+
+                quote!(#key => ::std::result::Result::Ok(::std::result::Result::Ok(Self::#ident)),)
+            });
+
+            let from_object = if others.is_empty() {
+                unknown.clone()
+            } else {
+                let expected_keys = format!("an object with {}", expected_variants(&others));
+                let arms = others.iter().map(|variant| {
+                    let key = &variant.key;
+                    let convert = from_content(variant, quote!(content), key);
+
+                    quote!(#key => #convert,)
+                });
+
+                quote! {
+                    let object = match value.downcast::<::neon::types::JsObject, _>(cx) {
+                        ::std::result::Result::Ok(object) => object,
+                        ::std::result::Result::Err(_) => return #unknown,
+                    };
+                    let key = match ::neon::macro_internal::extract::only_key(cx, object, #expected_keys)? {
+                        ::std::result::Result::Ok(key) => key,
+                        ::std::result::Result::Err(err) => {
+                            return ::std::result::Result::Ok(::std::result::Result::Err(err))
+                        }
+                    };
+                    let content = ::neon::object::Object::get(*object, cx, key.as_str())?;
+
+                    match key.as_str() {
+                        #(#arms)*
+                        _ => #unknown,
+                    }
+                }
+            };
+
+            quote! {
+                if let ::std::result::Result::Ok(s) = value.downcast::<::neon::types::JsString, _>(cx) {
+                    return match s.value(cx).as_str() {
+                        #(#unit_arms)*
+                        _ => #unknown,
+                    };
+                }
+
+                #from_object
+            }
+        }
+        Tagging::Internal(tag) | Tagging::Adjacent(tag, _) => {
+            let object = attempt(quote!(::neon::macro_internal::extract::object(cx, value)));
+            let tag_value = attempt_neon(quote! {
+                ::neon::macro_internal::extract::field::<_, ::std::string::String>(cx, object, #tag)
+            });
+            let arms = variants.iter().map(|variant| {
+                let ident = &variant.ident;
+                let key = &variant.key;
+                let convert = match (tagging, &variant.shape) {
+                    (_, Shape::Unit) => quote! {
+                        ::std::result::Result::Ok(::std::result::Result::Ok(Self::#ident))
+                    },
+                    (Tagging::Adjacent(_, content), _) => {
+                        let convert = from_content(variant, quote!(content), content);
+
+                        quote! {{
+                            let content = ::neon::object::Object::get(*object, cx, #content)?;
+
+                            #convert
+                        }}
+                    }
+                    _ => {
+                        let init = from_shape(&variant.shape, &quote!(value), quote!(Self::#ident));
+
+                        quote!(::std::result::Result::Ok(::std::result::Result::Ok(#init)))
+                    }
+                };
+
+                quote!(#key => #convert,)
+            });
+
+            quote! {
+                let object = #object;
+                let tag: ::std::string::String = #tag_value;
+
+                match tag.as_str() {
+                    #(#arms)*
+                    _ => {
+                        let value = ::neon::object::Object::get(*object, cx, #tag)?;
+
+                        ::std::result::Result::Ok(::std::result::Result::Err(
+                            ::neon::macro_internal::extract::unknown_variant(cx, value, #expected)
+                                .at_key(#tag),
+                        ))
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub(crate) fn from_js(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(item as syn::DeriveInput);
+
+    derive_from_js(input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+fn derive_from_js(input: syn::DeriveInput) -> syn::Result<TokenStream> {
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = split_generics(
+        &input.generics,
+        quote!(::neon::types::extract::TryFromJs<'__cx>),
+    );
+
+    let body = match parse(&input)? {
+        Data::Struct(shape) => {
+            let init = from_shape(&shape, &quote!(value), quote!(Self));
+
+            quote!(::std::result::Result::Ok(::std::result::Result::Ok(#init)))
+        }
+        Data::Enum(tagging, variants) => from_enum(&tagging, &variants),
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::neon::types::extract::TryFromJs<'__cx> for #ident #ty_generics #where_clause {
+            #[allow(unreachable_code)]
+            fn try_from_js<C: ::neon::context::Context<'__cx>>(
+                cx: &mut C,
+                value: ::neon::handle::Handle<'__cx, ::neon::types::JsValue>,
+            ) -> ::neon::result::NeonResult<
+                ::std::result::Result<Self, ::neon::types::extract::ConversionError>,
+            > {
+                #body
+            }
+        }
+    })
+}
+
+/// Sets the fields of a named struct or variant, bound to their `binding`, on `object`.
+fn set_named(fields: &[&Field], object: &TokenStream) -> TokenStream {
+    let sets = fields.iter().filter(|field| !field.skip).map(|field| {
+        let key = &field.key;
+        let binding = &field.binding;
+
+        quote!(::neon::macro_internal::extract::set_field(cx, #object, #key, #binding)?;)
+    });
+
+    quote!(#(#sets)*)
+}
+
+/// Converts the bound fields of a struct or variant with the given shape to a `JsValue`.
+fn into_value(shape: &Shape) -> TokenStream {
+    match shape {
+        Shape::Named(fields) => {
+            let sets = set_named(&fields.iter().collect::<Vec<_>>(), &quote!(object));
+
+            quote! {{
+                let object = ::neon::context::Context::empty_object(cx);
+
+                #sets
+
+                object.upcast::<::neon::types::JsValue>()
+            }}
+        }
+        Shape::Newtype(field) => {
+            let binding = &field.binding;
+
+            quote! {
+                ::neon::types::extract::TryIntoJs::try_into_js(#binding, cx)?
+                    .upcast::<::neon::types::JsValue>()
+            }
+        }
+        Shape::Tuple(fields) => {
+            let len = fields.len() as u32;
+            let sets = fields.iter().enumerate().map(|(i, field)| {
+                let binding = &field.binding;
+                let index = i as u32;
+
+                quote!(::neon::macro_internal::extract::set_element(cx, array, #index, #binding)?;)
+            });
+
+            quote! {{
+                let array = ::neon::types::JsArray::new(cx, #len);
+
+                #(#sets)*
+
+                array.upcast::<::neon::types::JsValue>()
+            }}
+        }
+        Shape::Unit => {
+            quote!(::neon::context::Context::undefined(cx).upcast::<::neon::types::JsValue>())
+        }
+    }
+}
+
+/// A pattern binding every field that is not skipped.
+fn pattern(shape: &Shape, path: TokenStream) -> TokenStream {
+    let bindings = shape
+        .fields()
+        .into_iter()
+        .filter(|field| !field.skip)
+        .map(|field| {
+            let member = &field.member;
+            let binding = &field.binding;
+
+            quote!(#member: #binding)
+        });
+
+    quote!(#path { #(#bindings,)* .. })
+}
+
+fn into_enum(tagging: &Tagging, variants: &[Variant]) -> TokenStream {
+    let arms = variants.iter().map(|variant| {
+        let ident = &variant.ident;
+        let key = &variant.key;
+        let pattern = pattern(&variant.shape, quote!(Self::#ident));
+        let convert = match (tagging, &variant.shape) {
+            (Tagging::External, Shape::Unit) => quote! {
+                ::neon::context::Context::string(cx, #key).upcast::<::neon::types::JsValue>()
+            },
+            (Tagging::External, shape) => {
+                let content = into_value(shape);
+
+                quote! {{
+                    let content = #content;
+                    let object = ::neon::context::Context::empty_object(cx);
+
+                    ::neon::object::Object::set(*object, cx, #key, content)?;
+                    object.upcast::<::neon::types::JsValue>()
+                }}
+            }
+            (Tagging::Internal(tag), shape) => {
+                let object = match shape {
+                    Shape::Named(fields) => {
+                        let sets = set_named(&fields.iter().collect::<Vec<_>>(), &quote!(object));
+
+                        quote! {{
+                            let object = ::neon::context::Context::empty_object(cx);
+
+                            #sets
+                            object
+                        }}
+                    }
+                    Shape::Newtype(field) => {
+                        let binding = &field.binding;
+
+                        quote!(::neon::macro_internal::extract::into_object(cx, #binding)?)
+                    }
+                    _ => quote!(::neon::context::Context::empty_object(cx)),
+                };
+
+                quote! {{
+                    let object = #object;
+
+                    ::neon::macro_internal::extract::set_field(cx, object, #tag, #key)?;
+                    object.upcast::<::neon::types::JsValue>()
+                }}
+            }
+            (Tagging::Adjacent(tag, content), shape) => {
+                let set_content = match shape {
+                    Shape::Unit => quote!(),
+                    shape => {
+                        let value = into_value(shape);
+
+                        quote! {
+                            let content = #value;
+
+                            ::neon::object::Object::set(*object, cx, #content, content)?;
+                        }
+                    }
+                };
+
+                quote! {{
+                    let object = ::neon::context::Context::empty_object(cx);
+
+                    ::neon::macro_internal::extract::set_field(cx, object, #tag, #key)?;
+                    #set_content
+                    object.upcast::<::neon::types::JsValue>()
+                }}
+            }
+        };
+
+        quote!(#pattern => #convert,)
+    });
+
+    quote! {
+        ::std::result::Result::Ok(match self {
+            #(#arms)*
+        })
+    }
+}
+
+pub(crate) fn into_js(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(item as syn::DeriveInput);
+
+    derive_into_js(input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+fn derive_into_js(input: syn::DeriveInput) -> syn::Result<TokenStream> {
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = split_generics(
+        &input.generics,
+        quote!(::neon::types::extract::TryIntoJs<'__cx>),
+    );
+
+    let (value_type, body) = match parse(&input)? {
+        Data::Struct(Shape::Named(fields)) => {
+            let fields = fields.iter().collect::<Vec<_>>();
+            let bindings = fields.iter().filter(|field| !field.skip).map(|field| {
+                let member = &field.member;
+                let binding = &field.binding;
+
+                quote!(let #binding = self.#member;)
+            });
+            let sets = set_named(&fields, &quote!(object));
+
+            (
+                quote!(::neon::types::JsObject),
+                quote! {
+                    #(#bindings)*
+                    let object = ::neon::context::Context::empty_object(cx);
+
+                    #sets
+
+                    ::std::result::Result::Ok(object)
+                },
+            )
+        }
+        Data::Struct(Shape::Newtype(field)) => {
+            let ty = &field.ty;
+
+            (
+                quote!(<#ty as ::neon::types::extract::TryIntoJs<'__cx>>::Value),
+                quote!(::neon::types::extract::TryIntoJs::try_into_js(self.0, cx)),
+            )
+        }
+        Data::Struct(shape) => {
+            let pattern = pattern(&shape, quote!(Self));
+            let value = into_value(&shape);
+
+            (
+                quote!(::neon::types::JsArray),
+                quote! {
+                    let #pattern = self;
+
+                    #value.downcast_or_throw(cx)
+                },
+            )
+        }
+        Data::Enum(tagging, variants) => (
+            quote!(::neon::types::JsValue),
+            into_enum(&tagging, &variants),
+        ),
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::neon::types::extract::TryIntoJs<'__cx> for #ident #ty_generics #where_clause {
+            type Value = #value_type;
+
+            fn try_into_js<C: ::neon::context::Context<'__cx>>(
+                self,
+                cx: &mut C,
+            ) -> ::neon::result::JsResult<'__cx, Self::Value> {
+                #body
+            }
+        }
+    })
+}
@@ -0,0 +1,214 @@
+use quote::{format_ident, quote};
+use syn::spanned::Spanned;
+
+/// Parses the arguments of `#[neon::export]`, returning the JavaScript name if given.
+fn parse_name(attr: proc_macro2::TokenStream) -> syn::Result<Option<syn::LitStr>> {
+    if attr.is_empty() {
+        return Ok(None);
+    }
+
+    match syn::parse2::<syn::MetaNameValue>(attr)? {
+        syn::MetaNameValue {
+            path,
+            lit: syn::Lit::Str(name),
+            ..
+        } if path.is_ident("name") => Ok(Some(name)),
+        meta => Err(syn::Error::new(
+            meta.span(),
+            "expected `#[neon::export]` or `#[neon::export(name = \"...\")]`",
+        )),
+    }
+}
+
+pub(crate) fn export(
+    attr: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(item as syn::ItemFn);
+
+    if !cfg!(feature = "export") {
+        return syn::Error::new(
+            input.sig.ident.span(),
+            "`#[neon::export]` requires the `export-macro` feature of `neon`",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    export_fn(attr.into(), input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+/// How an exported function receives the context of the call.
+enum Context {
+    /// `fn(FunctionContext) -> JsResult<T>`, exported as is.
+    Owned,
+    /// `&mut FunctionContext` as the first argument, followed by extracted arguments.
+    Borrowed,
+    /// Only extracted arguments.
+    None,
+}
+
+/// Tests whether the last segment of the path `ty` is `name`, e.g., `FunctionContext`
+/// for `neon::context::FunctionContext<'a>`.
+fn is_named(ty: &syn::Type, name: &str) -> bool {
+    match ty {
+        syn::Type::Path(ty) => ty
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident == name)
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
+fn context_kind(sig: &syn::Signature) -> syn::Result<Context> {
+    let ty = match sig.inputs.first() {
+        Some(syn::FnArg::Typed(arg)) => &*arg.ty,
+        Some(syn::FnArg::Receiver(receiver)) => {
+            return Err(syn::Error::new(
+                receiver.span(),
+                "`#[neon::export]` cannot be applied to methods",
+            ))
+        }
+        None => return Ok(Context::None),
+    };
+
+    Ok(match ty {
+        ty if is_named(ty, "FunctionContext") => Context::Owned,
+        syn::Type::Reference(ty)
+            if ty.mutability.is_some() && is_named(&ty.elem, "FunctionContext") =>
+        {
+            Context::Borrowed
+        }
+        _ => Context::None,
+    })
+}
+
+/// Converts the `output` of an async function on the JavaScript thread, rejecting the
+/// promise with an `Error` if it is an `Err`.
+fn settle_output(output: &syn::ReturnType) -> proc_macro2::TokenStream {
+    let convert = |value| {
+        quote! {
+            let value = ::neon::types::extract::TryIntoJs::try_into_js(#value, &mut cx)?;
+
+            Ok(::neon::handle::Handle::upcast(&value))
+        }
+    };
+
+    match output {
+        syn::ReturnType::Type(_, ty) if is_named(ty, "Result") => {
+            let ok = convert(quote!(value));
+
+            quote! {
+                match output {
+                    Ok(value) => { #ok }
+                    Err(err) => ::neon::context::Context::throw_error(
+                        &mut cx,
+                        ::std::string::ToString::to_string(&err),
+                    ),
+                }
+            }
+        }
+        _ => convert(quote!(output)),
+    }
+}
+
+fn export_fn(
+    attr: proc_macro2::TokenStream,
+    input: syn::ItemFn,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let sig = &input.sig;
+    let ident = &sig.ident;
+
+    // Lifetimes are allowed, e.g., to tie a returned handle to a borrowed context
+    if sig.generics.type_params().next().is_some() || sig.generics.const_params().next().is_some() {
+        return Err(syn::Error::new(
+            sig.generics.span(),
+            "`#[neon::export]` functions cannot be generic",
+        ));
+    }
+
+    let name = match parse_name(attr)? {
+        Some(name) => name.value(),
+        None => ident.to_string(),
+    };
+
+    let register = format_ident!("__NEON_EXPORT_{}", ident.to_string().to_uppercase());
+
+    let kind = context_kind(sig)?;
+
+    if sig.asyncness.is_some() && !matches!(kind, Context::None) {
+        return Err(syn::Error::new(
+            sig.inputs.span(),
+            "async `#[neon::export]` functions cannot take a context",
+        ));
+    }
+
+    let export = match kind {
+        Context::Owned => quote!(cx.export_function(#name, #ident)),
+        kind => {
+            let (context, skip) = match kind {
+                Context::Borrowed => (Some(quote!(&mut cx,)), 1),
+                _ => (None, 0),
+            };
+
+            let len = sig.inputs.len() - skip;
+            let args = (0..len)
+                .map(|i| format_ident!("arg{}", i))
+                .collect::<Vec<_>>();
+            let indices = (0..len).map(|i| i as i32);
+            let length = len as u32;
+
+            let body = if sig.asyncness.is_some() {
+                let settle = settle_output(&sig.output);
+
+                quote! {
+                    let future = #ident(#(#args),*);
+                    let promise = ::neon::macro_internal::spawn_promise(
+                        &mut cx,
+                        future,
+                        |mut cx, output| { #settle },
+                    );
+
+                    Ok(::neon::handle::Handle::upcast(&promise))
+                }
+            } else {
+                quote! {
+                    let result = #ident(#context #(#args),*);
+                    let result = ::neon::types::extract::TryIntoJs::try_into_js(result, &mut cx)?;
+
+                    Ok(::neon::handle::Handle::upcast(&result))
+                }
+            };
+
+            quote! {
+                fn export(
+                    mut cx: ::neon::context::FunctionContext,
+                ) -> ::neon::result::JsResult<::neon::types::JsValue> {
+                    #(let #args = cx.arg(#indices)?;)*
+                    #body
+                }
+
+                cx.export_function_with_length(#name, #length, export)
+            }
+        }
+    };
+
+    Ok(quote! {
+        #input
+
+        #[doc(hidden)]
+        #[::neon::macro_internal::linkme::distributed_slice(::neon::macro_internal::EXPORTS)]
+        #[linkme(crate = ::neon::macro_internal::linkme)]
+        static #register: for<'a> fn(&mut ::neon::context::ModuleContext<'a>) -> ::neon::result::NeonResult<()> = {
+            fn register(cx: &mut ::neon::context::ModuleContext) -> ::neon::result::NeonResult<()> {
+                #export
+            }
+
+            register
+        };
+    })
+}
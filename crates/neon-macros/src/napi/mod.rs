@@ -1,3 +1,11 @@
+mod class;
+mod derive;
+mod export;
+
+pub(crate) use class::class;
+pub(crate) use derive::{from_js, into_js};
+pub(crate) use export::export;
+
 pub(crate) fn main(
     _attr: proc_macro::TokenStream,
     item: proc_macro::TokenStream,
@@ -10,6 +18,22 @@ pub(crate) fn main(
     let block = &input.block;
     let name = &sig.ident;
 
+    // With `#[neon::export]`, Neon registers the module itself, running the main
+    // function after the exports.
+    if cfg!(feature = "export") {
+        return quote::quote!(
+            #(#attrs) *
+            #vis #sig {
+                #[::neon::macro_internal::linkme::distributed_slice(::neon::macro_internal::MAIN)]
+                #[linkme(crate = ::neon::macro_internal::linkme)]
+                static MAIN: fn(::neon::context::ModuleContext) -> ::neon::result::NeonResult<()> = #name;
+
+                #block
+            }
+        )
+        .into();
+    }
+
     quote::quote!(
         #(#attrs) *
         #vis #sig {
@@ -0,0 +1,128 @@
+//! Rust wrappers for N-API async contexts
+//!
+//! An async context identifies an asynchronous operation to `async_hooks`. Calling into
+//! JavaScript with an async context restores the execution context that was current
+//! when it was created, e.g., the store of an `AsyncLocalStorage`.
+//!
+//! See the [N-API Custom Asynchronous Operations][napi-docs] documentation for more details.
+//!
+//! [napi-docs]: https://nodejs.org/api/n-api.html#n_api_custom_asynchronous_operations
+
+use std::mem::MaybeUninit;
+use std::os::raw::c_void;
+
+use crate::napi::bindings as napi;
+use crate::raw::{Env, Local};
+
+/// Handle to an async context created with `init`
+#[derive(Clone, Copy, Debug)]
+pub struct AsyncContext(napi::AsyncContext);
+
+// # Safety
+// The handle is only dereferenced by N-API on the JavaScript thread that created it.
+unsafe impl Send for AsyncContext {}
+unsafe impl Sync for AsyncContext {}
+
+/// Creates an async context for the asynchronous operation represented by `resource`,
+/// triggered by the current execution context. `name` is the type reported to `async_hooks`.
+///
+/// # Safety
+/// * `env` must point to a valid `napi_env` for this thread
+/// * `resource` must be an object and kept alive until the context is destroyed
+pub unsafe fn init(env: Env, resource: Local, name: &str) -> AsyncContext {
+    let mut resource_name = MaybeUninit::uninit();
+    let mut context = MaybeUninit::uninit();
+
+    assert!(crate::napi::string::new(
+        &mut *resource_name.as_mut_ptr(),
+        env,
+        name.as_ptr(),
+        name.len() as i32,
+    ));
+
+    assert_eq!(
+        napi::async_init(
+            env,
+            resource,
+            resource_name.assume_init(),
+            context.as_mut_ptr(),
+        ),
+        napi::Status::Ok,
+    );
+
+    AsyncContext(context.assume_init())
+}
+
+/// Emits the `destroy` hook of the async context and frees it.
+///
+/// # Safety
+/// * `env` must be the `napi_env` that created the context, on its thread
+/// * The context must not be used again
+pub unsafe fn destroy(env: Env, context: AsyncContext) {
+    assert_eq!(napi::async_destroy(env, context.0), napi::Status::Ok);
+}
+
+/// Mutates the `out` argument to refer to the result of calling `fun` in the async
+/// context. Returns `false` if the function threw.
+///
+/// # Safety
+/// `env` must be the `napi_env` that created the context, on its thread
+pub unsafe fn make_callback(
+    out: &mut Local,
+    env: Env,
+    context: AsyncContext,
+    fun: Local,
+    this: Local,
+    argc: i32,
+    argv: *mut c_void,
+) -> bool {
+    let status = napi::make_callback(
+        env,
+        context.0,
+        this,
+        fun,
+        argc as usize,
+        argv as *const _,
+        out as *mut _,
+    );
+
+    status == napi::Status::Ok
+}
+
+/// Calls `f` in the async context, as if it were called from JavaScript by `make_callback`.
+/// Microtasks queued by `f` are run when it returns if the JavaScript stack is empty.
+///
+/// # Safety
+/// * `env` must be the `napi_env` that created the context, on its thread
+/// * `resource` must be the object the context was created with
+#[cfg(feature = "napi-3")]
+pub unsafe fn scope<T, F: FnOnce() -> T>(
+    env: Env,
+    resource: Local,
+    context: AsyncContext,
+    f: F,
+) -> T {
+    let mut scope = MaybeUninit::uninit();
+
+    assert_eq!(
+        napi::open_callback_scope(env, resource, context.0, scope.as_mut_ptr()),
+        napi::Status::Ok,
+    );
+
+    // Closes the scope even if `f` panics, since scopes must be closed in order
+    let _scope = CallbackScope(env, scope.assume_init());
+
+    f()
+}
+
+#[cfg(feature = "napi-3")]
+struct CallbackScope(Env, napi::CallbackScope);
+
+#[cfg(feature = "napi-3")]
+impl Drop for CallbackScope {
+    fn drop(&mut self) {
+        unsafe {
+            assert_eq!(napi::close_callback_scope(self.0, self.1), napi::Status::Ok);
+        }
+    }
+}
@@ -0,0 +1,147 @@
+//! Rust wrappers for N-API async work
+//!
+//! Async work runs an `execute` function on the libuv thread pool and then calls a
+//! `complete` function with its output on the JavaScript thread.
+//!
+//! See the [N-API Simple Asynchronous Operations][napi-docs] documentation for more details.
+//!
+//! [napi-docs]: https://nodejs.org/api/n-api.html#n_api_simple_asynchronous_operations
+
+use std::ffi::c_void;
+use std::mem::MaybeUninit;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::ptr;
+use std::thread;
+
+use crate::napi::bindings as napi;
+use crate::raw::Env;
+
+/// Function executed on the libuv thread pool. It must not call into JavaScript.
+pub type Execute<I, O> = fn(input: I) -> O;
+
+/// Function called on the JavaScript thread with the output of `Execute`, or the
+/// payload of a panic if it panicked. The output is `None` if the work was cancelled
+/// before it started executing.
+pub type Complete<O, D> = fn(env: Env, output: Option<thread::Result<O>>, data: D);
+
+/// Handle to scheduled async work, used to cancel it before it starts executing
+#[derive(Clone, Copy, Debug)]
+pub struct AsyncWork(napi::AsyncWork);
+
+// # Safety
+// The handle is only dereferenced by N-API on the JavaScript thread that scheduled it.
+unsafe impl Send for AsyncWork {}
+unsafe impl Sync for AsyncWork {}
+
+impl AsyncWork {
+    /// Removes the work from the queue if it has not started executing and returns
+    /// `true`. `Complete` is then called with `None`.
+    ///
+    /// # Safety
+    /// * `env` must be the `napi_env` that scheduled the work, on its thread
+    /// * `Complete` must not have been called yet
+    pub unsafe fn cancel(self, env: Env) -> bool {
+        napi::cancel_async_work(env, self.0) == napi::Status::Ok
+    }
+}
+
+struct Data<I, O, D> {
+    input: Option<I>,
+    output: Option<thread::Result<O>>,
+    execute: Execute<I, O>,
+    complete: Complete<O, D>,
+    data: D,
+    work: napi::AsyncWork,
+}
+
+/// Schedules `execute` to be called with `input` on the libuv thread pool, followed by
+/// `complete` with its output and `data` on the JavaScript thread. The returned handle is
+/// valid until `complete` is called.
+///
+/// # Safety
+/// `env` must point to a valid `napi_env` for this thread
+pub unsafe fn schedule<I, O, D>(
+    env: Env,
+    input: I,
+    execute: Execute<I, O>,
+    complete: Complete<O, D>,
+    data: D,
+) -> AsyncWork
+where
+    I: Send + 'static,
+    O: Send + 'static,
+    D: 'static,
+{
+    let data = Box::into_raw(Box::new(Data {
+        input: Some(input),
+        output: None,
+        execute,
+        complete,
+        data,
+        work: ptr::null_mut(),
+    }));
+
+    let name = "neon_async_work";
+    let mut resource_name = MaybeUninit::uninit();
+
+    assert!(crate::napi::string::new(
+        &mut *resource_name.as_mut_ptr(),
+        env,
+        name.as_ptr(),
+        name.len() as i32,
+    ));
+
+    assert_eq!(
+        napi::create_async_work(
+            env,
+            ptr::null_mut(),
+            resource_name.assume_init(),
+            Some(call_execute::<I, O, D>),
+            Some(call_complete::<I, O, D>),
+            data.cast(),
+            &mut (*data).work,
+        ),
+        napi::Status::Ok,
+    );
+
+    let work = (*data).work;
+
+    assert_eq!(napi::queue_async_work(env, work), napi::Status::Ok);
+
+    AsyncWork(work)
+}
+
+// Provides a C ABI wrapper for calling `execute` on the libuv thread pool. Panics are
+// caught and passed to `complete` to avoid unwinding into C.
+unsafe extern "C" fn call_execute<I, O, D>(_env: Env, data: *mut c_void) {
+    let data = &mut *data.cast::<Data<I, O, D>>();
+    let input = data
+        .input
+        .take()
+        .expect("Async work executed more than once");
+    let execute = data.execute;
+
+    data.output = Some(catch_unwind(AssertUnwindSafe(|| execute(input))));
+}
+
+// Provides a C ABI wrapper for calling `complete` on the JavaScript thread and freeing
+// the async work.
+unsafe extern "C" fn call_complete<I, O, D>(env: Env, status: napi::Status, data: *mut c_void) {
+    let Data {
+        output,
+        complete,
+        data,
+        work,
+        ..
+    } = *Box::from_raw(data.cast::<Data<I, O, D>>());
+
+    assert_eq!(napi::delete_async_work(env, work), napi::Status::Ok);
+
+    let output = match status {
+        napi::Status::Ok => Some(output.expect("Async work completed without executing")),
+        napi::Status::Cancelled => None,
+        status => panic!("Unexpected async work status: {:?}", status),
+    };
+
+    complete(env, output, data);
+}
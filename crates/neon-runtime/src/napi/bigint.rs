@@ -0,0 +1,118 @@
+use crate::napi::bindings as napi;
+use crate::raw::{Env, Local};
+use std::mem::MaybeUninit;
+use std::os::raw::c_int;
+use std::ptr;
+
+/// Create a new BigInt from an `i64`
+///
+/// # Safety
+///
+/// `env` is a raw pointer. Please ensure it points to a napi_env that is valid for the current context.
+pub unsafe fn new_int64(env: Env, value: i64) -> Local {
+    let mut local = MaybeUninit::zeroed();
+    let status = napi::create_bigint_int64(env, value, local.as_mut_ptr());
+    assert_eq!(status, napi::Status::Ok);
+    local.assume_init()
+}
+
+/// Create a new BigInt from a `u64`
+///
+/// # Safety
+///
+/// `env` is a raw pointer. Please ensure it points to a napi_env that is valid for the current context.
+pub unsafe fn new_uint64(env: Env, value: u64) -> Local {
+    let mut local = MaybeUninit::zeroed();
+    let status = napi::create_bigint_uint64(env, value, local.as_mut_ptr());
+    assert_eq!(status, napi::Status::Ok);
+    local.assume_init()
+}
+
+/// Create a new BigInt from a sign bit and a little-endian slice of 64-bit words.
+/// Returns `false` if the engine threw, e.g., because the value is too large.
+///
+/// # Safety
+///
+/// `env` is a raw pointer. Please ensure it points to a napi_env that is valid for the current context.
+pub unsafe fn new_words(out: &mut Local, env: Env, sign_bit: bool, words: &[u64]) -> bool {
+    let status = napi::create_bigint_words(
+        env,
+        sign_bit as c_int,
+        words.len(),
+        words.as_ptr(),
+        out as *mut _,
+    );
+
+    status == napi::Status::Ok
+}
+
+/// Get the value of a BigInt as an `i64` and whether the conversion was lossless
+///
+/// # Safety
+///
+/// `env` is a raw pointer. Please ensure it points to a napi_env that is valid for the current context.
+/// `Local` must be a BigInt value associated with the given `Env`
+pub unsafe fn value_int64(env: Env, p: Local) -> (i64, bool) {
+    let mut value = 0;
+    let mut lossless = false;
+    let status =
+        napi::get_value_bigint_int64(env, p, &mut value as *mut _, &mut lossless as *mut _);
+    assert_eq!(status, napi::Status::Ok);
+    (value, lossless)
+}
+
+/// Get the value of a BigInt as a `u64` and whether the conversion was lossless
+///
+/// # Safety
+///
+/// `env` is a raw pointer. Please ensure it points to a napi_env that is valid for the current context.
+/// `Local` must be a BigInt value associated with the given `Env`
+pub unsafe fn value_uint64(env: Env, p: Local) -> (u64, bool) {
+    let mut value = 0;
+    let mut lossless = false;
+    let status =
+        napi::get_value_bigint_uint64(env, p, &mut value as *mut _, &mut lossless as *mut _);
+    assert_eq!(status, napi::Status::Ok);
+    (value, lossless)
+}
+
+/// Get the number of 64-bit words needed to represent the magnitude of a BigInt
+///
+/// # Safety
+///
+/// `env` is a raw pointer. Please ensure it points to a napi_env that is valid for the current context.
+/// `Local` must be a BigInt value associated with the given `Env`
+pub unsafe fn word_count(env: Env, p: Local) -> usize {
+    let mut count = 0;
+    let status = napi::get_value_bigint_words(
+        env,
+        p,
+        ptr::null_mut(),
+        &mut count as *mut _,
+        ptr::null_mut(),
+    );
+    assert_eq!(status, napi::Status::Ok);
+    count
+}
+
+/// Read the sign bit and the little-endian words of a BigInt's magnitude into `words`.
+/// At most `words.len()` words are written. Returns the sign bit (`true` if negative)
+/// and the total number of words needed to represent the value.
+///
+/// # Safety
+///
+/// `env` is a raw pointer. Please ensure it points to a napi_env that is valid for the current context.
+/// `Local` must be a BigInt value associated with the given `Env`
+pub unsafe fn words(env: Env, p: Local, words: &mut [u64]) -> (bool, usize) {
+    let mut sign_bit: c_int = 0;
+    let mut count = words.len();
+    let status = napi::get_value_bigint_words(
+        env,
+        p,
+        &mut sign_bit as *mut _,
+        &mut count as *mut _,
+        words.as_mut_ptr(),
+    );
+    assert_eq!(status, napi::Status::Ok);
+    (sign_bit != 0, count)
+}
@@ -16,12 +16,24 @@ mod napi1 {
 
             fn create_double(env: Env, value: f64, result: *mut Value) -> Status;
 
+            fn create_int32(env: Env, value: i32, result: *mut Value) -> Status;
+
+            fn create_uint32(env: Env, value: u32, result: *mut Value) -> Status;
+
+            fn create_int64(env: Env, value: i64, result: *mut Value) -> Status;
+
             fn create_object(env: Env, result: *mut Value) -> Status;
 
             fn get_value_bool(env: Env, value: Value, result: *mut bool) -> Status;
 
             fn get_value_double(env: Env, value: Value, result: *mut f64) -> Status;
 
+            fn get_value_int32(env: Env, value: Value, result: *mut i32) -> Status;
+
+            fn get_value_uint32(env: Env, value: Value, result: *mut u32) -> Status;
+
+            fn get_value_int64(env: Env, value: Value, result: *mut i64) -> Status;
+
             fn create_array_with_length(env: Env, length: usize, result: *mut Value) -> Status;
 
             fn get_array_length(env: Env, value: Value, result: *mut u32) -> Status;
@@ -76,6 +88,38 @@ mod napi1 {
                 result: *mut Value,
             ) -> Status;
 
+            fn create_string_latin1(
+                env: Env,
+                str: *const c_char,
+                length: usize,
+                result: *mut Value,
+            ) -> Status;
+
+            fn create_string_utf16(
+                env: Env,
+                str: *const u16,
+                length: usize,
+                result: *mut Value,
+            ) -> Status;
+
+            fn get_value_string_latin1(
+                env: Env,
+                value: Value,
+                buf: *mut c_char,
+                bufsize: usize,
+                result: *mut usize,
+            ) -> Status;
+
+            fn get_value_string_utf16(
+                env: Env,
+                value: Value,
+                buf: *mut u16,
+                bufsize: usize,
+                result: *mut usize,
+            ) -> Status;
+
+            fn create_symbol(env: Env, description: Value, result: *mut Value) -> Status;
+
             fn create_arraybuffer(
                 env: Env,
                 byte_length: usize,
@@ -90,6 +134,46 @@ mod napi1 {
                 byte_length: *mut usize,
             ) -> Status;
 
+            fn is_typedarray(env: Env, value: Value, result: *mut bool) -> Status;
+
+            fn create_typedarray(
+                env: Env,
+                type_: TypedArrayType,
+                length: usize,
+                arraybuffer: Value,
+                byte_offset: usize,
+                result: *mut Value,
+            ) -> Status;
+
+            fn get_typedarray_info(
+                env: Env,
+                typedarray: Value,
+                type_: *mut TypedArrayType,
+                length: *mut usize,
+                data: *mut *mut c_void,
+                arraybuffer: *mut Value,
+                byte_offset: *mut usize,
+            ) -> Status;
+
+            fn is_dataview(env: Env, value: Value, result: *mut bool) -> Status;
+
+            fn create_dataview(
+                env: Env,
+                length: usize,
+                arraybuffer: Value,
+                byte_offset: usize,
+                result: *mut Value,
+            ) -> Status;
+
+            fn get_dataview_info(
+                env: Env,
+                dataview: Value,
+                bytelength: *mut usize,
+                data: *mut *mut c_void,
+                arraybuffer: *mut Value,
+                byte_offset: *mut usize,
+            ) -> Status;
+
             fn create_buffer(
                 env: Env,
                 length: usize,
@@ -147,6 +231,37 @@ mod napi1 {
                 result: *mut Value,
             ) -> Status;
 
+            fn wrap(
+                env: Env,
+                js_object: Value,
+                native_object: *mut c_void,
+                finalize_cb: Finalize,
+                finalize_hint: *mut c_void,
+                result: *mut Ref,
+            ) -> Status;
+
+            fn unwrap(env: Env, js_object: Value, result: *mut *mut c_void) -> Status;
+
+            fn remove_wrap(env: Env, js_object: Value, result: *mut *mut c_void) -> Status;
+
+            fn define_properties(
+                env: Env,
+                object: Value,
+                property_count: usize,
+                properties: *const PropertyDescriptor,
+            ) -> Status;
+
+            fn define_class(
+                env: Env,
+                utf8name: *const c_char,
+                length: usize,
+                constructor: Callback,
+                data: *mut c_void,
+                property_count: usize,
+                properties: *const PropertyDescriptor,
+                result: *mut Value,
+            ) -> Status;
+
             fn set_property(env: Env, object: Value, key: Value, value: Value) -> Status;
 
             fn get_property(env: Env, object: Value, key: Value, result: *mut Value) -> Status;
@@ -198,6 +313,72 @@ mod napi1 {
             ) -> Status;
 
             fn run_script(env: Env, script: Value, result: *mut Value) -> Status;
+
+            fn create_promise(env: Env, deferred: *mut Deferred, promise: *mut Value) -> Status;
+
+            fn resolve_deferred(env: Env, deferred: Deferred, resolution: Value) -> Status;
+
+            fn reject_deferred(env: Env, deferred: Deferred, rejection: Value) -> Status;
+
+            fn is_promise(env: Env, value: Value, is_promise: *mut bool) -> Status;
+
+            fn create_async_work(
+                env: Env,
+                async_resource: Value,
+                async_resource_name: Value,
+                execute: AsyncExecuteCallback,
+                complete: AsyncCompleteCallback,
+                data: *mut c_void,
+                result: *mut AsyncWork,
+            ) -> Status;
+
+            fn delete_async_work(env: Env, work: AsyncWork) -> Status;
+
+            fn queue_async_work(env: Env, work: AsyncWork) -> Status;
+
+            fn cancel_async_work(env: Env, work: AsyncWork) -> Status;
+
+            fn async_init(
+                env: Env,
+                async_resource: Value,
+                async_resource_name: Value,
+                result: *mut AsyncContext,
+            ) -> Status;
+
+            fn async_destroy(env: Env, async_context: AsyncContext) -> Status;
+
+            fn make_callback(
+                env: Env,
+                async_context: AsyncContext,
+                recv: Value,
+                func: Value,
+                argc: usize,
+                argv: *const Value,
+                result: *mut Value,
+            ) -> Status;
+        }
+    );
+}
+
+#[cfg(feature = "napi-3")]
+mod napi3 {
+    use super::super::types::*;
+    use std::os::raw::c_void;
+
+    generate!(
+        extern "C" {
+            fn add_env_cleanup_hook(env: Env, fun: CleanupHook, arg: *mut c_void) -> Status;
+
+            fn remove_env_cleanup_hook(env: Env, fun: CleanupHook, arg: *mut c_void) -> Status;
+
+            fn open_callback_scope(
+                env: Env,
+                resource_object: Value,
+                context: AsyncContext,
+                result: *mut CallbackScope,
+            ) -> Status;
+
+            fn close_callback_scope(env: Env, scope: CallbackScope) -> Status;
         }
     );
 }
@@ -244,6 +425,7 @@ mod napi4 {
 #[cfg(feature = "napi-5")]
 mod napi5 {
     use super::super::types::*;
+    use std::os::raw::c_void;
 
     generate!(
         extern "C" {
@@ -252,6 +434,15 @@ mod napi5 {
             fn get_date_value(env: Env, value: Value, result: *mut f64) -> Status;
 
             fn is_date(env: Env, value: Value, result: *mut bool) -> Status;
+
+            fn add_finalizer(
+                env: Env,
+                js_object: Value,
+                finalize_data: *mut c_void,
+                finalize_cb: Finalize,
+                finalize_hint: *mut c_void,
+                result: *mut Ref,
+            ) -> Status;
         }
     );
 }
@@ -259,7 +450,7 @@ mod napi5 {
 #[cfg(feature = "napi-6")]
 mod napi6 {
     use super::super::types::*;
-    use std::os::raw::c_void;
+    use std::os::raw::{c_int, c_void};
 
     generate!(
         extern "C" {
@@ -280,17 +471,74 @@ mod napi6 {
             ) -> Status;
 
             fn get_instance_data(env: Env, data: *mut *mut c_void) -> Status;
+
+            fn create_bigint_int64(env: Env, value: i64, result: *mut Value) -> Status;
+
+            fn create_bigint_uint64(env: Env, value: u64, result: *mut Value) -> Status;
+
+            fn create_bigint_words(
+                env: Env,
+                sign_bit: c_int,
+                word_count: usize,
+                words: *const u64,
+                result: *mut Value,
+            ) -> Status;
+
+            fn get_value_bigint_int64(
+                env: Env,
+                value: Value,
+                result: *mut i64,
+                lossless: *mut bool,
+            ) -> Status;
+
+            fn get_value_bigint_uint64(
+                env: Env,
+                value: Value,
+                result: *mut u64,
+                lossless: *mut bool,
+            ) -> Status;
+
+            fn get_value_bigint_words(
+                env: Env,
+                value: Value,
+                sign_bit: *mut c_int,
+                word_count: *mut usize,
+                words: *mut u64,
+            ) -> Status;
+        }
+    );
+}
+
+#[cfg(feature = "napi-8")]
+mod napi8 {
+    use super::super::types::*;
+    use std::os::raw::c_void;
+
+    generate!(
+        extern "C" {
+            fn add_async_cleanup_hook(
+                env: Env,
+                hook: AsyncCleanupHook,
+                arg: *mut c_void,
+                remove_handle: *mut AsyncCleanupHookHandle,
+            ) -> Status;
+
+            fn remove_async_cleanup_hook(remove_handle: AsyncCleanupHookHandle) -> Status;
         }
     );
 }
 
 pub(crate) use napi1::*;
+#[cfg(feature = "napi-3")]
+pub(crate) use napi3::*;
 #[cfg(feature = "napi-4")]
 pub(crate) use napi4::*;
 #[cfg(feature = "napi-5")]
 pub(crate) use napi5::*;
 #[cfg(feature = "napi-6")]
 pub(crate) use napi6::*;
+#[cfg(feature = "napi-8")]
+pub(crate) use napi8::*;
 
 use super::{Env, Status};
 
@@ -316,6 +564,9 @@ pub(crate) unsafe fn load(env: Env) -> Result<(), libloading::Error> {
 
     napi1::load(&host, version, 1)?;
 
+    #[cfg(feature = "napi-3")]
+    napi3::load(&host, version, 3)?;
+
     #[cfg(feature = "napi-4")]
     napi4::load(&host, version, 4)?;
 
@@ -325,5 +576,8 @@ pub(crate) unsafe fn load(env: Env) -> Result<(), libloading::Error> {
     #[cfg(feature = "napi-6")]
     napi6::load(&host, version, 6)?;
 
+    #[cfg(feature = "napi-8")]
+    napi8::load(&host, version, 8)?;
+
     Ok(())
 }
@@ -47,6 +47,40 @@ pub struct Ref__ {
 
 pub type Ref = *mut Ref__;
 
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct Deferred__ {
+    _unused: [u8; 0],
+}
+
+pub type Deferred = *mut Deferred__;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AsyncWork__ {
+    _unused: [u8; 0],
+}
+
+pub type AsyncWork = *mut AsyncWork__;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AsyncContext__ {
+    _unused: [u8; 0],
+}
+
+pub type AsyncContext = *mut AsyncContext__;
+
+#[cfg(feature = "napi-3")]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct CallbackScope__ {
+    _unused: [u8; 0],
+}
+
+#[cfg(feature = "napi-3")]
+pub type CallbackScope = *mut CallbackScope__;
+
 #[cfg(feature = "napi-4")]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -62,6 +96,28 @@ pub(crate) type Callback = Option<unsafe extern "C" fn(env: Env, info: CallbackI
 pub(crate) type Finalize =
     Option<unsafe extern "C" fn(env: Env, finalize_data: *mut c_void, finalize_hint: *mut c_void)>;
 
+pub(crate) type AsyncExecuteCallback = Option<unsafe extern "C" fn(env: Env, data: *mut c_void)>;
+
+pub(crate) type AsyncCompleteCallback =
+    Option<unsafe extern "C" fn(env: Env, status: Status, data: *mut c_void)>;
+
+#[cfg(feature = "napi-3")]
+pub(crate) type CleanupHook = Option<unsafe extern "C" fn(arg: *mut c_void)>;
+
+#[cfg(feature = "napi-8")]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AsyncCleanupHookHandle__ {
+    _unused: [u8; 0],
+}
+
+#[cfg(feature = "napi-8")]
+pub type AsyncCleanupHookHandle = *mut AsyncCleanupHookHandle__;
+
+#[cfg(feature = "napi-8")]
+pub(crate) type AsyncCleanupHook =
+    Option<unsafe extern "C" fn(handle: AsyncCleanupHookHandle, arg: *mut c_void)>;
+
 #[cfg(feature = "napi-4")]
 pub type ThreadsafeFunctionCallJs = Option<
     unsafe extern "C" fn(env: Env, js_callback: Value, context: *mut c_void, data: *mut c_void),
@@ -111,6 +167,23 @@ pub(crate) enum ValueType {
     BigInt = 9,
 }
 
+#[allow(dead_code)]
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum TypedArrayType {
+    I8 = 0,
+    U8 = 1,
+    U8Clamped = 2,
+    I16 = 3,
+    U16 = 4,
+    I32 = 5,
+    U32 = 6,
+    F32 = 7,
+    F64 = 8,
+    I64 = 9,
+    U64 = 10,
+}
+
 #[allow(dead_code)]
 #[repr(u32)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
@@ -186,3 +259,37 @@ impl std::ops::BitAndAssign for KeyFilter {
         self.0 &= rhs.0;
     }
 }
+
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct PropertyAttributes(pub ::std::os::raw::c_uint);
+
+#[allow(dead_code)]
+impl PropertyAttributes {
+    pub(crate) const DEFAULT: PropertyAttributes = PropertyAttributes(0);
+    pub(crate) const WRITABLE: PropertyAttributes = PropertyAttributes(1);
+    pub(crate) const ENUMERABLE: PropertyAttributes = PropertyAttributes(1 << 1);
+    pub(crate) const CONFIGURABLE: PropertyAttributes = PropertyAttributes(1 << 2);
+    pub(crate) const STATIC: PropertyAttributes = PropertyAttributes(1 << 10);
+}
+
+impl std::ops::BitOr<PropertyAttributes> for PropertyAttributes {
+    type Output = Self;
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        PropertyAttributes(self.0 | other.0)
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct PropertyDescriptor {
+    pub utf8name: *const ::std::os::raw::c_char,
+    pub name: Value,
+    pub method: Callback,
+    pub getter: Callback,
+    pub setter: Callback,
+    pub value: Value,
+    pub attributes: PropertyAttributes,
+    pub data: *mut c_void,
+}
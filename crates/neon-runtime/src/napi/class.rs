@@ -0,0 +1,262 @@
+//! Facilities for defining JS classes whose instances wrap Rust data.
+
+#[cfg(not(feature = "napi-6"))]
+use std::cell::RefCell;
+#[cfg(not(feature = "napi-6"))]
+use std::collections::HashMap;
+use std::mem::MaybeUninit;
+use std::os::raw::c_void;
+use std::ptr::null_mut;
+
+use crate::call::CCallback;
+use crate::napi::bindings as napi;
+use crate::raw::{Env, Local};
+
+/// A class map registered with `set_class_map`, freed by its `free_map` function.
+#[cfg(not(feature = "napi-6"))]
+struct ClassMap {
+    map: *mut c_void,
+    #[cfg(feature = "napi-3")]
+    free_map: unsafe extern "C" fn(*mut c_void),
+}
+
+#[cfg(not(feature = "napi-6"))]
+thread_local! {
+    // An environment is only ever used from the thread that created it. Without instance
+    // data, the class maps of its environments are kept by the thread and freed by a
+    // cleanup hook when an environment is torn down. Before N-API 3, they are leaked: the
+    // thread may outlive the environment, and freeing a map releases references that
+    // belong to it.
+    static CLASS_MAPS: RefCell<HashMap<usize, ClassMap>> = RefCell::new(HashMap::new());
+}
+
+/// Returns the class map previously registered for `env`, or `null` if there is none.
+#[cfg(not(feature = "napi-6"))]
+pub unsafe fn get_class_map(env: Env) -> *mut c_void {
+    CLASS_MAPS.with(|maps| {
+        maps.borrow()
+            .get(&(env as usize))
+            .map(|entry| entry.map)
+            .unwrap_or_else(null_mut)
+    })
+}
+
+/// Registers the class map of `env`. `free_map` must be an `extern "C"` function that
+/// takes ownership of `map`; it is called when `env` is torn down. Before N-API 3, the
+/// map is never freed.
+#[cfg(not(feature = "napi-6"))]
+pub unsafe fn set_class_map(env: Env, map: *mut c_void, free_map: *mut c_void) {
+    #[cfg(not(feature = "napi-3"))]
+    let _ = free_map;
+
+    let entry = ClassMap {
+        map,
+        #[cfg(feature = "napi-3")]
+        free_map: std::mem::transmute(free_map),
+    };
+
+    CLASS_MAPS.with(|maps| maps.borrow_mut().insert(env as usize, entry));
+
+    #[cfg(feature = "napi-3")]
+    crate::napi::lifecycle::add_cleanup_hook(env, drop_class_map, env.cast());
+}
+
+/// Cleanup hook that frees the class map of the `Env` it is registered with.
+#[cfg(all(feature = "napi-3", not(feature = "napi-6")))]
+unsafe extern "C" fn drop_class_map(env: *mut c_void) {
+    let entry = CLASS_MAPS
+        .try_with(|maps| maps.borrow_mut().remove(&(env as usize)))
+        .ok()
+        .flatten();
+
+    // Freed outside of the borrow, since freeing the map may use the environment
+    if let Some(entry) = entry {
+        (entry.free_map)(entry.map);
+    }
+}
+
+/// A property of a class, other than its constructor.
+pub enum Member<'a> {
+    /// A method of the prototype, or of the constructor if `is_static`.
+    Method {
+        name: &'a str,
+        callback: CCallback,
+        is_static: bool,
+    },
+    /// An accessor property of the prototype. N-API passes the same data to the
+    /// getter and the setter, so both callbacks must have the same `dynamic_callback`.
+    Accessor {
+        name: &'a str,
+        getter: Option<CCallback>,
+        setter: Option<CCallback>,
+    },
+    /// The `Symbol.toStringTag` property of the prototype.
+    ToStringTag(&'a str),
+}
+
+unsafe fn string(env: Env, s: &str) -> Option<Local> {
+    let mut out = MaybeUninit::uninit();
+    let status = napi::create_string_utf8(env, s.as_ptr().cast(), s.len(), out.as_mut_ptr());
+
+    if status != napi::Status::Ok {
+        return None;
+    }
+
+    Some(out.assume_init())
+}
+
+unsafe fn to_string_tag_symbol(env: Env) -> Option<Local> {
+    let mut global = MaybeUninit::uninit();
+    let mut symbol = MaybeUninit::uninit();
+    let mut tag = MaybeUninit::uninit();
+
+    assert_eq!(napi::get_global(env, global.as_mut_ptr()), napi::Status::Ok);
+
+    let key = string(env, "Symbol")?;
+    let status = napi::get_property(env, global.assume_init(), key, symbol.as_mut_ptr());
+
+    if status != napi::Status::Ok {
+        return None;
+    }
+
+    let key = string(env, "toStringTag")?;
+    let status = napi::get_property(env, symbol.assume_init(), key, tag.as_mut_ptr());
+
+    if status != napi::Status::Ok {
+        return None;
+    }
+
+    Some(tag.assume_init())
+}
+
+unsafe fn descriptor(env: Env, member: &Member) -> Option<napi::PropertyDescriptor> {
+    let mut descriptor = napi::PropertyDescriptor {
+        utf8name: null_mut(),
+        name: null_mut(),
+        method: None,
+        getter: None,
+        setter: None,
+        value: null_mut(),
+        attributes: napi::PropertyAttributes::DEFAULT,
+        data: null_mut(),
+    };
+
+    // Matches the attributes of members defined with the `class` syntax
+    match member {
+        Member::Method {
+            name,
+            callback,
+            is_static,
+        } => {
+            descriptor.name = string(env, name)?;
+            descriptor.method = Some(std::mem::transmute(callback.static_callback));
+            descriptor.data = callback.dynamic_callback;
+            descriptor.attributes =
+                napi::PropertyAttributes::WRITABLE | napi::PropertyAttributes::CONFIGURABLE;
+
+            if *is_static {
+                descriptor.attributes = descriptor.attributes | napi::PropertyAttributes::STATIC;
+            }
+        }
+        Member::Accessor {
+            name,
+            getter,
+            setter,
+        } => {
+            descriptor.name = string(env, name)?;
+            descriptor.attributes = napi::PropertyAttributes::CONFIGURABLE;
+
+            descriptor.data = getter
+                .as_ref()
+                .or(setter.as_ref())
+                .map(|callback| callback.dynamic_callback)
+                .unwrap_or_else(null_mut);
+
+            if let Some(getter) = getter {
+                descriptor.getter = Some(std::mem::transmute(getter.static_callback));
+            }
+
+            if let Some(setter) = setter {
+                descriptor.setter = Some(std::mem::transmute(setter.static_callback));
+            }
+        }
+        Member::ToStringTag(tag) => {
+            descriptor.name = to_string_tag_symbol(env)?;
+            descriptor.value = string(env, tag)?;
+            descriptor.attributes = napi::PropertyAttributes::CONFIGURABLE;
+        }
+    }
+
+    Some(descriptor)
+}
+
+/// Mutates the `out` argument provided to refer to a newly defined class named `name`,
+/// with the given `constructor` and `members`. Returns `false` if the class couldn't be
+/// defined.
+///
+/// The class does not own the data of `constructor` or `members`; the caller must keep
+/// it alive for as long as the class may be called.
+pub unsafe fn define(
+    out: &mut Local,
+    env: Env,
+    name: &str,
+    constructor: CCallback,
+    members: &[Member],
+) -> bool {
+    let mut properties = Vec::with_capacity(members.len());
+
+    for member in members {
+        match descriptor(env, member) {
+            Some(descriptor) => properties.push(descriptor),
+            None => return false,
+        }
+    }
+
+    let status = napi::define_class(
+        env,
+        name.as_ptr().cast(),
+        name.len(),
+        Some(std::mem::transmute(constructor.static_callback)),
+        constructor.dynamic_callback,
+        properties.len(),
+        properties.as_ptr(),
+        out as *mut Local,
+    );
+
+    status == napi::Status::Ok
+}
+
+/// Associates `data` with the object `obj`. `finalize` is called with `data` when the
+/// object is garbage collected. Returns `false` if the object couldn't be wrapped,
+/// e.g., because it is already wrapped.
+pub unsafe fn wrap(
+    env: Env,
+    obj: Local,
+    data: *mut c_void,
+    finalize: unsafe extern "C" fn(env: Env, data: *mut c_void, hint: *mut c_void),
+) -> bool {
+    napi::wrap(env, obj, data, Some(finalize), null_mut(), null_mut()) == napi::Status::Ok
+}
+
+/// Returns the data associated with `obj` by `wrap`, or `null` if it is not wrapped.
+pub unsafe fn unwrap(env: Env, obj: Local) -> *mut c_void {
+    let mut data = null_mut();
+
+    if napi::unwrap(env, obj, &mut data as *mut _) != napi::Status::Ok {
+        return null_mut();
+    }
+
+    data
+}
+
+/// Dissociates the data of `obj` from it without finalizing it. Returns the data, or
+/// `null` if it is not wrapped.
+pub unsafe fn remove_wrap(env: Env, obj: Local) -> *mut c_void {
+    let mut data = null_mut();
+
+    if napi::remove_wrap(env, obj, &mut data as *mut _) != napi::Status::Ok {
+        return null_mut();
+    }
+
+    data
+}
@@ -0,0 +1,58 @@
+use crate::raw::{Env, Local};
+use std::mem::MaybeUninit;
+use std::os::raw::c_void;
+
+use crate::napi::bindings as napi;
+
+/// Information describing a DataView
+#[derive(Debug)]
+pub struct DataViewInfo {
+    pub data: *mut c_void,
+    pub byte_length: usize,
+    pub buf: Local,
+    pub offset: usize,
+}
+
+/// Create a new DataView of `byte_length` bytes over `buf`, starting at `byte_offset`.
+/// Returns `false` if the engine threw, e.g., because the view is out of bounds.
+///
+/// # Safety
+///
+/// `env` is a raw pointer. Please ensure it points to a napi_env that is valid for the current context.
+/// `buf` must be an ArrayBuffer associated with the given `Env`
+pub unsafe fn new(
+    out: &mut Local,
+    env: Env,
+    byte_length: usize,
+    buf: Local,
+    byte_offset: usize,
+) -> bool {
+    let status = napi::create_dataview(env, byte_length, buf, byte_offset, out as *mut _);
+
+    status == napi::Status::Ok
+}
+
+/// Get the data pointer, byte length, backing ArrayBuffer and byte offset of a DataView
+///
+/// # Safety
+///
+/// `env` is a raw pointer. Please ensure it points to a napi_env that is valid for the current context.
+/// `value` must be a DataView associated with the given `Env`
+pub unsafe fn info(env: Env, value: Local) -> DataViewInfo {
+    let mut info = MaybeUninit::<DataViewInfo>::zeroed();
+    let ptr = info.as_mut_ptr();
+
+    assert_eq!(
+        napi::get_dataview_info(
+            env,
+            value,
+            &mut (*ptr).byte_length,
+            &mut (*ptr).data,
+            &mut (*ptr).buf,
+            &mut (*ptr).offset,
+        ),
+        napi::Status::Ok,
+    );
+
+    info.assume_init()
+}
@@ -49,8 +49,14 @@ pub unsafe fn throw(env: Env, val: Local) {
 }
 
 pub unsafe fn new_error(env: Env, out: &mut Local, msg: Local) {
+    new_error_with_code(env, out, ptr::null_mut(), msg)
+}
+
+/// Mutates the `out` argument to refer to an `Error` with a `code` property.
+/// `code` may be null to omit the property.
+pub unsafe fn new_error_with_code(env: Env, out: &mut Local, code: Local, msg: Local) {
     let mut result = MaybeUninit::uninit();
-    let status = napi::create_error(env, ptr::null_mut(), msg, result.as_mut_ptr());
+    let status = napi::create_error(env, code, msg, result.as_mut_ptr());
 
     assert_eq!(status, napi::Status::Ok);
 
@@ -58,8 +64,14 @@ pub unsafe fn new_error(env: Env, out: &mut Local, msg: Local) {
 }
 
 pub unsafe fn new_type_error(env: Env, out: &mut Local, msg: Local) {
+    new_type_error_with_code(env, out, ptr::null_mut(), msg)
+}
+
+/// Mutates the `out` argument to refer to a `TypeError` with a `code` property.
+/// `code` may be null to omit the property.
+pub unsafe fn new_type_error_with_code(env: Env, out: &mut Local, code: Local, msg: Local) {
     let mut result = MaybeUninit::uninit();
-    let status = napi::create_type_error(env, ptr::null_mut(), msg, result.as_mut_ptr());
+    let status = napi::create_type_error(env, code, msg, result.as_mut_ptr());
 
     assert_eq!(status, napi::Status::Ok);
 
@@ -67,8 +79,14 @@ pub unsafe fn new_type_error(env: Env, out: &mut Local, msg: Local) {
 }
 
 pub unsafe fn new_range_error(env: Env, out: &mut Local, msg: Local) {
+    new_range_error_with_code(env, out, ptr::null_mut(), msg)
+}
+
+/// Mutates the `out` argument to refer to a `RangeError` with a `code` property.
+/// `code` may be null to omit the property.
+pub unsafe fn new_range_error_with_code(env: Env, out: &mut Local, code: Local, msg: Local) {
     let mut result = MaybeUninit::uninit();
-    let status = napi::create_range_error(env, ptr::null_mut(), msg, result.as_mut_ptr());
+    let status = napi::create_range_error(env, code, msg, result.as_mut_ptr());
 
     assert_eq!(status, napi::Status::Ok);
 
@@ -1,7 +1,8 @@
 //! Facilities for working with JS functions.
 
+use std::mem::MaybeUninit;
 use std::os::raw::c_void;
-use std::ptr::null;
+use std::ptr::null_mut;
 
 use crate::call::CCallback;
 use crate::napi::bindings as napi;
@@ -10,10 +11,16 @@ use crate::raw::{Env, Local};
 /// Mutates the `out` argument provided to refer to a newly created `v8::Function`. Returns
 /// `false` if the value couldn't be created.
 pub unsafe fn new(out: &mut Local, env: Env, callback: CCallback) -> bool {
+    new_named(out, env, "", callback)
+}
+
+/// Mutates the `out` argument provided to refer to a newly created `v8::Function` with
+/// the given `name`. Returns `false` if the value couldn't be created.
+pub unsafe fn new_named(out: &mut Local, env: Env, name: &str, callback: CCallback) -> bool {
     let status = napi::create_function(
         env,
-        null(),
-        0,
+        name.as_ptr().cast(),
+        name.len(),
         Some(std::mem::transmute(callback.static_callback)),
         callback.dynamic_callback,
         out as *mut Local,
@@ -22,6 +29,62 @@ pub unsafe fn new(out: &mut Local, env: Env, callback: CCallback) -> bool {
     status == napi::Status::Ok
 }
 
+/// Defines the `length` property of a function, the number of arguments it expects.
+/// Returns `false` if the property couldn't be defined, e.g., because it is not
+/// configurable, in which case an exception is not necessarily pending.
+pub unsafe fn set_length(env: Env, fun: Local, length: u32) -> bool {
+    let mut value = MaybeUninit::uninit();
+    let status = napi::create_uint32(env, length, value.as_mut_ptr());
+
+    assert_eq!(status, napi::Status::Ok);
+
+    // Matches the attributes of the built-in `length` property
+    let descriptor = napi::PropertyDescriptor {
+        utf8name: b"length\0".as_ptr().cast(),
+        name: null_mut(),
+        method: None,
+        getter: None,
+        setter: None,
+        value: value.assume_init(),
+        attributes: napi::PropertyAttributes::CONFIGURABLE,
+        data: null_mut(),
+    };
+
+    napi::define_properties(env, fun, 1, &descriptor) == napi::Status::Ok
+}
+
+/// Mutates the `out` argument provided to refer to a newly created `v8::Function` with
+/// the given `name` that owns the data of `callback`. `finalize` is called with the data
+/// when the function is garbage collected. Returns `false` if the value couldn't be
+/// created, in which case the caller is still responsible for the data.
+pub unsafe fn new_with_finalizer(
+    out: &mut Local,
+    env: Env,
+    name: &str,
+    callback: CCallback,
+    finalize: unsafe extern "C" fn(env: Env, data: *mut c_void, hint: *mut c_void),
+) -> bool {
+    let data = callback.dynamic_callback;
+
+    if !new_named(out, env, name, callback) {
+        return false;
+    }
+
+    // A finalizer leaves the wrap slot of the function free for other native code
+    #[cfg(feature = "napi-5")]
+    let status = napi::add_finalizer(env, *out, data, Some(finalize), null_mut(), null_mut());
+
+    // Neon never wraps functions, so the function's own wrap slot can hold the data
+    #[cfg(not(feature = "napi-5"))]
+    let status = napi::wrap(env, *out, data, Some(finalize), null_mut(), null_mut());
+
+    // The function may already be referenced by the engine, so the data can not be
+    // safely freed if the finalizer could not be attached.
+    assert_eq!(status, napi::Status::Ok);
+
+    true
+}
+
 pub unsafe fn get_dynamic_callback(_env: Env, data: *mut c_void) -> *mut c_void {
     data
 }
@@ -8,15 +8,20 @@
 //!
 //! [napi-docs]: https://nodejs.org/api/n-api.html#n_api_environment_life_cycle_apis
 
+#[cfg(feature = "napi-6")]
 use std::mem::MaybeUninit;
 use std::os::raw::c_void;
+#[cfg(feature = "napi-6")]
 use std::ptr;
 
 use crate::napi::bindings as napi;
+#[cfg(feature = "napi-8")]
+use crate::raw::AsyncCleanupHookHandle;
 use crate::raw::Env;
 
 /// # Safety
 /// `env` must point to a valid `napi_env` for this thread
+#[cfg(feature = "napi-6")]
 pub unsafe fn set_instance_data<T: Send + 'static>(env: Env, data: T) -> *mut T {
     let data = Box::into_raw(Box::new(data));
 
@@ -33,6 +38,7 @@ pub unsafe fn set_instance_data<T: Send + 'static>(env: Env, data: T) -> *mut T
 /// * Caller must ensure reference does not outlive `Env`
 /// * Return value may be `null`
 /// * `env` must point to a valid `napi_env` for this thread
+#[cfg(feature = "napi-6")]
 pub unsafe fn get_instance_data<T: Send + 'static>(env: Env) -> *mut T {
     let mut data = MaybeUninit::uninit();
 
@@ -44,6 +50,86 @@ pub unsafe fn get_instance_data<T: Send + 'static>(env: Env) -> *mut T {
     data.assume_init().cast()
 }
 
+#[cfg(feature = "napi-6")]
 unsafe extern "C" fn drop_box<T>(_env: Env, data: *mut c_void, _hint: *mut c_void) {
     Box::<T>::from_raw(data.cast());
 }
+
+/// Registers `hook` to be called with `arg` when the environment is torn down.
+/// Hooks are called in the reverse order that they were registered.
+///
+/// # Safety
+/// * `env` must point to a valid `napi_env` for this thread
+/// * The pair of `hook` and `arg` must not already be registered
+pub unsafe fn add_cleanup_hook(
+    env: Env,
+    hook: unsafe extern "C" fn(*mut c_void),
+    arg: *mut c_void,
+) {
+    assert_eq!(
+        napi::add_env_cleanup_hook(env, Some(hook), arg),
+        napi::Status::Ok,
+    );
+}
+
+/// Removes a hook registered with `add_cleanup_hook`.
+///
+/// # Safety
+/// * `env` must point to a valid `napi_env` for this thread
+/// * The pair of `hook` and `arg` must have been registered with `add_cleanup_hook`
+pub unsafe fn remove_cleanup_hook(
+    env: Env,
+    hook: unsafe extern "C" fn(*mut c_void),
+    arg: *mut c_void,
+) {
+    assert_eq!(
+        napi::remove_env_cleanup_hook(env, Some(hook), arg),
+        napi::Status::Ok,
+    );
+}
+
+/// Handle to a hook registered with `add_async_cleanup_hook`
+#[cfg(feature = "napi-8")]
+#[derive(Clone, Copy, Debug)]
+pub struct AsyncCleanupHandle(AsyncCleanupHookHandle);
+
+// # Safety
+// The handle is only dereferenced by N-API on the JavaScript thread that registered it.
+#[cfg(feature = "napi-8")]
+unsafe impl Send for AsyncCleanupHandle {}
+#[cfg(feature = "napi-8")]
+unsafe impl Sync for AsyncCleanupHandle {}
+
+/// Registers `hook` to be called with `arg` when the environment is torn down. Unlike
+/// hooks registered with `add_cleanup_hook`, the environment is not torn down until the
+/// hook is removed with `remove_async_cleanup_hook`, which may happen asynchronously.
+/// `hook` is also passed the handle that is returned.
+///
+/// # Safety
+/// `env` must point to a valid `napi_env` for this thread
+#[cfg(feature = "napi-8")]
+pub unsafe fn add_async_cleanup_hook(
+    env: Env,
+    hook: unsafe extern "C" fn(handle: AsyncCleanupHookHandle, arg: *mut c_void),
+    arg: *mut c_void,
+) -> AsyncCleanupHandle {
+    let mut handle = MaybeUninit::uninit();
+
+    assert_eq!(
+        napi::add_async_cleanup_hook(env, Some(hook), arg, handle.as_mut_ptr()),
+        napi::Status::Ok,
+    );
+
+    AsyncCleanupHandle(handle.assume_init())
+}
+
+/// Removes a hook registered with `add_async_cleanup_hook`, either before it is called or
+/// to signal that the asynchronous cleanup it started has completed.
+///
+/// # Safety
+/// * Must be called on the JavaScript thread that registered the hook
+/// * The hook must not have been removed already
+#[cfg(feature = "napi-8")]
+pub unsafe fn remove_async_cleanup_hook(handle: AsyncCleanupHandle) {
+    assert_eq!(napi::remove_async_cleanup_hook(handle.0), napi::Status::Ok,);
+}
@@ -1,25 +1,34 @@
 pub mod array;
 pub mod arraybuffer;
+pub mod async_context;
+pub mod async_work;
+#[cfg(feature = "napi-6")]
+pub mod bigint;
 pub mod buffer;
 pub mod call;
+pub mod class;
 pub mod convert;
+pub mod dataview;
 #[cfg(feature = "napi-5")]
 pub mod date;
 pub mod error;
 pub mod external;
 pub mod fun;
-#[cfg(feature = "napi-6")]
+#[cfg(feature = "napi-3")]
 pub mod lifecycle;
 pub mod mem;
 pub mod object;
 pub mod primitive;
+pub mod promise;
 pub mod raw;
 pub mod reference;
 pub mod scope;
 pub mod string;
+pub mod symbol;
 pub mod tag;
 #[cfg(feature = "napi-4")]
 pub mod tsfn;
+pub mod typedarray;
 
 mod bindings;
 pub use bindings::*;
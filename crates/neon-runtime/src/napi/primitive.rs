@@ -43,3 +43,55 @@ pub unsafe fn number_value(env: Env, p: Local) -> f64 {
     );
     value
 }
+
+/// Mutates the `out` argument provided to refer to a newly created `Local` containing a
+/// JavaScript number with the value of an `i32`.
+pub unsafe fn number_i32(out: &mut Local, env: Env, v: i32) {
+    napi::create_int32(env, v, out as *mut Local);
+}
+
+/// Mutates the `out` argument provided to refer to a newly created `Local` containing a
+/// JavaScript number with the value of a `u32`.
+pub unsafe fn number_u32(out: &mut Local, env: Env, v: u32) {
+    napi::create_uint32(env, v, out as *mut Local);
+}
+
+/// Mutates the `out` argument provided to refer to a newly created `Local` containing a
+/// JavaScript number with the value of an `i64`. Precision is lost for values outside of
+/// `Number.MIN_SAFE_INTEGER..=Number.MAX_SAFE_INTEGER`.
+pub unsafe fn number_i64(out: &mut Local, env: Env, v: i64) {
+    napi::create_int64(env, v, out as *mut Local);
+}
+
+/// Gets the value of a JavaScript number converted with the semantics of `ToInt32`.
+/// Panics if the given `Local` is not a number.
+pub unsafe fn number_value_i32(env: Env, p: Local) -> i32 {
+    let mut value = 0;
+    assert_eq!(
+        napi::get_value_int32(env, p, &mut value as *mut i32),
+        napi::Status::Ok
+    );
+    value
+}
+
+/// Gets the value of a JavaScript number converted with the semantics of `ToUint32`.
+/// Panics if the given `Local` is not a number.
+pub unsafe fn number_value_u32(env: Env, p: Local) -> u32 {
+    let mut value = 0;
+    assert_eq!(
+        napi::get_value_uint32(env, p, &mut value as *mut u32),
+        napi::Status::Ok
+    );
+    value
+}
+
+/// Gets the value of a JavaScript number truncated to an `i64`. Non-finite values are
+/// converted to `0`. Panics if the given `Local` is not a number.
+pub unsafe fn number_value_i64(env: Env, p: Local) -> i64 {
+    let mut value = 0;
+    assert_eq!(
+        napi::get_value_int64(env, p, &mut value as *mut i64),
+        napi::Status::Ok
+    );
+    value
+}
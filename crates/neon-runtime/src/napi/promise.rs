@@ -0,0 +1,78 @@
+//! JavaScript Promise and Deferred handle
+//!
+//! See: [Promises in N-API](https://nodejs.org/api/n-api.html#n_api_promises)
+
+use std::mem::MaybeUninit;
+use std::ptr;
+
+use crate::napi::bindings as napi;
+use crate::raw::{Deferred, Env, Local};
+
+/// Create a `Promise` and a `Deferred` handle for resolving it
+///
+/// # Safety
+/// * `env` is a valid `napi_env` for the current thread
+/// * The returned `Deferred` must be resolved or rejected exactly once
+pub unsafe fn create(env: Env) -> (Deferred, Local) {
+    let mut deferred = MaybeUninit::uninit();
+    let mut promise = MaybeUninit::uninit();
+
+    assert_eq!(
+        napi::create_promise(env, deferred.as_mut_ptr(), promise.as_mut_ptr()),
+        napi::Status::Ok,
+    );
+
+    (deferred.assume_init(), promise.assume_init())
+}
+
+/// Resolve a promise from a `Deferred` handle
+///
+/// # Safety
+/// * `env` is a valid `napi_env` for the current thread
+/// * `resolution` is a valid `napi::Value`
+pub unsafe fn resolve(env: Env, deferred: Deferred, resolution: Local) {
+    assert_eq!(
+        napi::resolve_deferred(env, deferred, resolution),
+        napi::Status::Ok,
+    );
+}
+
+/// Rejects a promise from a `Deferred` handle
+///
+/// # Safety
+/// * `env` is a valid `napi_env` for the current thread
+/// * `rejection` is a valid `napi::Value`
+pub unsafe fn reject(env: Env, deferred: Deferred, rejection: Local) {
+    assert_eq!(
+        napi::reject_deferred(env, deferred, rejection),
+        napi::Status::Ok,
+    );
+}
+
+/// Rejects a promise from a `Deferred` handle with a string message
+///
+/// # Safety
+/// * `env` is a valid `napi_env` for the current thread
+pub unsafe fn reject_err_message(env: Env, deferred: Deferred, msg: impl AsRef<str>) {
+    let msg = msg.as_ref();
+    let mut msg_value = MaybeUninit::uninit();
+
+    assert_eq!(
+        napi::create_string_utf8(env, msg.as_ptr().cast(), msg.len(), msg_value.as_mut_ptr(),),
+        napi::Status::Ok,
+    );
+
+    let mut err = MaybeUninit::uninit();
+
+    assert_eq!(
+        napi::create_error(
+            env,
+            ptr::null_mut(),
+            msg_value.assume_init(),
+            err.as_mut_ptr(),
+        ),
+        napi::Status::Ok,
+    );
+
+    reject(env, deferred, err.assume_init());
+}
@@ -8,6 +8,13 @@ pub type FunctionCallbackInfo = napi::CallbackInfo;
 
 pub type Env = napi::Env;
 
+pub type Deferred = napi::Deferred;
+
+#[cfg(feature = "napi-8")]
+pub type AsyncCleanupHookHandle = napi::AsyncCleanupHookHandle;
+
+pub type TypedArrayType = napi::TypedArrayType;
+
 #[repr(C)]
 #[derive(Clone, Copy)]
 pub struct HandleScope {
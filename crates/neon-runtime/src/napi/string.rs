@@ -29,6 +29,51 @@ pub unsafe fn data(env: Env, out: *mut u8, len: isize, value: Local) -> isize {
     read.assume_init() as isize
 }
 
+pub unsafe fn new_utf16(out: &mut Local, env: Env, data: *const u16, len: usize) -> bool {
+    let status = napi::create_string_utf16(env, data, len, out);
+
+    status == napi::Status::Ok
+}
+
+pub unsafe fn new_latin1(out: &mut Local, env: Env, data: *const u8, len: usize) -> bool {
+    let status = napi::create_string_latin1(env, data as *const _, len, out);
+
+    status == napi::Status::Ok
+}
+
+/// Length of the string in UTF-16 code units. Since Latin-1 encodes a
+/// single code unit per byte, this is also the length of a Latin-1 read.
+pub unsafe fn utf16_len(env: Env, value: Local) -> usize {
+    let mut len = MaybeUninit::uninit();
+    let status = napi::get_value_string_utf16(env, value, ptr::null_mut(), 0, len.as_mut_ptr());
+
+    assert_eq!(status, napi::Status::Ok);
+
+    len.assume_init()
+}
+
+/// Copies at most `len - 1` UTF-16 code units into `out`, followed by a null terminator.
+/// Returns the number of code units copied, excluding the terminator.
+pub unsafe fn data_utf16(env: Env, out: *mut u16, len: usize, value: Local) -> usize {
+    let mut read = MaybeUninit::uninit();
+    let status = napi::get_value_string_utf16(env, value, out, len, read.as_mut_ptr());
+
+    assert_eq!(status, napi::Status::Ok);
+
+    read.assume_init()
+}
+
+/// Copies at most `len - 1` Latin-1 bytes into `out`, followed by a null terminator.
+/// Returns the number of bytes copied, excluding the terminator.
+pub unsafe fn data_latin1(env: Env, out: *mut u8, len: usize, value: Local) -> usize {
+    let mut read = MaybeUninit::uninit();
+    let status = napi::get_value_string_latin1(env, value, out as *mut _, len, read.as_mut_ptr());
+
+    assert_eq!(status, napi::Status::Ok);
+
+    read.assume_init()
+}
+
 pub unsafe fn run_script(out: &mut Local, env: Env, value: Local) -> bool {
     let status = napi::run_script(env, value, out as *mut _);
 
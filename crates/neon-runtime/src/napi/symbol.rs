@@ -0,0 +1,14 @@
+use crate::napi::bindings as napi;
+use crate::raw::{Env, Local};
+
+/// Mutates the `out` argument to refer to a newly created unique symbol. If `desc` is
+/// not null, it must be a string to use as the symbol's description.
+///
+/// # Safety
+///
+/// `env` is a raw pointer. Please ensure it points to a napi_env that is valid for the current context.
+pub unsafe fn new(out: &mut Local, env: Env, desc: Local) -> bool {
+    let status = napi::create_symbol(env, desc, out as *mut _);
+
+    status == napi::Status::Ok
+}
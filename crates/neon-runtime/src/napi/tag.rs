@@ -34,6 +34,11 @@ pub unsafe fn is_string(env: Env, val: Local) -> bool {
     is_type(env, val, napi::ValueType::String)
 }
 
+/// Is `val` a JavaScript symbol?
+pub unsafe fn is_symbol(env: Env, val: Local) -> bool {
+    is_type(env, val, napi::ValueType::Symbol)
+}
+
 pub unsafe fn is_object(env: Env, val: Local) -> bool {
     is_type(env, val, napi::ValueType::Object)
 }
@@ -80,6 +85,26 @@ pub unsafe fn is_arraybuffer(env: Env, val: Local) -> bool {
     result
 }
 
+/// Is `val` a TypedArray instance?
+pub unsafe fn is_typedarray(env: Env, val: Local) -> bool {
+    let mut result = false;
+    assert_eq!(
+        napi::is_typedarray(env, val, &mut result as *mut _),
+        napi::Status::Ok
+    );
+    result
+}
+
+/// Is `val` a DataView instance?
+pub unsafe fn is_dataview(env: Env, val: Local) -> bool {
+    let mut result = false;
+    assert_eq!(
+        napi::is_dataview(env, val, &mut result as *mut _),
+        napi::Status::Ok
+    );
+    result
+}
+
 #[cfg(feature = "napi-5")]
 pub unsafe fn is_date(env: Env, val: Local) -> bool {
     let mut result = false;
@@ -89,3 +114,18 @@ pub unsafe fn is_date(env: Env, val: Local) -> bool {
     );
     result
 }
+
+#[cfg(feature = "napi-6")]
+pub unsafe fn is_bigint(env: Env, val: Local) -> bool {
+    is_type(env, val, napi::ValueType::BigInt)
+}
+
+/// Is `val` a Promise?
+pub unsafe fn is_promise(env: Env, val: Local) -> bool {
+    let mut result = false;
+    assert_eq!(
+        napi::is_promise(env, val, &mut result as *mut _),
+        napi::Status::Ok
+    );
+    result
+}
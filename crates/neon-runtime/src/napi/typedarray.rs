@@ -0,0 +1,61 @@
+use crate::raw::{Env, Local, TypedArrayType};
+use std::mem::MaybeUninit;
+use std::os::raw::c_void;
+
+use crate::napi::bindings as napi;
+
+/// Information describing a TypedArray view
+#[derive(Debug)]
+pub struct TypedArrayInfo {
+    pub typ: TypedArrayType,
+    pub length: usize,
+    pub data: *mut c_void,
+    pub buf: Local,
+    pub offset: usize,
+}
+
+/// Create a new TypedArray view of `length` elements over `buf`, starting at `byte_offset`.
+/// Returns `false` if the engine threw, e.g., because the view is out of bounds or misaligned.
+///
+/// # Safety
+///
+/// `env` is a raw pointer. Please ensure it points to a napi_env that is valid for the current context.
+/// `buf` must be an ArrayBuffer associated with the given `Env`
+pub unsafe fn new(
+    out: &mut Local,
+    env: Env,
+    typ: TypedArrayType,
+    length: usize,
+    buf: Local,
+    byte_offset: usize,
+) -> bool {
+    let status = napi::create_typedarray(env, typ, length, buf, byte_offset, out as *mut _);
+
+    status == napi::Status::Ok
+}
+
+/// Get the element type, length, data pointer, backing ArrayBuffer and byte offset of a TypedArray
+///
+/// # Safety
+///
+/// `env` is a raw pointer. Please ensure it points to a napi_env that is valid for the current context.
+/// `value` must be a TypedArray associated with the given `Env`
+pub unsafe fn info(env: Env, value: Local) -> TypedArrayInfo {
+    let mut info = MaybeUninit::<TypedArrayInfo>::zeroed();
+    let ptr = info.as_mut_ptr();
+
+    assert_eq!(
+        napi::get_typedarray_info(
+            env,
+            value,
+            &mut (*ptr).typ,
+            &mut (*ptr).length,
+            &mut (*ptr).data,
+            &mut (*ptr).buf,
+            &mut (*ptr).offset,
+        ),
+        napi::Status::Ok,
+    );
+
+    info.assume_init()
+}
@@ -1,6 +1,6 @@
 use super::ModuleContext;
 use crate::handle::Handle;
-#[cfg(feature = "legacy-runtime")]
+#[cfg(any(feature = "legacy-runtime", not(feature = "napi-6")))]
 use crate::object::class::ClassMap;
 use crate::result::NeonResult;
 use crate::types::{JsObject, JsValue};
@@ -13,7 +13,7 @@ use neon_runtime::try_catch::TryCatchControl;
 use std::any::Any;
 use std::cell::{Cell, RefCell};
 use std::mem::MaybeUninit;
-#[cfg(feature = "legacy-runtime")]
+#[cfg(any(feature = "legacy-runtime", not(feature = "napi-6")))]
 use std::os::raw::c_void;
 #[cfg(feature = "legacy-runtime")]
 use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
@@ -31,9 +31,13 @@ pub struct Env(raw::Env);
 thread_local! {
     #[allow(unused)]
     pub(crate) static IS_RUNNING: RefCell<bool> = RefCell::new(false);
+
+    /// The `napi_env` of the innermost scope entered on this thread
+    #[cfg(feature = "napi-1")]
+    static CURRENT_ENV: Cell<Option<raw::Env>> = const { Cell::new(None) };
 }
 
-#[cfg(feature = "legacy-runtime")]
+#[cfg(any(feature = "legacy-runtime", not(feature = "napi-6")))]
 extern "C" fn drop_class_map(map: Box<ClassMap>) {
     std::mem::drop(map);
 }
@@ -51,11 +55,16 @@ impl Env {
         ptr
     }
 
-    #[cfg(feature = "legacy-runtime")]
+    /// Returns the class map of this environment. With N-API 6, it is stored in the
+    /// instance data instead.
+    #[cfg(any(feature = "legacy-runtime", not(feature = "napi-6")))]
     pub(crate) fn class_map(&mut self) -> &mut ClassMap {
         let mut ptr: *mut c_void = unsafe { neon_runtime::class::get_class_map(self.to_raw()) };
         if ptr.is_null() {
+            #[cfg(feature = "legacy-runtime")]
             let b: Box<ClassMap> = Box::new(ClassMap::new());
+            #[cfg(feature = "napi-1")]
+            let b: Box<ClassMap> = Box::new(ClassMap::new(self.to_raw()));
             let raw = Box::into_raw(b);
             ptr = raw.cast();
             let free_map: *mut c_void = unsafe { std::mem::transmute(drop_class_map as usize) };
@@ -70,6 +79,24 @@ impl Env {
     pub(crate) fn current() -> Env {
         unsafe { std::mem::transmute(neon_runtime::call::current_isolate()) }
     }
+
+    /// Returns the `Env` of the innermost scope entered on this thread.
+    ///
+    /// Panics if called outside of a Neon context, e.g., from another thread.
+    #[cfg(feature = "napi-1")]
+    pub(crate) fn current() -> Env {
+        CURRENT_ENV
+            .with(|env| env.get())
+            .map(Env)
+            .expect("Must be called from within a Neon context on the JavaScript thread")
+    }
+}
+
+#[cfg(feature = "napi-1")]
+impl From<raw::Env> for Env {
+    fn from(env: raw::Env) -> Self {
+        Env(env)
+    }
 }
 
 pub struct ScopeMetadata {
@@ -88,6 +115,8 @@ impl<'a, R: Root + 'static> Scope<'a, R> {
         unsafe {
             handle_scope.enter(env.to_raw());
         }
+        #[cfg(feature = "napi-1")]
+        let outer = CURRENT_ENV.with(|current| current.replace(Some(env.to_raw())));
         let result = {
             let scope = Scope {
                 metadata: ScopeMetadata {
@@ -98,6 +127,8 @@ impl<'a, R: Root + 'static> Scope<'a, R> {
             };
             f(scope)
         };
+        #[cfg(feature = "napi-1")]
+        CURRENT_ENV.with(|current| current.set(outer));
         unsafe {
             handle_scope.exit(env.to_raw());
         }
@@ -250,6 +281,18 @@ pub fn initialize_module(
         *v.borrow_mut() = true;
     });
 
+    #[cfg(feature = "export-macro")]
+    ModuleContext::with(Env(env), exports, |mut cx| {
+        for export in crate::macro_internal::EXPORTS {
+            if export(&mut cx).is_err() {
+                return;
+            }
+        }
+
+        let _ = init(cx);
+    });
+
+    #[cfg(not(feature = "export-macro"))]
     ModuleContext::with(Env(env), exports, |cx| {
         let _ = init(cx);
     });
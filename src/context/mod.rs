@@ -153,19 +153,32 @@ use crate::borrow::{Borrow, BorrowMut, Ref, RefMut};
 use crate::context::internal::Env;
 #[cfg(all(feature = "napi-4", feature = "channel-api"))]
 use crate::event::Channel;
+#[cfg(feature = "napi-6")]
+use crate::executor::Executor;
 use crate::handle::{Handle, Managed};
-#[cfg(all(feature = "napi-6", feature = "channel-api"))]
+#[cfg(feature = "napi-8")]
+use crate::lifecycle::AsyncCleanupHook;
+#[cfg(feature = "napi-3")]
+use crate::lifecycle::CleanupHook;
+#[cfg(feature = "napi-6")]
 use crate::lifecycle::InstanceData;
-#[cfg(feature = "legacy-runtime")]
 use crate::object::class::Class;
 use crate::object::{Object, This};
 use crate::result::{JsResult, NeonResult, Throw};
+#[cfg(feature = "napi-1")]
+use crate::task::TaskBuilder;
 use crate::types::binary::{JsArrayBuffer, JsBuffer};
 #[cfg(feature = "napi-1")]
 use crate::types::boxed::{Finalize, JsBox};
 #[cfg(feature = "napi-5")]
 use crate::types::date::{DateError, JsDate};
+#[cfg(feature = "napi-1")]
+use crate::types::error::ErrorKind;
 use crate::types::error::JsError;
+#[cfg(feature = "napi-1")]
+use crate::types::extract::TryFromJs;
+#[cfg(feature = "napi-1")]
+use crate::types::promise::{Deferred, JsPromise};
 use crate::types::{
     JsArray, JsBoolean, JsFunction, JsNull, JsNumber, JsObject, JsString, JsUndefined, JsValue,
     StringResult, Value,
@@ -177,6 +190,8 @@ use smallvec::SmallVec;
 use std;
 use std::cell::RefCell;
 use std::convert::Into;
+#[cfg(feature = "napi-6")]
+use std::future::Future;
 use std::marker::PhantomData;
 use std::os::raw::c_void;
 use std::panic::UnwindSafe;
@@ -514,6 +529,23 @@ pub trait Context<'a>: ContextInternal<'a> {
         JsError::range_error(self, msg)
     }
 
+    /// Creates an instance of the [`SyntaxError`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/SyntaxError) class.
+    fn syntax_error<S: AsRef<str>>(&mut self, msg: S) -> JsResult<'a, JsError> {
+        JsError::syntax_error(self, msg)
+    }
+
+    #[cfg(feature = "napi-1")]
+    /// Creates an error of the given `kind` with a `code` property.
+    /// See [`JsError::with_code`](JsError::with_code).
+    fn error_with_code<S: AsRef<str>, M: AsRef<str>>(
+        &mut self,
+        kind: ErrorKind,
+        code: S,
+        msg: M,
+    ) -> JsResult<'a, JsError> {
+        JsError::with_code(self, kind, code, msg)
+    }
+
     /// Throws a direct instance of the [`Error`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Error) class.
     fn throw_error<S: AsRef<str>, T>(&mut self, msg: S) -> NeonResult<T> {
         let err = JsError::error(self, msg)?;
@@ -532,6 +564,38 @@ pub trait Context<'a>: ContextInternal<'a> {
         self.throw(err)
     }
 
+    /// Throws an instance of the [`SyntaxError`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/SyntaxError) class.
+    fn throw_syntax_error<S: AsRef<str>, T>(&mut self, msg: S) -> NeonResult<T> {
+        let err = JsError::syntax_error(self, msg)?;
+        self.throw(err)
+    }
+
+    #[cfg(feature = "napi-1")]
+    /// Throws an error of the given `kind` with a `code` property.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # use neon::types::ErrorKind;
+    /// fn parse_port(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     let port = cx.argument::<JsNumber>(0)?.value(&mut cx);
+    ///
+    ///     if !(0.0..65536.0).contains(&port) {
+    ///         return cx.throw_error_with_code(ErrorKind::RangeError, "ERR_SOCKET_BAD_PORT", "invalid port");
+    ///     }
+    ///
+    ///     Ok(cx.number(port))
+    /// }
+    /// ```
+    fn throw_error_with_code<S: AsRef<str>, M: AsRef<str>, T>(
+        &mut self,
+        kind: ErrorKind,
+        code: S,
+        msg: M,
+    ) -> NeonResult<T> {
+        let err = JsError::with_code(self, kind, code, msg)?;
+        self.throw(err)
+    }
+
     #[cfg(feature = "napi-1")]
     /// Convenience method for wrapping a value in a `JsBox`.
     ///
@@ -553,6 +617,58 @@ pub trait Context<'a>: ContextInternal<'a> {
         JsBox::new(self, v)
     }
 
+    #[cfg(feature = "napi-1")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-1")))]
+    /// Creates a new [`JsPromise`](crate::types::JsPromise) and a [`Deferred`](crate::types::Deferred)
+    /// handle for settling it.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// # use neon::prelude::*;
+    /// fn resolve_now(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    ///     let (deferred, promise) = cx.promise();
+    ///     let value = cx.string("done");
+    ///
+    ///     deferred.resolve(&mut cx, value);
+    ///
+    ///     Ok(promise)
+    /// }
+    /// ```
+    fn promise(&mut self) -> (Deferred, Handle<'a, JsPromise>) {
+        JsPromise::new(self)
+    }
+
+    #[cfg(feature = "napi-1")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-1")))]
+    /// Creates a [`TaskBuilder`](crate::task::TaskBuilder) for running `execute` on the
+    /// libuv thread pool and completing on the JavaScript thread.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn sum_async(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    ///     let numbers = cx.argument::<JsArray>(0)?.to_vec(&mut cx)?;
+    ///     let numbers = numbers
+    ///         .into_iter()
+    ///         .map(|n| Ok(n.downcast_or_throw::<JsNumber, _>(&mut cx)?.value(&mut cx)))
+    ///         .collect::<NeonResult<Vec<f64>>>()?;
+    ///
+    ///     let promise = cx
+    ///         .task(move || numbers.iter().sum::<f64>())
+    ///         .promise(|mut cx, sum| Ok(cx.number(sum)));
+    ///
+    ///     Ok(promise)
+    /// }
+    /// ```
+    fn task<'cx, O, E>(&'cx mut self, execute: E) -> TaskBuilder<'cx, Self, E>
+    where
+        'a: 'cx,
+        O: Send + 'static,
+        E: FnOnce() -> O + Send + 'static,
+    {
+        TaskBuilder::new(self, execute)
+    }
+
     #[cfg(all(feature = "napi-4", feature = "channel-api"))]
     #[cfg_attr(docsrs, doc(cfg(all(feature = "napi-4", feature = "channel-api"))))]
     /// Returns an unbounded channel for scheduling events to be executed on the JavaScript thread.
@@ -569,6 +685,75 @@ pub trait Context<'a>: ContextInternal<'a> {
         channel
     }
 
+    #[cfg(feature = "napi-6")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+    /// Returns the runtime for futures spawned by this instance of the module, creating it
+    /// if necessary. See the [`executor`](crate::executor) module for details.
+    fn runtime(&mut self) -> &'a dyn Executor {
+        InstanceData::runtime(self)
+    }
+
+    #[cfg(feature = "napi-6")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+    /// Runs a future in the background on the [`runtime`](Context::runtime) of this
+    /// instance of the module.
+    ///
+    /// The future runs off of the JavaScript thread; use a [`Channel`](crate::event::Channel)
+    /// to send its results back to JavaScript.
+    fn spawn<F>(&mut self, future: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        self.runtime().spawn(Box::pin(future));
+    }
+
+    #[cfg(feature = "napi-3")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-3")))]
+    /// Registers `f` to be called when the JavaScript environment of this instance of the
+    /// module is torn down, e.g., when a worker thread exits. Hooks are called in the
+    /// reverse order that they were registered. See the [`lifecycle`](crate::lifecycle)
+    /// module for an example.
+    ///
+    /// `f` may not call into JavaScript. The returned [`CleanupHook`] can deregister it.
+    fn on_cleanup<F>(&mut self, f: F) -> CleanupHook
+    where
+        F: FnOnce() + 'static,
+    {
+        CleanupHook::new(self, f)
+    }
+
+    #[cfg(feature = "napi-8")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-8")))]
+    /// Registers `f` to be called when the JavaScript environment of this instance of the
+    /// module is torn down, like [`on_cleanup`](Context::on_cleanup), and waits for the
+    /// future it returns to complete before the environment is destroyed.
+    ///
+    /// The future runs on the [`runtime`](Context::runtime) of this instance, which is
+    /// shut down after all pending async cleanup hooks have completed. The event loop
+    /// keeps running while waiting, but JavaScript may no longer be called.
+    ///
+    /// ```
+    /// # #[cfg(feature = "neon-macros")] {
+    /// # use neon::prelude::*;
+    /// # async fn flush() {}
+    /// #[neon::main]
+    /// fn main(mut cx: ModuleContext) -> NeonResult<()> {
+    ///     cx.on_async_cleanup(|| async {
+    ///         flush().await;
+    ///     });
+    ///
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    fn on_async_cleanup<F, Fut>(&mut self, f: F) -> AsyncCleanupHook
+    where
+        F: FnOnce() -> Fut + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        AsyncCleanupHook::new(self, f)
+    }
+
     #[cfg(all(feature = "napi-4", feature = "channel-api"))]
     #[deprecated(since = "0.9.0", note = "Please use the channel() method instead")]
     #[doc(hidden)]
@@ -607,17 +792,49 @@ impl<'a> ModuleContext<'a> {
     }
 
     /// Convenience method for exporting a Neon function from a module.
+    ///
+    /// With the N-API runtime, the `name` of the function is the export `key`.
     pub fn export_function<T: Value>(
         &mut self,
         key: &str,
         f: fn(FunctionContext) -> JsResult<T>,
     ) -> NeonResult<()> {
+        #[cfg(feature = "legacy-runtime")]
         let value = JsFunction::new(self, f)?.upcast::<JsValue>();
+        #[cfg(feature = "napi-1")]
+        let value = JsFunction::with_name(self, key, f)?.upcast::<JsValue>();
+        self.exports.set(self, key, value)?;
+        Ok(())
+    }
+
+    #[cfg(feature = "napi-1")]
+    /// Exports a Neon function named `key` that expects `length` arguments, as
+    /// reported by the `length` property of the function.
+    ///
+    /// ```
+    /// # #[cfg(feature = "neon-macros")] {
+    /// # use neon::prelude::*;
+    /// # fn add(mut cx: FunctionContext) -> JsResult<JsNumber> { Ok(cx.number(0)) }
+    /// #[neon::main]
+    /// fn main(mut cx: ModuleContext) -> NeonResult<()> {
+    ///     // add.name === "add" && add.length === 2
+    ///     cx.export_function_with_length("add", 2, add)?;
+    ///     Ok(())
+    /// }
+    /// # }
+    /// ```
+    pub fn export_function_with_length<T: Value>(
+        &mut self,
+        key: &str,
+        length: u32,
+        f: fn(FunctionContext) -> JsResult<T>,
+    ) -> NeonResult<()> {
+        let value = JsFunction::with_name(self, key, f)?;
+        value.set_length(self, length)?;
         self.exports.set(self, key, value)?;
         Ok(())
     }
 
-    #[cfg(feature = "legacy-runtime")]
     /// Convenience method for exporting a Neon class constructor from a module.
     pub fn export_class<T: Class>(&mut self, key: &str) -> NeonResult<()> {
         let constructor = T::constructor(self)?;
@@ -722,6 +939,17 @@ impl<'a, T: This> CallContext<'a, T> {
         kind
     }
 
+    #[cfg(feature = "napi-1")]
+    /// Reinterprets the `this`-binding of the context as another type.
+    pub(crate) fn cast<U: This>(self) -> CallContext<'a, U> {
+        CallContext {
+            scope: self.scope,
+            info: self.info,
+            arguments: self.arguments,
+            phantom_type: PhantomData,
+        }
+    }
+
     pub(crate) fn with<U, F: for<'b> FnOnce(CallContext<'b, T>) -> U>(
         env: Env,
         info: &'a CallbackInfo<'a>,
@@ -779,6 +1007,34 @@ impl<'a, T: This> CallContext<'a, T> {
         }
     }
 
+    #[cfg(feature = "napi-1")]
+    /// Produces the `i`th argument converted to a Rust value with
+    /// [`TryFromJs`](crate::types::extract::TryFromJs). Missing arguments are
+    /// `undefined`, so optional arguments can be extracted as an `Option`.
+    ///
+    /// Throws a `TypeError` naming the argument if it cannot be converted.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn repeat(mut cx: FunctionContext) -> JsResult<JsString> {
+    ///     let s: String = cx.arg(0)?;
+    ///     let n: Option<u32> = cx.arg(1)?;
+    ///
+    ///     Ok(cx.string(s.repeat(n.unwrap_or(2) as usize)))
+    /// }
+    /// ```
+    pub fn arg<V: TryFromJs<'a>>(&mut self, i: i32) -> NeonResult<V> {
+        let v = match self.argument_opt(i) {
+            Some(v) => v,
+            None => self.undefined().upcast(),
+        };
+
+        match V::try_from_js(self, v)? {
+            Ok(v) => Ok(v),
+            Err(err) => self.throw_type_error(err.describe(&format!("argument {}", i))),
+        }
+    }
+
     /// Produces a handle to the `this`-binding.
     pub fn this(&mut self) -> Handle<'a, T> {
         #[cfg(feature = "legacy-runtime")]
@@ -818,7 +1074,7 @@ impl<'a> TaskContext<'a> {
         Scope::with(env, |scope| f(TaskContext { scope }))
     }
 
-    #[cfg(all(feature = "napi-4", feature = "channel-api"))]
+    #[cfg(feature = "napi-1")]
     pub(crate) fn with_context<T, F: for<'b> FnOnce(TaskContext<'b>) -> T>(env: Env, f: F) -> T {
         Scope::with(env, |scope| f(TaskContext { scope }))
     }
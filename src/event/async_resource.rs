@@ -0,0 +1,244 @@
+#[cfg(feature = "napi-6")]
+use std::sync::Arc;
+
+use neon_runtime::async_context::{self, AsyncContext};
+use neon_runtime::raw;
+use neon_runtime::reference;
+#[cfg(feature = "napi-6")]
+use neon_runtime::tsfn::ThreadsafeFunction;
+use smallvec::SmallVec;
+
+#[cfg(feature = "napi-3")]
+use crate::context::internal::Env;
+use crate::context::Context;
+use crate::handle::root::NapiRef;
+use crate::handle::{Handle, Managed};
+#[cfg(feature = "napi-6")]
+use crate::lifecycle::{DropData, InstanceData};
+use crate::object::Object;
+use crate::result::JsResult;
+use crate::types::boxed::Finalize;
+use crate::types::{build, prepare_call, JsFunction, JsValue, Value};
+
+/// An asynchronous operation that calls back into JavaScript in the async context of
+/// the code that created it.
+///
+/// Node.js tracks the execution context of asynchronous operations with
+/// [`async_hooks`](https://nodejs.org/api/async_hooks.html), which powers
+/// `AsyncLocalStorage`, tracing and domains. JavaScript called from Rust without an
+/// `AsyncResource`, e.g., from a closure sent across a [`Channel`](super::Channel), does
+/// not know which operation it continues and loses that context. An `AsyncResource`
+/// captures the context when it is created and restores it for each callback.
+///
+/// The `name` of a resource is the `type` reported to `async_hooks`.
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::event::AsyncResource;
+///
+/// // Calls the callback once for each item, with the async context of the caller
+/// fn for_each(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+///     let items = cx.argument::<JsArray>(0)?.to_vec(&mut cx)?;
+///     let callback = cx.argument::<JsFunction>(1)?;
+///     let resource = AsyncResource::new(&mut cx, "neon:for_each");
+///     let this = cx.global();
+///
+///     for item in items {
+///         resource.call(&mut cx, callback, this, vec![item])?;
+///     }
+///
+///     resource.destroy(&mut cx);
+///
+///     Ok(cx.undefined())
+/// }
+/// ```
+///
+/// # Drop Safety
+///
+/// An `AsyncResource` should be disposed of with [`AsyncResource::destroy`], which
+/// reports the end of the operation to `async_hooks`. If it is dropped instead:
+/// * N-API < 6, Neon will `panic` to notify of the leak
+/// * N-API >= 6, Neon will destroy it from a global queue at a runtime cost
+pub struct AsyncResource {
+    // `Option` is used to skip `Drop` when `AsyncResource::destroy` is used.
+    // It will *always* be `Some` when a user is interacting with `AsyncResource`.
+    internal: Option<NodeApiAsyncResource>,
+    #[cfg(feature = "napi-6")]
+    drop_queue: Arc<ThreadsafeFunction<DropData>>,
+}
+
+impl std::fmt::Debug for AsyncResource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("AsyncResource")
+    }
+}
+
+impl AsyncResource {
+    /// Creates a resource for an operation named `name`, triggered by the current async
+    /// context.
+    pub fn new<'a, C: Context<'a>>(cx: &mut C, name: &str) -> Self {
+        let resource = cx.empty_object();
+
+        Self::with_resource(cx, resource, name)
+    }
+
+    /// Creates a resource for an operation named `name` that is represented by the
+    /// `resource` object in `async_hooks`. The object is kept alive until the resource
+    /// is destroyed.
+    pub fn with_resource<'a, C: Context<'a>, T: Object>(
+        cx: &mut C,
+        resource: Handle<T>,
+        name: &str,
+    ) -> Self {
+        let env = cx.env().to_raw();
+        let internal = unsafe {
+            NodeApiAsyncResource {
+                context: async_context::init(env, resource.to_raw(), name),
+                resource: NapiRef(reference::new(env, resource.to_raw()).cast()),
+            }
+        };
+
+        Self {
+            internal: Some(internal),
+            #[cfg(feature = "napi-6")]
+            drop_queue: InstanceData::drop_queue(cx),
+        }
+    }
+
+    /// Calls `callback` in the async context of the resource. Microtasks queued by the
+    /// callback are run before returning, unless there is JavaScript on the stack.
+    ///
+    /// Unlike [`JsFunction::call`], the receiver `this` must be an object.
+    pub fn call<'a, 'b, C: Context<'a>, T, A, AS>(
+        &self,
+        cx: &mut C,
+        callback: Handle<JsFunction>,
+        this: Handle<'b, T>,
+        args: AS,
+    ) -> JsResult<'a, JsValue>
+    where
+        T: Object,
+        A: Value + 'b,
+        AS: IntoIterator<Item = Handle<'b, A>>,
+    {
+        let mut args = args.into_iter().collect::<SmallVec<[_; 8]>>();
+        let (argc, argv) = unsafe { prepare_call(cx, &mut args) }?;
+        let env = cx.env().to_raw();
+        let context = self.as_inner().context;
+
+        build(cx.env(), |out| unsafe {
+            async_context::make_callback(
+                out,
+                env,
+                context,
+                callback.to_raw(),
+                this.to_raw(),
+                argc,
+                argv,
+            )
+        })
+    }
+
+    /// Runs `f` in the async context of the resource, as if it were a callback called
+    /// with [`AsyncResource::call`]. JavaScript called by `f` sees the context of the
+    /// code that created the resource.
+    #[cfg(feature = "napi-3")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-3")))]
+    pub fn enter<'a, C: Context<'a>, T, F: FnOnce(&mut C) -> T>(&self, cx: &mut C, f: F) -> T {
+        let env = cx.env();
+
+        self.scope(env, || f(cx))
+    }
+
+    /// Runs `f` in the async context of the resource, given the `Env` of the thread
+    /// that created it.
+    #[cfg(feature = "napi-3")]
+    pub(crate) fn scope<T, F: FnOnce() -> T>(&self, env: Env, f: F) -> T {
+        let env = env.to_raw();
+        let internal = self.as_inner();
+
+        unsafe {
+            let resource = reference::get(env, internal.resource.0.cast());
+
+            async_context::scope(env, resource, internal.context, f)
+        }
+    }
+
+    /// Reports the end of the operation to `async_hooks` and releases the resource.
+    pub fn destroy<'a, C: Context<'a>>(mut self, cx: &mut C) {
+        let internal = self
+            .internal
+            .take()
+            // `unwrap` will not `panic` because this is the only place
+            // `internal` is replaced with `None` and it consumes `self`.
+            .unwrap();
+
+        unsafe {
+            internal.destroy(cx.env().to_raw());
+        }
+    }
+
+    fn as_inner(&self) -> &NodeApiAsyncResource {
+        self.internal
+            .as_ref()
+            // `unwrap` will not `panic` because `internal` will always be `Some`
+            // until the `AsyncResource` is destroyed.
+            .unwrap()
+    }
+}
+
+// Allows putting an `AsyncResource` directly in a container that implements `Finalize`
+impl Finalize for AsyncResource {
+    fn finalize<'a, C: Context<'a>>(self, cx: &mut C) {
+        self.destroy(cx);
+    }
+}
+
+impl Drop for AsyncResource {
+    #[cfg(not(feature = "napi-6"))]
+    fn drop(&mut self) {
+        // If `None`, the `AsyncResource` has already been destroyed
+        if self.internal.is_none() {
+            return;
+        }
+
+        // Destructors are called during stack unwinding, prevent a double
+        // panic and instead prefer to leak.
+        if std::thread::panicking() {
+            eprintln!("Warning: neon::event::AsyncResource leaked during a panic");
+            return;
+        }
+
+        // Only panic if the event loop is still running
+        if let Ok(true) = crate::context::internal::IS_RUNNING.try_with(|v| *v.borrow()) {
+            panic!(
+                "Must call `destroy` on `neon::event::AsyncResource` \
+                https://docs.rs/neon/latest/neon/event/struct.AsyncResource.html#drop-safety"
+            );
+        }
+    }
+
+    #[cfg(feature = "napi-6")]
+    fn drop(&mut self) {
+        // If `None`, the `AsyncResource` has already been destroyed
+        if let Some(internal) = self.internal.take() {
+            let _ = self
+                .drop_queue
+                .call(DropData::AsyncResource(internal), None);
+        }
+    }
+}
+
+pub(crate) struct NodeApiAsyncResource {
+    context: AsyncContext,
+    resource: NapiRef,
+}
+
+impl NodeApiAsyncResource {
+    /// # Safety
+    /// `env` must be the `napi_env` that created the resource, on its thread
+    pub(crate) unsafe fn destroy(self, env: raw::Env) {
+        async_context::destroy(env, self.context);
+        reference::unreference(env, self.resource.0.cast());
+    }
+}
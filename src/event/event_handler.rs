@@ -25,6 +25,11 @@ impl Drop for EventHandlerInner {
     }
 }
 
+/// Calls a JavaScript function on the main thread from any thread.
+///
+/// Callbacks are not run in the async context of the code that created the handler, so
+/// `AsyncLocalStorage` and `async_hooks` do not follow them. With the N-API runtime, a
+/// `Channel` can capture and restore an async context instead.
 #[derive(Clone)]
 pub struct EventHandler(Arc<EventHandlerInner>);
 
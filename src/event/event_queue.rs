@@ -5,6 +5,8 @@ use neon_runtime::raw::Env;
 use neon_runtime::tsfn::ThreadsafeFunction;
 
 use crate::context::{Context, TaskContext};
+#[cfg(feature = "napi-6")]
+use crate::event::AsyncResource;
 use crate::result::NeonResult;
 
 type Callback = Box<dyn FnOnce(Env) + Send + 'static>;
@@ -58,6 +60,9 @@ type Callback = Box<dyn FnOnce(Env) + Send + 'static>;
 pub struct Channel {
     state: Arc<ChannelState>,
     has_ref: bool,
+    /// Async context that closures are called in, shared by clones of the `Channel`
+    #[cfg(feature = "napi-6")]
+    async_resource: Option<Arc<AsyncResource>>,
 }
 
 impl std::fmt::Debug for Channel {
@@ -73,6 +78,8 @@ impl Channel {
         Self {
             state: Arc::new(ChannelState::new(cx)),
             has_ref: true,
+            #[cfg(feature = "napi-6")]
+            async_resource: None,
         }
     }
 
@@ -102,6 +109,39 @@ impl Channel {
         self
     }
 
+    /// Captures the async context of the caller, e.g., the store of an `AsyncLocalStorage`,
+    /// and restores it when closures sent on this `Channel` and its clones are called.
+    /// `name` is the type of the [`AsyncResource`] reported to `async_hooks`.
+    ///
+    /// Promises settled with [`Deferred::settle_with`](crate::types::Deferred::settle_with)
+    /// across this `Channel` are also settled in the captured context.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn resolve_later(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    ///     let (deferred, promise) = cx.promise();
+    ///     let mut channel = cx.channel();
+    ///
+    ///     channel.capture_async_context(&mut cx, "neon:resolve_later");
+    ///
+    ///     std::thread::spawn(move || {
+    ///         deferred.settle_with(&channel, |mut cx| Ok(cx.undefined()));
+    ///     });
+    ///
+    ///     Ok(promise)
+    /// }
+    /// ```
+    #[cfg(feature = "napi-6")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+    pub fn capture_async_context<'a, C: Context<'a>>(
+        &mut self,
+        cx: &mut C,
+        name: &str,
+    ) -> &mut Self {
+        self.async_resource = Some(Arc::new(AsyncResource::new(cx, name)));
+        self
+    }
+
     /// Schedules a closure to execute on the JavaScript thread that created this Channel
     /// Panics if there is a libuv error
     pub fn send<F>(&self, f: F)
@@ -119,14 +159,26 @@ impl Channel {
     where
         F: FnOnce(TaskContext) -> NeonResult<()> + Send + 'static,
     {
+        #[cfg(feature = "napi-6")]
+        let async_resource = self.async_resource.clone();
+
         let callback = Box::new(move |env| {
             let env = unsafe { std::mem::transmute(env) };
 
             // Note: It is sufficient to use `TaskContext`'s `InheritedHandleScope` because
             // N-API creates a `HandleScope` before calling the callback.
-            TaskContext::with_context(env, move |cx| {
-                let _ = f(cx);
-            });
+            let call = move || {
+                TaskContext::with_context(env, move |cx| {
+                    let _ = f(cx);
+                })
+            };
+
+            #[cfg(feature = "napi-6")]
+            if let Some(async_resource) = async_resource {
+                return async_resource.scope(env, call);
+            }
+
+            call()
         });
 
         self.state.tsfn.call(callback, None).map_err(|_| SendError)
@@ -155,6 +207,8 @@ impl Clone for Channel {
             return Self {
                 state: self.state.clone(),
                 has_ref: false,
+                #[cfg(feature = "napi-6")]
+                async_resource: self.async_resource.clone(),
             };
         }
 
@@ -166,6 +220,8 @@ impl Clone for Channel {
         Self {
             state,
             has_ref: true,
+            #[cfg(feature = "napi-6")]
+            async_resource: self.async_resource.clone(),
         }
     }
 }
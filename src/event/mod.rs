@@ -123,10 +123,21 @@
 //! [psd-crate]: https://crates.io/crates/psd
 //! [psd-file]: https://www.adobe.com/devnet-apps/photoshop/fileformatashtml/
 
+#[cfg(feature = "napi-1")]
+mod async_resource;
+
 #[cfg(all(feature = "napi-4", feature = "channel-api"))]
 mod event_queue;
 
+#[cfg(feature = "napi-1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-1")))]
+pub use self::async_resource::AsyncResource;
+
+#[cfg(feature = "napi-6")]
+pub(crate) use self::async_resource::NodeApiAsyncResource;
+
 #[cfg(all(feature = "napi-4", feature = "channel-api"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "napi-4", feature = "channel-api"))))]
 pub use self::event_queue::{Channel, SendError};
 
 #[cfg(all(feature = "napi-4", feature = "channel-api"))]
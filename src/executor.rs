@@ -0,0 +1,257 @@
+//! Runs Rust futures in the background on a runtime owned by the module instance.
+//!
+//! Each instance of a module (e.g., one per worker thread) lazily creates its own
+//! runtime the first time [`Context::runtime`](crate::context::Context::runtime) or
+//! [`Context::spawn`](crate::context::Context::spawn) is called. With the `tokio`
+//! feature, the runtime is a multi-threaded [`tokio`] runtime; otherwise, each future
+//! runs on a thread of its own. A different [`Executor`] may be installed with
+//! [`set_runtime`] before the runtime is first used.
+//!
+//! The runtime is shut down by a cleanup hook when the JavaScript environment that owns
+//! it is torn down, so that loading a module in a worker does not leak threads when the
+//! worker exits.
+//!
+//! # Example
+//!
+//! ```
+//! # use neon::prelude::*;
+//! fn log_later(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+//!     let message = cx.argument::<JsString>(0)?.value(&mut cx);
+//!
+//!     cx.spawn(async move {
+//!         println!("{}", message);
+//!     });
+//!
+//!     Ok(cx.undefined())
+//! }
+//! ```
+
+use std::future::Future;
+#[cfg(feature = "channel-api")]
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::pin::Pin;
+#[cfg(not(feature = "tokio"))]
+use std::sync::atomic::{AtomicBool, Ordering};
+#[cfg(not(feature = "tokio"))]
+use std::sync::{Arc, Mutex};
+#[cfg(any(feature = "channel-api", not(feature = "tokio")))]
+use std::task::{self, Poll};
+#[cfg(not(feature = "tokio"))]
+use std::task::{Wake, Waker};
+#[cfg(any(feature = "channel-api", not(feature = "tokio")))]
+use std::thread;
+#[cfg(not(feature = "tokio"))]
+use std::thread::{JoinHandle, Thread};
+#[cfg(feature = "tokio")]
+use std::time::Duration;
+
+use crate::context::Context;
+#[cfg(feature = "channel-api")]
+use crate::context::TaskContext;
+#[cfg(feature = "channel-api")]
+use crate::handle::Handle;
+use crate::lifecycle::InstanceData;
+#[cfg(feature = "channel-api")]
+use crate::result::JsResult;
+use crate::result::NeonResult;
+#[cfg(feature = "channel-api")]
+use crate::types::error::panic_message;
+#[cfg(feature = "channel-api")]
+use crate::types::{JsPromise, JsValue};
+
+/// A future that has been boxed to be spawned on an [`Executor`].
+pub type BoxFuture = Pin<Box<dyn Future<Output = ()> + Send + 'static>>;
+
+/// Runs futures in the background on behalf of a module instance.
+pub trait Executor: Send + 'static {
+    /// Runs `future` to completion without blocking the JavaScript thread.
+    fn spawn(&self, future: BoxFuture);
+
+    /// Stops the executor when the JavaScript environment that owns it is torn down.
+    /// The default implementation drops it.
+    fn shutdown(self: Box<Self>) {}
+}
+
+/// How long the JavaScript thread waits for the threads of a tokio runtime to stop when
+/// it is shut down.
+#[cfg(feature = "tokio")]
+const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(1);
+
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+/// Spawns futures on the runtime. When it is shut down, pending futures are dropped and
+/// its threads are joined, waiting at most one second for blocking tasks to complete.
+impl Executor for tokio::runtime::Runtime {
+    fn spawn(&self, future: BoxFuture) {
+        tokio::runtime::Runtime::spawn(self, future);
+    }
+
+    fn shutdown(self: Box<Self>) {
+        self.shutdown_timeout(SHUTDOWN_TIMEOUT);
+    }
+}
+
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+/// Spawns futures on a runtime owned elsewhere, which outlives the module instance.
+impl Executor for tokio::runtime::Handle {
+    fn spawn(&self, future: BoxFuture) {
+        tokio::runtime::Handle::spawn(self, future);
+    }
+}
+
+/// Runs each future on a thread of its own. When it is shut down, pending futures are
+/// dropped the next time they are woken and their threads are joined.
+#[cfg(not(feature = "tokio"))]
+#[derive(Default)]
+struct ThreadExecutor {
+    is_shutdown: Arc<AtomicBool>,
+    threads: Mutex<Vec<JoinHandle<()>>>,
+}
+
+#[cfg(not(feature = "tokio"))]
+impl Executor for ThreadExecutor {
+    fn spawn(&self, future: BoxFuture) {
+        let is_shutdown = Arc::clone(&self.is_shutdown);
+        let thread = thread::spawn(move || block_on(future, &is_shutdown));
+        let mut threads = self.threads.lock().unwrap();
+
+        threads.retain(|thread| !thread.is_finished());
+        threads.push(thread);
+    }
+
+    fn shutdown(self: Box<Self>) {
+        self.is_shutdown.store(true, Ordering::Release);
+
+        for thread in self.threads.into_inner().unwrap() {
+            thread.thread().unpark();
+            // A panic has already been reported by the panic hook
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Creates the runtime used when none was installed with [`set_runtime`].
+#[cfg(feature = "tokio")]
+pub(crate) fn default_runtime() -> Box<dyn Executor> {
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .thread_name("neon-runtime")
+        .build()
+        .expect("Failed to create the tokio runtime");
+
+    Box::new(runtime)
+}
+
+/// Creates the runtime used when none was installed with [`set_runtime`].
+#[cfg(not(feature = "tokio"))]
+pub(crate) fn default_runtime() -> Box<dyn Executor> {
+    Box::new(ThreadExecutor::default())
+}
+
+/// Installs `runtime` as the executor of this module instance, e.g., to share a runtime
+/// between instances or to configure it differently than the default.
+///
+/// Throws an `Error` if the runtime of this instance has already been created.
+///
+/// ```
+/// # #[cfg(all(feature = "neon-macros", feature = "tokio"))] {
+/// # use neon::prelude::*;
+/// #[neon::main]
+/// fn main(mut cx: ModuleContext) -> NeonResult<()> {
+///     let runtime = tokio::runtime::Builder::new_multi_thread()
+///         .worker_threads(2)
+///         .enable_all()
+///         .build()
+///         .or_else(|err| cx.throw_error(err.to_string()))?;
+///
+///     neon::executor::set_runtime(&mut cx, runtime)
+/// }
+/// # }
+/// ```
+pub fn set_runtime<'a, C: Context<'a>, E: Executor>(cx: &mut C, runtime: E) -> NeonResult<()> {
+    if InstanceData::set_runtime(cx, Box::new(runtime)).is_err() {
+        return cx.throw_error("The runtime of this module instance has already been created");
+    }
+
+    Ok(())
+}
+
+/// Wakes a thread blocked in [`block_on`] by unparking it.
+#[cfg(not(feature = "tokio"))]
+struct ThreadWaker(Thread);
+
+#[cfg(not(feature = "tokio"))]
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Polls `future` on the current thread, parking it until the future is woken. The
+/// future is dropped without completing if `is_shutdown` is set while it is pending.
+#[cfg(not(feature = "tokio"))]
+fn block_on(mut future: BoxFuture, is_shutdown: &AtomicBool) {
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = task::Context::from_waker(&waker);
+
+    while !is_shutdown.load(Ordering::Acquire) {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(()) => return,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+/// Completes with the output of a future, or with the payload of a panic while polling it.
+#[cfg(feature = "channel-api")]
+struct CatchUnwind<F>(Pin<Box<F>>);
+
+#[cfg(feature = "channel-api")]
+impl<F: Future> Future for CatchUnwind<F> {
+    type Output = thread::Result<F::Output>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut task::Context) -> Poll<Self::Output> {
+        let future = self.0.as_mut();
+
+        match catch_unwind(AssertUnwindSafe(|| future.poll(cx))) {
+            Ok(Poll::Ready(output)) => Poll::Ready(Ok(output)),
+            Ok(Poll::Pending) => Poll::Pending,
+            Err(panic) => Poll::Ready(Err(panic)),
+        }
+    }
+}
+
+/// Spawns `future` on the runtime of the module instance and returns a promise that is
+/// settled by calling `settle` with its output on the JavaScript thread.
+///
+/// If the future panics, the promise is rejected with an `Error` describing the panic.
+/// The event loop is kept alive until the promise is settled.
+#[cfg(feature = "channel-api")]
+#[doc(hidden)]
+pub fn spawn_promise<'a, C, F, S>(cx: &mut C, future: F, settle: S) -> Handle<'a, JsPromise>
+where
+    C: Context<'a>,
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+    S: for<'b> FnOnce(TaskContext<'b>, F::Output) -> JsResult<'b, JsValue> + Send + 'static,
+{
+    let (deferred, promise) = cx.promise();
+    // A referenced channel keeps the event loop alive until it is dropped
+    let channel = cx.channel();
+
+    cx.spawn(async move {
+        let output = CatchUnwind(Box::pin(future)).await;
+
+        deferred.settle_with(&channel, move |mut cx| match output {
+            Ok(output) => settle(cx, output),
+            Err(panic) => cx.throw_error(panic_message(&*panic)),
+        });
+    });
+
+    promise
+}
@@ -82,20 +82,28 @@
 
 pub mod borrow;
 pub mod context;
-#[cfg(any(
-    feature = "event-handler-api",
-    all(feature = "napi-4", feature = "channel-api")
-))]
-#[cfg_attr(docsrs, doc(cfg(all(feature = "napi-4", feature = "channel-api"))))]
+#[cfg(any(feature = "event-handler-api", feature = "napi-1"))]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(any(feature = "event-handler-api", feature = "napi-1")))
+)]
 pub mod event;
+#[cfg(feature = "napi-6")]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+pub mod executor;
 pub mod handle;
+#[cfg(feature = "napi-3")]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-3")))]
+pub mod lifecycle;
 pub mod meta;
 pub mod object;
 pub mod prelude;
 #[cfg(feature = "napi-1")]
 pub mod reflect;
 pub mod result;
-#[cfg(feature = "legacy-runtime")]
+#[cfg(all(feature = "napi-1", feature = "serde"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "napi-1", feature = "serde"))))]
+pub mod serde;
 pub mod task;
 pub mod types;
 
@@ -106,13 +114,41 @@ pub mod macro_internal;
 #[cfg_attr(docsrs, doc(cfg(feature = "proc-macros")))]
 pub use neon_macros::*;
 
-#[cfg(feature = "napi-6")]
-mod lifecycle;
-
 #[cfg(all(feature = "legacy-runtime", feature = "napi-1"))]
 compile_error!("Cannot enable both `legacy-runtime` and `napi-*` features.\n\nTo use `napi-*`, disable `legacy-runtime` by setting `default-features` to `false` in Cargo.toml\nor with cargo's --no-default-features flag.");
 
-#[cfg(all(feature = "napi-1", not(feature = "legacy-runtime")))]
+#[cfg(all(feature = "export-macro", not(feature = "legacy-runtime")))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! register_module {
+    ($module:pat, $init:block) => {
+        register_module!(|$module| $init);
+    };
+
+    (|$module:pat| $init:block) => {
+        #[$crate::macro_internal::linkme::distributed_slice($crate::macro_internal::MAIN)]
+        #[linkme(crate = $crate::macro_internal::linkme)]
+        static __NEON_MAIN: fn($crate::context::ModuleContext) -> $crate::result::NeonResult<()> = {
+            fn __init_neon_module(
+                $module: $crate::context::ModuleContext,
+            ) -> $crate::result::NeonResult<()> {
+                // Suppress the default Rust panic hook, which prints diagnostics to stderr.
+                #[cfg(not(feature = "default-panic-hook"))]
+                ::std::panic::set_hook(::std::boxed::Box::new(|_| {}));
+
+                $init
+            }
+
+            __init_neon_module
+        };
+    };
+}
+
+#[cfg(all(
+    feature = "napi-1",
+    not(feature = "export-macro"),
+    not(feature = "legacy-runtime")
+))]
 #[doc(hidden)]
 #[macro_export]
 macro_rules! register_module {
@@ -240,7 +276,6 @@ macro_rules! register_module {
     }
 }
 
-#[cfg(feature = "legacy-runtime")]
 #[doc(hidden)]
 #[macro_export(local_inner_macros)]
 macro_rules! class_definition {
@@ -330,7 +365,6 @@ macro_rules! class_definition {
     };
 }
 
-#[cfg(feature = "legacy-runtime")]
 #[doc(hidden)]
 #[macro_export(local_inner_macros)]
 macro_rules! impl_managed {
@@ -351,7 +385,6 @@ macro_rules! impl_managed {
     };
 }
 
-#[cfg(feature = "legacy-runtime")]
 /// Declare custom native JavaScript types with Rust implementations.
 ///
 /// Example:
@@ -433,7 +466,6 @@ macro_rules! declare_types {
     { } => { };
 }
 
-#[cfg(feature = "legacy-runtime")]
 #[doc(hidden)]
 #[macro_export]
 macro_rules! neon_stringify {
@@ -4,48 +4,140 @@
 //! environment. If a Neon module is loaded multiple times (Web Workers, worker
 //! threads), these API will be handle data associated with a specific instance.
 //!
-//! See the [N-API Lifecycle][npai-docs] documentation for more details.
+//! Hooks registered with [`Context::on_cleanup`](crate::context::Context::on_cleanup)
+//! are called when the environment that registered them is torn down, e.g., when a
+//! worker thread exits, to release resources that are not owned by JavaScript values.
+//! Hooks are called in the reverse order that they were registered.
+//!
+//! ```
+//! # #[cfg(feature = "neon-macros")] {
+//! # use neon::prelude::*;
+//! # use std::sync::mpsc;
+//! #[neon::main]
+//! fn main(mut cx: ModuleContext) -> NeonResult<()> {
+//!     let (sender, receiver) = mpsc::channel::<String>();
+//!     let logger = std::thread::spawn(move || {
+//!         for message in receiver {
+//!             println!("{}", message);
+//!         }
+//!     });
+//!
+//!     // Dropping the sender stops the logger after it has printed all messages
+//!     cx.on_cleanup(move || {
+//!         drop(sender);
+//!         let _ = logger.join();
+//!     });
+//!
+//!     Ok(())
+//! }
+//! # }
+//! ```
+//!
+//! See the [N-API Lifecycle][napi-docs] documentation for more details.
 //!
 //! [napi-docs]: https://nodejs.org/api/n-api.html#n_api_environment_life_cycle_apis
 
+#[cfg(feature = "napi-6")]
 use std::mem;
+use std::os::raw::c_void;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
+#[cfg(feature = "napi-8")]
+use neon_runtime::lifecycle::AsyncCleanupHandle;
+use neon_runtime::raw;
+#[cfg(feature = "napi-6")]
 use neon_runtime::raw::Env;
-use neon_runtime::reference;
+#[cfg(feature = "napi-8")]
+use neon_runtime::scope::Root;
+#[cfg(feature = "napi-6")]
 use neon_runtime::tsfn::ThreadsafeFunction;
+#[cfg(feature = "napi-6")]
+use neon_runtime::{promise, reference};
 
 use crate::context::Context;
-#[cfg(all(feature = "channel-api"))]
+#[cfg(all(feature = "napi-6", feature = "channel-api"))]
 use crate::event::Channel;
+#[cfg(feature = "napi-6")]
+use crate::event::NodeApiAsyncResource;
+#[cfg(feature = "napi-8")]
+use crate::executor::BoxFuture;
+#[cfg(feature = "napi-6")]
+use crate::executor::{self, Executor};
+#[cfg(feature = "napi-6")]
 use crate::handle::root::NapiRef;
+#[cfg(feature = "napi-6")]
+use crate::object::class::ClassMap;
+#[cfg(feature = "napi-6")]
+use crate::types::promise::NodeApiDeferred;
 
+#[cfg(feature = "napi-6")]
 /// `InstanceData` holds Neon data associated with a particular instance of a
 /// native module. If a module is loaded multiple times (e.g., worker threads), this
 /// data will be unique per instance.
 pub(crate) struct InstanceData {
-    /// Used to free `Root` in the same JavaScript environment that created it
+    /// Used to free `Root`, destroy `AsyncResource` and settle dropped `Deferred` in
+    /// the same JavaScript environment that created it
     ///
     /// _Design Note_: An `Arc` ensures the `ThreadsafeFunction` outlives the unloading
     /// of a module. Since it is unlikely that modules will be re-loaded frequently, this
     /// could be replaced with a leaked `&'static ThreadsafeFunction<NapiRef>`. However,
     /// given the cost of FFI, this optimization is omitted until the cost of an
     /// `Arc` is demonstrated as significant.
-    drop_queue: Arc<ThreadsafeFunction<NapiRef>>,
+    drop_queue: Arc<ThreadsafeFunction<DropData>>,
 
     /// Shared `Channel` that is cloned to be returned by the `cx.channel()` method
-    #[cfg(all(feature = "channel-api"))]
+    #[cfg(feature = "channel-api")]
     shared_channel: Channel,
+
+    /// Classes defined in this instance. The references to their constructors are
+    /// deleted when the instance data is finalized.
+    class_map: ClassMap,
+
+    /// Runtime for futures spawned by this instance, created when it is first used and
+    /// shut down by a cleanup hook when the environment is torn down
+    runtime: Option<Box<dyn Executor>>,
+
+    /// Number of async cleanup hooks that have been called but have not completed
+    #[cfg(feature = "napi-8")]
+    pending_cleanups: usize,
+
+    /// Set when the runtime is shut down while async cleanup hooks are pending, which
+    /// may still be running futures on it. The last hook to complete shuts it down.
+    #[cfg(feature = "napi-8")]
+    runtime_shutdown_deferred: bool,
 }
 
-fn drop_napi_ref(env: Option<Env>, data: NapiRef) {
-    if let Some(env) = env {
-        unsafe {
-            reference::unreference(env, mem::transmute(data));
+/// Wrapper for raw Node-API values to be dropped on the main thread
+#[cfg(feature = "napi-6")]
+pub(crate) enum DropData {
+    Deferred(NodeApiDeferred),
+    Ref(NapiRef),
+    AsyncResource(NodeApiAsyncResource),
+}
+
+#[cfg(feature = "napi-6")]
+impl DropData {
+    /// Drop a value on the main thread
+    fn drop(env: Option<Env>, data: Self) {
+        if let Some(env) = env {
+            unsafe {
+                match data {
+                    DropData::Deferred(data) => promise::reject_err_message(
+                        env,
+                        data.into_inner(),
+                        "`neon::types::Deferred` was dropped without being settled",
+                    ),
+                    DropData::Ref(data) => reference::unreference(env, mem::transmute(data)),
+                    DropData::AsyncResource(data) => data.destroy(env),
+                }
+            }
         }
     }
 }
 
+#[cfg(feature = "napi-6")]
 impl InstanceData {
     /// Return the data associated with this module instance, lazily initializing if
     /// necessary.
@@ -63,12 +155,12 @@ impl InstanceData {
         }
 
         let drop_queue = unsafe {
-            let queue = ThreadsafeFunction::new(env, drop_napi_ref);
+            let queue = ThreadsafeFunction::new(env, DropData::drop);
             queue.unref(env);
             queue
         };
 
-        #[cfg(all(feature = "channel-api"))]
+        #[cfg(feature = "channel-api")]
         let shared_channel = {
             let mut channel = Channel::new(cx);
             channel.unref(cx);
@@ -77,24 +169,384 @@ impl InstanceData {
 
         let data = InstanceData {
             drop_queue: Arc::new(drop_queue),
-            #[cfg(all(feature = "channel-api"))]
+            #[cfg(feature = "channel-api")]
             shared_channel,
+            class_map: ClassMap::new(env),
+            runtime: None,
+            #[cfg(feature = "napi-8")]
+            pending_cleanups: 0,
+            #[cfg(feature = "napi-8")]
+            runtime_shutdown_deferred: false,
         };
 
         unsafe { &mut *neon_runtime::lifecycle::set_instance_data(env, data) }
     }
 
+    /// Helper to return a reference to the `class_map` field of `InstanceData`
+    pub(crate) fn class_map<'a, C: Context<'a>>(cx: &mut C) -> &'a mut ClassMap {
+        &mut InstanceData::get(cx).class_map
+    }
+
     /// Helper to return a reference to the `drop_queue` field of `InstanceData`
-    pub(crate) fn drop_queue<'a, C: Context<'a>>(cx: &mut C) -> Arc<ThreadsafeFunction<NapiRef>> {
+    pub(crate) fn drop_queue<'a, C: Context<'a>>(cx: &mut C) -> Arc<ThreadsafeFunction<DropData>> {
         Arc::clone(&InstanceData::get(cx).drop_queue)
     }
 
     /// Clones the shared channel and references it since new channels should start
     /// referenced, but the shared channel is unreferenced.
-    #[cfg(all(feature = "channel-api"))]
+    #[cfg(feature = "channel-api")]
     pub(crate) fn channel<'a, C: Context<'a>>(cx: &mut C) -> Channel {
         let mut channel = InstanceData::get(cx).shared_channel.clone();
         channel.reference(cx);
         channel
     }
+
+    /// Returns the runtime of this instance, creating the default runtime if one has
+    /// not been installed with `set_runtime`.
+    pub(crate) fn runtime<'a, C: Context<'a>>(cx: &mut C) -> &'a dyn Executor {
+        if InstanceData::get(cx).runtime.is_none() {
+            let _ = InstanceData::set_runtime(cx, executor::default_runtime());
+        }
+
+        InstanceData::get(cx)
+            .runtime
+            .as_deref()
+            .expect("Runtime should be initialized")
+    }
+
+    /// Installs the runtime of this instance and registers a hook to shut it down when the
+    /// environment is torn down. Returns the runtime if one was already installed.
+    pub(crate) fn set_runtime<'a, C: Context<'a>>(
+        cx: &mut C,
+        runtime: Box<dyn Executor>,
+    ) -> Result<(), Box<dyn Executor>> {
+        let env = cx.env().to_raw();
+        let data = InstanceData::get(cx);
+
+        if data.runtime.is_some() {
+            return Err(runtime);
+        }
+
+        data.runtime = Some(runtime);
+
+        unsafe {
+            neon_runtime::lifecycle::add_cleanup_hook(env, shutdown_runtime, env.cast());
+        }
+
+        Ok(())
+    }
+}
+
+/// Cleanup hook that shuts down the runtime of the instance associated with an `Env`.
+/// Hooks run before the instance data is finalized.
+#[cfg(feature = "napi-6")]
+unsafe extern "C" fn shutdown_runtime(env: *mut c_void) {
+    let data = match neon_runtime::lifecycle::get_instance_data::<InstanceData>(env.cast()).as_mut()
+    {
+        Some(data) => data,
+        None => return,
+    };
+
+    #[cfg(feature = "napi-8")]
+    if data.pending_cleanups > 0 {
+        data.runtime_shutdown_deferred = true;
+        return;
+    }
+
+    if let Some(runtime) = data.runtime.take() {
+        runtime.shutdown();
+    }
+}
+
+/// Handle to a hook registered with [`Context::on_cleanup`](crate::context::Context::on_cleanup)
+/// that can deregister it before the environment is torn down.
+///
+/// Dropping the handle does not deregister the hook.
+pub struct CleanupHook {
+    env: raw::Env,
+    hook: *mut Hook,
+    called: Arc<AtomicBool>,
+}
+
+// # Safety
+// `env` and `hook` are only used by `remove`, which requires a `Context` of the same `Env`.
+unsafe impl Send for CleanupHook {}
+unsafe impl Sync for CleanupHook {}
+
+impl std::fmt::Debug for CleanupHook {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("CleanupHook")
+    }
+}
+
+struct Hook {
+    f: Box<dyn FnOnce()>,
+    called: Arc<AtomicBool>,
+}
+
+impl CleanupHook {
+    pub(crate) fn new<'a, C: Context<'a>, F: FnOnce() + 'static>(cx: &mut C, f: F) -> Self {
+        let env = cx.env().to_raw();
+        let called = Arc::new(AtomicBool::new(false));
+        let hook = Box::into_raw(Box::new(Hook {
+            f: Box::new(f),
+            called: Arc::clone(&called),
+        }));
+
+        unsafe {
+            neon_runtime::lifecycle::add_cleanup_hook(env, call_cleanup_hook, hook.cast());
+        }
+
+        Self { env, hook, called }
+    }
+
+    /// Deregisters the hook so that it is not called when the environment is torn down.
+    /// Returns `false` if the hook has already been called.
+    ///
+    /// Panics if `cx` belongs to a different instance of the module than the one that
+    /// registered the hook.
+    pub fn remove<'a, C: Context<'a>>(self, cx: &mut C) -> bool {
+        assert!(
+            cx.env().to_raw() == self.env,
+            "Cleanup hooks must be removed by the module instance that registered them"
+        );
+
+        if self.called.load(Ordering::SeqCst) {
+            return false;
+        }
+
+        unsafe {
+            neon_runtime::lifecycle::remove_cleanup_hook(
+                self.env,
+                call_cleanup_hook,
+                self.hook.cast(),
+            );
+
+            drop(Box::from_raw(self.hook));
+        }
+
+        true
+    }
+}
+
+unsafe extern "C" fn call_cleanup_hook(arg: *mut c_void) {
+    let Hook { f, called } = *Box::from_raw(arg.cast::<Hook>());
+
+    called.store(true, Ordering::SeqCst);
+
+    // A panic has already been reported by the panic hook and must not unwind into Node
+    let _ = catch_unwind(AssertUnwindSafe(f));
+}
+
+/// Handle to a hook registered with
+/// [`Context::on_async_cleanup`](crate::context::Context::on_async_cleanup) that can
+/// deregister it before the environment is torn down.
+///
+/// Dropping the handle does not deregister the hook.
+#[cfg(feature = "napi-8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-8")))]
+pub struct AsyncCleanupHook {
+    env: raw::Env,
+    hook: *mut AsyncHook,
+    handle: AsyncCleanupHandle,
+    called: Arc<AtomicBool>,
+}
+
+// # Safety
+// `env` and `hook` are only used by `remove`, which requires a `Context` of the same `Env`.
+#[cfg(feature = "napi-8")]
+unsafe impl Send for AsyncCleanupHook {}
+#[cfg(feature = "napi-8")]
+unsafe impl Sync for AsyncCleanupHook {}
+
+#[cfg(feature = "napi-8")]
+impl std::fmt::Debug for AsyncCleanupHook {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("AsyncCleanupHook")
+    }
+}
+
+#[cfg(feature = "napi-8")]
+struct AsyncHook {
+    f: Box<dyn FnOnce() -> BoxFuture>,
+    env: raw::Env,
+    // Always `Some` once the hook is registered
+    handle: Option<AsyncCleanupHandle>,
+    called: Arc<AtomicBool>,
+}
+
+#[cfg(feature = "napi-8")]
+impl AsyncCleanupHook {
+    pub(crate) fn new<'a, C, F, Fut>(cx: &mut C, f: F) -> Self
+    where
+        C: Context<'a>,
+        F: FnOnce() -> Fut + 'static,
+        Fut: std::future::Future<Output = ()> + Send + 'static,
+    {
+        let env = cx.env().to_raw();
+        let called = Arc::new(AtomicBool::new(false));
+
+        // Creates the runtime before registering the hook, so that the runtime is shut
+        // down after the hook has been called
+        InstanceData::runtime(cx);
+
+        let hook = Box::into_raw(Box::new(AsyncHook {
+            f: Box::new(move || Box::pin(f()) as BoxFuture),
+            env,
+            handle: None,
+            called: Arc::clone(&called),
+        }));
+
+        let handle = unsafe {
+            let handle = neon_runtime::lifecycle::add_async_cleanup_hook(
+                env,
+                call_async_cleanup_hook,
+                hook.cast(),
+            );
+
+            (*hook).handle = Some(handle);
+            handle
+        };
+
+        Self {
+            env,
+            hook,
+            handle,
+            called,
+        }
+    }
+
+    /// Deregisters the hook so that it is not called when the environment is torn down.
+    /// Returns `false` if the hook has already been called.
+    ///
+    /// Panics if `cx` belongs to a different instance of the module than the one that
+    /// registered the hook.
+    pub fn remove<'a, C: Context<'a>>(self, cx: &mut C) -> bool {
+        assert!(
+            cx.env().to_raw() == self.env,
+            "Cleanup hooks must be removed by the module instance that registered them"
+        );
+
+        if self.called.load(Ordering::SeqCst) {
+            return false;
+        }
+
+        unsafe {
+            neon_runtime::lifecycle::remove_async_cleanup_hook(self.handle);
+            drop(Box::from_raw(self.hook));
+        }
+
+        true
+    }
+}
+
+/// Starts the future of an async cleanup hook on the runtime of the instance. The
+/// environment is torn down after the future completes, panics or is dropped.
+#[cfg(feature = "napi-8")]
+unsafe extern "C" fn call_async_cleanup_hook(
+    _handle: raw::AsyncCleanupHookHandle,
+    arg: *mut c_void,
+) {
+    let AsyncHook {
+        f,
+        env,
+        handle,
+        called,
+    } = *Box::from_raw(arg.cast::<AsyncHook>());
+
+    called.store(true, Ordering::SeqCst);
+
+    // The instance data was created by `AsyncCleanupHook::new`
+    let data = neon_runtime::lifecycle::get_instance_data::<InstanceData>(env)
+        .as_mut()
+        .expect("Instance data should be initialized");
+    let completion = AsyncCompletion::new(env, handle.expect("Hook should be registered"));
+
+    data.pending_cleanups += 1;
+
+    // A panic has already been reported by the panic hook. Dropping `completion`
+    // completes the hook.
+    let future = match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(future) => future,
+        Err(_) => return,
+    };
+
+    if let Some(runtime) = data.runtime.as_deref() {
+        runtime.spawn(Box::pin(async move {
+            let _completion = completion;
+
+            future.await;
+        }));
+    }
+}
+
+/// Completes an async cleanup hook on the JavaScript thread when dropped.
+#[cfg(feature = "napi-8")]
+struct AsyncCompletion {
+    tsfn: ThreadsafeFunction<PendingCleanup>,
+    cleanup: Option<PendingCleanup>,
+}
+
+#[cfg(feature = "napi-8")]
+struct PendingCleanup {
+    env: raw::Env,
+    handle: AsyncCleanupHandle,
+}
+
+// # Safety
+// `env` is only used on the JavaScript thread, which is kept alive until the hook is removed.
+#[cfg(feature = "napi-8")]
+unsafe impl Send for PendingCleanup {}
+
+#[cfg(feature = "napi-8")]
+impl AsyncCompletion {
+    /// # Safety
+    /// Must be called on the JavaScript thread of `env` while it is being torn down
+    unsafe fn new(env: raw::Env, handle: AsyncCleanupHandle) -> Self {
+        // Threadsafe functions created earlier are closed by their own cleanup hooks, but
+        // the event loop keeps running until the async cleanup hooks are removed. Node
+        // seals the handle scope of cleanup hooks.
+        let mut scope = raw::HandleScope::new();
+
+        scope.enter(env);
+
+        let tsfn = ThreadsafeFunction::new(env, complete_async_cleanup);
+
+        scope.exit(env);
+
+        Self {
+            tsfn,
+            cleanup: Some(PendingCleanup { env, handle }),
+        }
+    }
+}
+
+#[cfg(feature = "napi-8")]
+impl Drop for AsyncCompletion {
+    fn drop(&mut self) {
+        if let Some(cleanup) = self.cleanup.take() {
+            let _ = self.tsfn.call(cleanup, None);
+        }
+    }
+}
+
+#[cfg(feature = "napi-8")]
+fn complete_async_cleanup(_env: Option<Env>, cleanup: PendingCleanup) {
+    let PendingCleanup { env, handle } = cleanup;
+
+    unsafe {
+        let data = neon_runtime::lifecycle::get_instance_data::<InstanceData>(env).as_mut();
+
+        if let Some(data) = data {
+            data.pending_cleanups -= 1;
+
+            if data.pending_cleanups == 0 && data.runtime_shutdown_deferred {
+                if let Some(runtime) = data.runtime.take() {
+                    runtime.shutdown();
+                }
+            }
+        }
+
+        neon_runtime::lifecycle::remove_async_cleanup_hook(handle);
+    }
 }
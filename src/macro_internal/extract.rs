@@ -0,0 +1,129 @@
+//! Helpers for the code generated by `#[derive(FromJs, IntoJs)]`
+
+use crate::context::Context;
+use crate::handle::Handle;
+use crate::object::Object;
+use crate::result::{JsResult, NeonResult};
+use crate::types::extract::{own_keys, ConversionError, TryFromJs, TryIntoJs};
+use crate::types::{JsArray, JsObject, JsString, JsUndefined, JsValue};
+
+/// Downcasts the value being converted to an object.
+pub fn object<'a, C: Context<'a>>(
+    cx: &mut C,
+    value: Handle<'a, JsValue>,
+) -> Result<Handle<'a, JsObject>, ConversionError> {
+    value
+        .downcast::<JsObject, _>(cx)
+        .map_err(|_| ConversionError::new(cx, "an object", value))
+}
+
+/// Downcasts the value being converted to an array.
+pub fn array<'a, C: Context<'a>>(
+    cx: &mut C,
+    value: Handle<'a, JsValue>,
+) -> Result<Handle<'a, JsArray>, ConversionError> {
+    value
+        .downcast::<JsArray, _>(cx)
+        .map_err(|_| ConversionError::new(cx, "an array", value))
+}
+
+/// Converts the property `key` of `object`.
+pub fn field<'a, C: Context<'a>, T: TryFromJs<'a>>(
+    cx: &mut C,
+    object: Handle<'a, JsObject>,
+    key: &str,
+) -> NeonResult<Result<T, ConversionError>> {
+    let value = object.get(cx, key)?;
+
+    Ok(T::try_from_js(cx, value)?.map_err(|err| err.at_key(key)))
+}
+
+/// Converts the property `key` of `object`, or calls `default` if it is `undefined`.
+pub fn field_or_else<'a, C: Context<'a>, T: TryFromJs<'a>, F: FnOnce() -> T>(
+    cx: &mut C,
+    object: Handle<'a, JsObject>,
+    key: &str,
+    default: F,
+) -> NeonResult<Result<T, ConversionError>> {
+    let value = object.get(cx, key)?;
+
+    if value.is_a::<JsUndefined, _>(cx) {
+        return Ok(Ok(default()));
+    }
+
+    Ok(T::try_from_js(cx, value)?.map_err(|err| err.at_key(key)))
+}
+
+/// Converts the element at `index` of `array`.
+pub fn element<'a, C: Context<'a>, T: TryFromJs<'a>>(
+    cx: &mut C,
+    array: Handle<'a, JsArray>,
+    index: u32,
+) -> NeonResult<Result<T, ConversionError>> {
+    let value = array.get(cx, index)?;
+
+    Ok(T::try_from_js(cx, value)?.map_err(|err| err.at_index(index)))
+}
+
+/// Returns the only own property of an externally tagged enum, e.g., `{ circle: 1 }`.
+pub fn only_key<'a, C: Context<'a>>(
+    cx: &mut C,
+    object: Handle<'a, JsObject>,
+    expected: &str,
+) -> NeonResult<Result<String, ConversionError>> {
+    let keys = own_keys(cx, object)?.to_vec(cx)?;
+
+    if keys.len() != 1 {
+        return Ok(Err(ConversionError::new(cx, expected, object.upcast())));
+    }
+
+    Ok(Ok(keys[0].downcast_or_throw::<JsString, _>(cx)?.value(cx)))
+}
+
+/// Reports an unknown enum variant `value`, e.g., `one of "circle", "square"`.
+pub fn unknown_variant<'a, C: Context<'a>>(
+    cx: &mut C,
+    value: Handle<'a, JsValue>,
+    expected: &str,
+) -> ConversionError {
+    ConversionError::new(cx, expected, value)
+}
+
+/// Converts `value` and sets it as the property `key` of `object`.
+pub fn set_field<'a, C: Context<'a>, T: TryIntoJs<'a>>(
+    cx: &mut C,
+    object: Handle<'a, JsObject>,
+    key: &str,
+    value: T,
+) -> NeonResult<()> {
+    let value = value.try_into_js(cx)?;
+
+    object.set(cx, key, value)?;
+
+    Ok(())
+}
+
+/// Converts `value` and sets it as the element `index` of `array`.
+pub fn set_element<'a, C: Context<'a>, T: TryIntoJs<'a>>(
+    cx: &mut C,
+    array: Handle<'a, JsArray>,
+    index: u32,
+    value: T,
+) -> NeonResult<()> {
+    let value = value.try_into_js(cx)?;
+
+    array.set(cx, index, value)?;
+
+    Ok(())
+}
+
+/// Converts `value`, which must become an object, as with the content of an internally
+/// tagged enum.
+pub fn into_object<'a, C: Context<'a>, T: TryIntoJs<'a>>(
+    cx: &mut C,
+    value: T,
+) -> JsResult<'a, JsObject> {
+    let value = value.try_into_js(cx)?.upcast::<JsValue>();
+
+    value.downcast_or_throw(cx)
+}
@@ -1,12 +1,58 @@
 //! Internals needed by macros. These have to be exported for the macros to work
 pub use crate::context::internal::{initialize_module, Env};
+#[cfg(feature = "napi-1")]
+pub use crate::object::class::internal::{member_context, this_internals, Accessor};
 /// but are subject to change and should never be explicitly used.
-
-#[cfg(feature = "legacy-runtime")]
 // Used by the class macro.
 pub use crate::object::class::internal::{
     AllocateCallback, ConstructCallback, ConstructorCallCallback, MethodCallback,
 };
+#[cfg(feature = "napi-1")]
+pub use crate::types::internal::FunctionCallback;
+
+#[cfg(all(feature = "napi-6", feature = "channel-api"))]
+pub use crate::executor::spawn_promise;
+
+#[cfg(feature = "napi-1")]
+pub mod extract;
+
+#[cfg(feature = "export-macro")]
+use crate::context::{Context, ModuleContext};
+#[cfg(feature = "export-macro")]
+use crate::result::NeonResult;
+#[cfg(feature = "export-macro")]
+pub use linkme;
+#[cfg(feature = "export-macro")]
+use neon_runtime::raw;
+
+#[cfg(feature = "export-macro")]
+/// Exports registered with `#[neon::export]`, collected from the whole crate at link time
+/// and run by `initialize_module` before the `#[neon::main]` function.
+#[linkme::distributed_slice]
+pub static EXPORTS: [for<'a> fn(&mut ModuleContext<'a>) -> NeonResult<()>];
+
+#[cfg(feature = "export-macro")]
+/// The function marked with `#[neon::main]`, if any. Neon registers the module itself,
+/// so that exports are registered even if the crate has no main function.
+#[linkme::distributed_slice]
+pub static MAIN: [fn(ModuleContext) -> NeonResult<()>];
+
+#[cfg(feature = "export-macro")]
+fn main(mut cx: ModuleContext) -> NeonResult<()> {
+    match *MAIN {
+        [] => Ok(()),
+        [main] => main(cx),
+        _ => cx.throw_error("Only one function may be marked with `#[neon::main]`"),
+    }
+}
+
+#[cfg(feature = "export-macro")]
+#[no_mangle]
+unsafe extern "C" fn napi_register_module_v1(env: raw::Env, m: raw::Local) -> raw::Local {
+    initialize_module(env, std::mem::transmute(m), main);
+
+    m
+}
 
 // An alias for neon_runtime so macros can refer to it.
 pub mod runtime {
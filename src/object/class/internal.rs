@@ -1,19 +1,48 @@
-use super::{Callback, Class, ClassInternal};
+#[cfg(feature = "legacy-runtime")]
+use super::ClassInternal;
+use super::{Callback, Class};
 use crate::context::internal::{ContextInternal, Env};
+#[cfg(feature = "napi-1")]
+use crate::context::CallKind;
+#[cfg(feature = "napi-1")]
+use crate::context::FunctionContext;
 use crate::context::{CallContext, CallbackInfo, Context};
 use crate::handle::{Handle, Managed};
-use crate::result::{JsResult, NeonResult, Throw};
+#[cfg(feature = "napi-1")]
+use crate::object::This;
+#[cfg(feature = "legacy-runtime")]
+use crate::result::Throw;
+use crate::result::{JsResult, NeonResult};
+#[cfg(feature = "legacy-runtime")]
+use crate::types::build;
 use crate::types::error::convert_panics;
-use crate::types::{build, JsFunction, JsObject, JsUndefined, JsValue};
+#[cfg(feature = "napi-1")]
+use crate::types::internal::ValueInternal;
+use crate::types::{JsFunction, JsObject, JsUndefined, JsValue};
 use neon_runtime;
 use neon_runtime::raw;
+#[cfg(feature = "napi-1")]
+use std::any::TypeId;
+#[cfg(feature = "napi-1")]
+use std::cell::RefCell;
+#[cfg(feature = "napi-1")]
+use std::collections::HashMap;
 use std::mem;
 use std::os::raw::c_void;
 use std::ptr::null_mut;
 
+#[cfg(feature = "napi-1")]
+thread_local! {
+    // The class of each object wrapped by a Neon constructor on this thread, keyed by the
+    // address of its internals. This brands instances without reading data that may have
+    // been wrapped by another native module.
+    static INSTANCES: RefCell<HashMap<usize, TypeId>> = RefCell::new(HashMap::new());
+}
+
 #[repr(C)]
 pub struct MethodCallback<T: Class>(pub fn(CallContext<T>) -> JsResult<JsValue>);
 
+#[cfg(feature = "legacy-runtime")]
 impl<T: Class> Callback<()> for MethodCallback<T> {
     extern "C" fn invoke(env: Env, info: CallbackInfo<'_>) {
         unsafe {
@@ -22,12 +51,7 @@ impl<T: Class> Callback<()> for MethodCallback<T> {
                 let this: Handle<JsValue> =
                     Handle::new_internal(JsValue::from_raw(env, info.this(&mut cx)));
 
-                #[cfg(feature = "legacy-runtime")]
-                let is_a_t = this.is_a::<T>();
-                #[cfg(feature = "napi-1")]
-                let is_a_t = this.is_a::<T, _>(&mut cx);
-
-                if !is_a_t {
+                if !this.is_a::<T>() {
                     if let Ok(metadata) = T::metadata(&mut cx) {
                         neon_runtime::class::throw_this_error(
                             mem::transmute(cx.env()),
@@ -51,11 +75,103 @@ impl<T: Class> Callback<()> for MethodCallback<T> {
     }
 }
 
+/// Invokes a method `kernel` of a class, after checking that `this` is an instance.
+#[cfg(feature = "napi-1")]
+unsafe fn invoke_method<T: Class>(
+    env: Env,
+    info: &CallbackInfo<'_>,
+    kernel: fn(CallContext<T>) -> JsResult<JsValue>,
+) -> raw::Local {
+    info.with_cx::<T, _, _>(env, |mut cx| {
+        let this: Handle<JsValue> =
+            Handle::new_internal(JsValue::from_raw(env, info.this(&mut cx)));
+
+        if !this.is_a::<T, _>(&mut cx) {
+            let msg = format!("this is not an object of type {}.", T::name());
+            let _ = cx.throw_type_error::<_, ()>(msg);
+            return null_mut();
+        };
+        if let Ok(value) = convert_panics(env, || kernel(cx)) {
+            value.to_raw()
+        } else {
+            null_mut()
+        }
+    })
+}
+
+#[cfg(feature = "napi-1")]
+impl<T: Class> Callback<raw::Local> for MethodCallback<T> {
+    extern "C" fn invoke(env: Env, info: CallbackInfo<'_>) -> raw::Local {
+        unsafe {
+            let data = info.data(env);
+            let kernel: fn(CallContext<T>) -> JsResult<JsValue> =
+                mem::transmute(neon_runtime::fun::get_dynamic_callback(env.to_raw(), data));
+
+            invoke_method(env, &info, kernel)
+        }
+    }
+
+    fn into_ptr(self) -> *mut c_void {
+        self.0 as *mut c_void
+    }
+}
+
+/// The getter and setter of an accessor property of a class. A setter receives the
+/// assigned value as its only argument.
+#[cfg(feature = "napi-1")]
+pub struct Accessor<T: Class> {
+    pub get: Option<MethodCallback<T>>,
+    pub set: Option<MethodCallback<T>>,
+}
+
+#[cfg(feature = "napi-1")]
+pub(crate) struct AccessorGetter<T: Class>(pub(crate) &'static Accessor<T>);
+
+#[cfg(feature = "napi-1")]
+impl<T: Class> Callback<raw::Local> for AccessorGetter<T> {
+    extern "C" fn invoke(env: Env, info: CallbackInfo<'_>) -> raw::Local {
+        unsafe {
+            let accessor = &*(info.data(env) as *const Accessor<T>);
+
+            match &accessor.get {
+                Some(kernel) => invoke_method(env, &info, kernel.0),
+                None => null_mut(),
+            }
+        }
+    }
+
+    fn into_ptr(self) -> *mut c_void {
+        self.0 as *const Accessor<T> as *mut c_void
+    }
+}
+
+#[cfg(feature = "napi-1")]
+pub(crate) struct AccessorSetter<T: Class>(pub(crate) &'static Accessor<T>);
+
+#[cfg(feature = "napi-1")]
+impl<T: Class> Callback<raw::Local> for AccessorSetter<T> {
+    extern "C" fn invoke(env: Env, info: CallbackInfo<'_>) -> raw::Local {
+        unsafe {
+            let accessor = &*(info.data(env) as *const Accessor<T>);
+
+            match &accessor.set {
+                Some(kernel) => invoke_method(env, &info, kernel.0),
+                None => null_mut(),
+            }
+        }
+    }
+
+    fn into_ptr(self) -> *mut c_void {
+        self.0 as *const Accessor<T> as *mut c_void
+    }
+}
+
 #[repr(C)]
 pub struct ConstructorCallCallback(pub fn(CallContext<JsValue>) -> JsResult<JsValue>);
 
 impl ConstructorCallCallback {
     pub(crate) fn default<T: Class>() -> Self {
+        #[cfg(feature = "legacy-runtime")]
         fn callback<T: Class>(mut cx: CallContext<JsValue>) -> JsResult<JsValue> {
             unsafe {
                 if let Ok(metadata) = T::metadata(&mut cx) {
@@ -68,10 +184,16 @@ impl ConstructorCallCallback {
             Err(Throw)
         }
 
+        #[cfg(feature = "napi-1")]
+        fn callback<T: Class>(mut cx: CallContext<JsValue>) -> JsResult<JsValue> {
+            cx.throw_type_error(format!("{} constructor called without new.", T::name()))
+        }
+
         ConstructorCallCallback(callback::<T>)
     }
 }
 
+#[cfg(feature = "legacy-runtime")]
 impl Callback<()> for ConstructorCallCallback {
     extern "C" fn invoke(env: Env, info: CallbackInfo<'_>) {
         unsafe {
@@ -94,6 +216,7 @@ impl Callback<()> for ConstructorCallCallback {
 #[repr(C)]
 pub struct AllocateCallback<T: Class>(pub fn(CallContext<JsUndefined>) -> NeonResult<T::Internals>);
 
+#[cfg(feature = "legacy-runtime")]
 impl<T: Class> Callback<*mut c_void> for AllocateCallback<T> {
     extern "C" fn invoke(env: Env, info: CallbackInfo<'_>) -> *mut c_void {
         unsafe {
@@ -121,6 +244,7 @@ pub struct ConstructCallback<T: Class>(
     pub fn(CallContext<T>) -> NeonResult<Option<Handle<JsObject>>>,
 );
 
+#[cfg(feature = "legacy-runtime")]
 impl<T: Class> Callback<bool> for ConstructCallback<T> {
     extern "C" fn invoke(env: Env, info: CallbackInfo<'_>) -> bool {
         unsafe {
@@ -145,6 +269,153 @@ impl<T: Class> Callback<bool> for ConstructCallback<T> {
     }
 }
 
+/// The constructor of a class, which owns the kernels that implement its behavior.
+#[cfg(feature = "napi-1")]
+pub(crate) struct ConstructorCallback<T: Class> {
+    pub(crate) allocate: AllocateCallback<T>,
+    pub(crate) construct: Option<ConstructCallback<T>>,
+    pub(crate) call: ConstructorCallCallback,
+}
+
+#[cfg(feature = "napi-1")]
+impl<T: Class> ConstructorCallback<T> {
+    unsafe fn construct(&self, env: Env, info: &CallbackInfo<'_>) -> raw::Local {
+        let allocate = self.allocate.0;
+        let internals = match info.with_cx(env, |cx| convert_panics(env, || allocate(cx))) {
+            Ok(internals) => internals,
+            Err(_) => return null_mut(),
+        };
+
+        let this = info.with_cx::<JsValue, _, _>(env, |mut cx| info.this(&mut cx));
+        let instance = Box::into_raw(Box::new(internals));
+
+        if !neon_runtime::class::wrap(
+            env.to_raw(),
+            this,
+            instance.cast(),
+            drop_instance::<T::Internals>,
+        ) {
+            drop(Box::from_raw(instance));
+            return info.with_cx::<JsValue, _, _>(env, |mut cx| {
+                let _ = cx.throw_error::<_, ()>("failed to wrap class instance");
+                null_mut()
+            });
+        }
+
+        INSTANCES.with(|instances| {
+            instances
+                .borrow_mut()
+                .insert(instance as usize, TypeId::of::<T>())
+        });
+
+        let construct = match &self.construct {
+            Some(construct) => construct.0,
+            None => return this,
+        };
+
+        info.with_cx::<T, _, _>(env, |cx| match convert_panics(env, || construct(cx)) {
+            Ok(None) => this,
+            Ok(Some(obj)) => obj.to_raw(),
+            Err(_) => {
+                // The instance is unusable, so its internals are dropped eagerly
+                let instance = neon_runtime::class::remove_wrap(env.to_raw(), this);
+                drop_instance::<T::Internals>(env.to_raw(), instance, null_mut());
+                null_mut()
+            }
+        })
+    }
+}
+
+#[cfg(feature = "napi-1")]
+impl<T: Class> Callback<raw::Local> for ConstructorCallback<T> {
+    extern "C" fn invoke(env: Env, info: CallbackInfo<'_>) -> raw::Local {
+        unsafe {
+            let callback = &*(info.data(env) as *const Self);
+
+            match info.with_cx::<JsValue, _, _>(env, |cx| cx.kind()) {
+                CallKind::Construct => callback.construct(env, &info),
+                CallKind::Call => info.with_cx(env, |cx| {
+                    let kernel = callback.call.0;
+                    if let Ok(value) = convert_panics(env, || kernel(cx)) {
+                        value.to_raw()
+                    } else {
+                        null_mut()
+                    }
+                }),
+            }
+        }
+    }
+
+    fn into_ptr(self) -> *mut c_void {
+        Box::into_raw(Box::new(self)).cast()
+    }
+}
+
+/// Frees the data of a class constructor created from a `ConstructorCallback<T>`.
+#[cfg(feature = "napi-1")]
+pub(crate) unsafe fn drop_constructor<T: Class>(data: *mut c_void) {
+    drop(Box::from_raw(data as *mut ConstructorCallback<T>));
+}
+
+#[cfg(feature = "napi-1")]
+unsafe extern "C" fn drop_instance<I>(_env: raw::Env, data: *mut c_void, _hint: *mut c_void) {
+    if data.is_null() {
+        return;
+    }
+
+    // The thread may be exiting if the environment is torn down with it
+    let _ = INSTANCES.try_with(|instances| instances.borrow_mut().remove(&(data as usize)));
+
+    drop(Box::from_raw(data as *mut I));
+}
+
+/// Returns a pointer to the internals of an instance of `T`, or `null` if `obj` is not
+/// an instance of `T` created by its constructor.
+#[cfg(feature = "napi-1")]
+pub(crate) unsafe fn instance_internals<T: Class>(env: Env, obj: raw::Local) -> *mut T::Internals {
+    let internals = neon_runtime::class::unwrap(env.to_raw(), obj);
+
+    if internals.is_null() {
+        return null_mut();
+    }
+
+    let is_instance = INSTANCES.with(|instances| {
+        instances.borrow().get(&(internals as usize)) == Some(&TypeId::of::<T>())
+    });
+
+    if is_instance {
+        internals.cast()
+    } else {
+        null_mut()
+    }
+}
+
+/// Converts the context of a class kernel into the context of a `#[neon::class]`
+/// member, whose `this`-binding is a plain object.
+#[cfg(feature = "napi-1")]
+pub fn member_context<T: This>(cx: CallContext<T>) -> FunctionContext {
+    cx.cast()
+}
+
+/// Returns the internals of the `this`-binding of a method, or throws a `TypeError` if
+/// it is not an instance of `T`.
+///
+/// # Safety
+/// The returned reference must not outlive the call.
+#[cfg(feature = "napi-1")]
+pub unsafe fn this_internals<'b, T: Class>(
+    cx: &mut CallContext<T>,
+) -> NeonResult<&'b T::Internals> {
+    let this = cx.this().to_raw();
+    let internals = instance_internals::<T>(cx.env(), this);
+
+    if internals.is_null() {
+        return cx.throw_type_error(format!("this is not an object of type {}.", T::name()));
+    }
+
+    Ok(&*internals)
+}
+
 #[repr(C)]
 #[derive(Clone, Copy)]
 pub struct ClassMetadata {
@@ -152,6 +423,7 @@ pub struct ClassMetadata {
 }
 
 impl ClassMetadata {
+    #[cfg(feature = "legacy-runtime")]
     pub unsafe fn constructor<'a, T: Class, C: Context<'a>>(
         &self,
         cx: &mut C,
@@ -165,6 +437,17 @@ impl ClassMetadata {
         })
     }
 
+    #[cfg(feature = "napi-1")]
+    pub unsafe fn constructor<'a, T: Class, C: Context<'a>>(
+        &self,
+        cx: &mut C,
+    ) -> JsResult<'a, JsFunction<T>> {
+        let env = cx.env();
+        let local = neon_runtime::reference::get(env.to_raw(), self.pointer.cast());
+        Ok(Handle::new_internal(JsFunction::from_raw(env, local)))
+    }
+
+    #[cfg(feature = "legacy-runtime")]
     pub unsafe fn has_instance(&self, value: raw::Local) -> bool {
         neon_runtime::class::has_instance(self.pointer, value)
     }
@@ -2,6 +2,11 @@
 
 pub(crate) mod internal;
 
+#[cfg(feature = "napi-1")]
+use self::internal::{
+    drop_constructor, instance_internals, Accessor, AccessorGetter, AccessorSetter,
+    ConstructorCallback,
+};
 use self::internal::{
     AllocateCallback, ClassMetadata, ConstructCallback, ConstructorCallCallback, MethodCallback,
 };
@@ -9,36 +14,108 @@ use crate::borrow::{Borrow, BorrowMut, LoanError, Ref, RefMut};
 use crate::context::internal::Env;
 use crate::context::{Context, Lock};
 use crate::handle::{Handle, Managed};
+#[cfg(feature = "napi-6")]
+use crate::lifecycle::InstanceData;
 use crate::object::{Object, This};
-use crate::result::{JsResult, NeonResult, Throw};
+#[cfg(feature = "legacy-runtime")]
+use crate::result::Throw;
+use crate::result::{JsResult, NeonResult};
+#[cfg(feature = "napi-1")]
+use crate::types::internal::FunctionCallback;
 use crate::types::internal::{Callback, ValueInternal};
 use crate::types::{build, JsFunction, JsValue, Value};
 use neon_runtime;
+#[cfg(feature = "napi-1")]
+use neon_runtime::class::Member;
 use neon_runtime::raw;
 use std::any::{Any, TypeId};
+#[cfg(feature = "napi-1")]
+use std::collections::BTreeMap;
 use std::collections::HashMap;
+#[cfg(feature = "legacy-runtime")]
 use std::mem;
 use std::os::raw::c_void;
+#[cfg(feature = "legacy-runtime")]
 use std::slice;
+#[cfg(feature = "napi-1")]
+use std::sync::Mutex;
+
+// Class names are the same in every environment and on every thread, but
+// `ValueInternal::name` is not given access to one.
+#[cfg(feature = "napi-1")]
+static CLASS_NAMES: Mutex<BTreeMap<TypeId, String>> = Mutex::new(BTreeMap::new());
 
 pub(crate) struct ClassMap {
     map: HashMap<TypeId, ClassMetadata>,
+    #[cfg(feature = "napi-1")]
+    env: raw::Env,
+    #[cfg(feature = "napi-1")]
+    data: Vec<(*mut c_void, FreeData)>,
 }
 
+/// Frees the data of a class constructor
+#[cfg(feature = "napi-1")]
+type FreeData = unsafe fn(*mut c_void);
+
+// A class map is only used and dropped on the JavaScript thread of its environment
+#[cfg(feature = "napi-6")]
+unsafe impl Send for ClassMap {}
+
 impl ClassMap {
+    #[cfg(feature = "legacy-runtime")]
     pub(crate) fn new() -> ClassMap {
         ClassMap {
             map: HashMap::new(),
         }
     }
 
+    #[cfg(feature = "napi-1")]
+    pub(crate) fn new(env: raw::Env) -> ClassMap {
+        ClassMap {
+            map: HashMap::new(),
+            env,
+            data: Vec::new(),
+        }
+    }
+
     pub(crate) fn get(&self, key: &TypeId) -> Option<&ClassMetadata> {
         self.map.get(key)
     }
 
+    #[cfg(feature = "legacy-runtime")]
     pub(crate) fn set(&mut self, key: TypeId, val: ClassMetadata) {
         self.map.insert(key, val);
     }
+
+    /// Registers the class `key`. The map owns the reference to its constructor in
+    /// `val` and the `data` of the constructor, which is freed with `free_data`.
+    #[cfg(feature = "napi-1")]
+    pub(crate) fn set(
+        &mut self,
+        key: TypeId,
+        val: ClassMetadata,
+        data: *mut c_void,
+        free_data: FreeData,
+    ) {
+        self.map.insert(key, val);
+        self.data.push((data, free_data));
+    }
+}
+
+#[cfg(feature = "napi-1")]
+impl Drop for ClassMap {
+    fn drop(&mut self) {
+        // The environment is being torn down, so the constructors can no longer be called
+        unsafe {
+            for metadata in self.map.values() {
+                neon_runtime::reference::unreference(self.env, metadata.pointer.cast());
+            }
+
+            for (data, free_data) in self.data.drain(..) {
+                free_data(data);
+            }
+        }
+    }
 }
 
 #[doc(hidden)]
@@ -48,6 +125,12 @@ pub struct ClassDescriptor<'a, T: Class> {
     call: Option<ConstructorCallCallback>,
     construct: Option<ConstructCallback<T>>,
     methods: Vec<(&'a str, MethodCallback<T>)>,
+    #[cfg(feature = "napi-1")]
+    static_methods: Vec<(&'a str, FunctionCallback<JsValue>)>,
+    #[cfg(feature = "napi-1")]
+    accessors: Vec<(&'a str, &'static Accessor<T>)>,
+    #[cfg(feature = "napi-1")]
+    to_string_tag: Option<&'a str>,
 }
 
 impl<'a, T: Class> ClassDescriptor<'a, T> {
@@ -59,6 +142,12 @@ impl<'a, T: Class> ClassDescriptor<'a, T> {
             call: None,
             construct: None,
             methods: Vec::new(),
+            #[cfg(feature = "napi-1")]
+            static_methods: Vec::new(),
+            #[cfg(feature = "napi-1")]
+            accessors: Vec::new(),
+            #[cfg(feature = "napi-1")]
+            to_string_tag: None,
         }
     }
 
@@ -79,8 +168,31 @@ impl<'a, T: Class> ClassDescriptor<'a, T> {
         self.methods.push((name, callback));
         self
     }
+
+    #[cfg(feature = "napi-1")]
+    /// Adds a method of the constructor to this class descriptor.
+    pub fn static_method(mut self, name: &'a str, callback: FunctionCallback<JsValue>) -> Self {
+        self.static_methods.push((name, callback));
+        self
+    }
+
+    #[cfg(feature = "napi-1")]
+    /// Adds an accessor property to this class descriptor.
+    pub fn accessor(mut self, name: &'a str, accessor: &'static Accessor<T>) -> Self {
+        self.accessors.push((name, accessor));
+        self
+    }
+
+    #[cfg(feature = "napi-1")]
+    /// Sets the `Symbol.toStringTag` of instances of this class, which is used by
+    /// `Object.prototype.toString`.
+    pub fn to_string_tag(mut self, tag: &'a str) -> Self {
+        self.to_string_tag = Some(tag);
+        self
+    }
 }
 
+#[cfg(feature = "legacy-runtime")]
 extern "C" fn drop_internals<T>(internals: *mut c_void) {
     let p: Box<T> = unsafe { Box::from_raw(internals.cast()) };
     mem::drop(p);
@@ -133,10 +245,18 @@ unsafe impl<T: Class> This for T {
 impl<T: Class> Object for T {}
 
 pub(crate) trait ClassInternal: Class {
+    #[cfg(not(feature = "napi-6"))]
     fn metadata_opt<'a, C: Context<'a>>(cx: &mut C) -> Option<ClassMetadata> {
         cx.env().class_map().get(&TypeId::of::<Self>()).copied()
     }
 
+    #[cfg(feature = "napi-6")]
+    fn metadata_opt<'a, C: Context<'a>>(cx: &mut C) -> Option<ClassMetadata> {
+        InstanceData::class_map(cx)
+            .get(&TypeId::of::<Self>())
+            .copied()
+    }
+
     fn metadata<'a, C: Context<'a>>(cx: &mut C) -> NeonResult<ClassMetadata> {
         match Self::metadata_opt(cx) {
             Some(metadata) => Ok(metadata),
@@ -144,6 +264,7 @@ pub(crate) trait ClassInternal: Class {
         }
     }
 
+    #[cfg(feature = "legacy-runtime")]
     fn create<'a, C: Context<'a>>(cx: &mut C) -> NeonResult<ClassMetadata> {
         let descriptor = Self::setup(cx)?;
         unsafe {
@@ -209,11 +330,104 @@ pub(crate) trait ClassInternal: Class {
             Ok(metadata)
         }
     }
+
+    #[cfg(feature = "napi-1")]
+    fn create<'a, C: Context<'a>>(cx: &mut C) -> NeonResult<ClassMetadata> {
+        let descriptor = Self::setup(cx)?;
+        let class_name = descriptor.name;
+        unsafe {
+            let env = cx.env().to_raw();
+
+            let constructor = ConstructorCallback::<Self> {
+                allocate: descriptor.allocate,
+                construct: descriptor.construct,
+                call: descriptor
+                    .call
+                    .unwrap_or_else(ConstructorCallCallback::default::<Self>),
+            }
+            .into_c_callback();
+            let data = constructor.dynamic_callback;
+
+            let mut members = Vec::new();
+
+            for (name, method) in descriptor.methods {
+                members.push(Member::Method {
+                    name,
+                    callback: method.into_c_callback(),
+                    is_static: false,
+                });
+            }
+
+            for (name, method) in descriptor.static_methods {
+                members.push(Member::Method {
+                    name,
+                    callback: method.into_c_callback(),
+                    is_static: true,
+                });
+            }
+
+            for (name, accessor) in descriptor.accessors {
+                members.push(Member::Accessor {
+                    name,
+                    getter: accessor
+                        .get
+                        .as_ref()
+                        .map(|_| AccessorGetter(accessor).into_c_callback()),
+                    setter: accessor
+                        .set
+                        .as_ref()
+                        .map(|_| AccessorSetter(accessor).into_c_callback()),
+                });
+            }
+
+            if let Some(tag) = descriptor.to_string_tag {
+                members.push(Member::ToStringTag(tag));
+            }
+
+            let constructor: Handle<JsFunction<Self>> = build(cx.env(), |out| {
+                let defined =
+                    neon_runtime::class::define(out, env, class_name, constructor, &members);
+
+                if !defined {
+                    drop_constructor::<Self>(data);
+                }
+
+                defined
+            })?;
+
+            CLASS_NAMES
+                .lock()
+                .unwrap()
+                .insert(TypeId::of::<Self>(), class_name.to_string());
+
+            // The constructor and its data are kept alive until the environment is torn down
+            let metadata = ClassMetadata {
+                pointer: neon_runtime::reference::new(env, constructor.to_raw()).cast(),
+            };
+
+            #[cfg(feature = "napi-6")]
+            let map = InstanceData::class_map(cx);
+            #[cfg(not(feature = "napi-6"))]
+            let mut env = cx.env();
+            #[cfg(not(feature = "napi-6"))]
+            let map = env.class_map();
+
+            map.set(
+                TypeId::of::<Self>(),
+                metadata,
+                data,
+                drop_constructor::<Self>,
+            );
+
+            Ok(metadata)
+        }
+    }
 }
 
 impl<T: Class> ClassInternal for T {}
 
 impl<T: Class> ValueInternal for T {
+    #[cfg(feature = "legacy-runtime")]
     fn name() -> String {
         let mut isolate: Env = unsafe { mem::transmute(neon_runtime::call::current_isolate()) };
         let raw_isolate = unsafe { mem::transmute(isolate) };
@@ -235,6 +449,17 @@ impl<T: Class> ValueInternal for T {
         }
     }
 
+    #[cfg(feature = "napi-1")]
+    fn name() -> String {
+        CLASS_NAMES
+            .lock()
+            .unwrap()
+            .get(&TypeId::of::<T>())
+            .cloned()
+            .unwrap_or_else(|| "unknown".to_string())
+    }
+
+    #[cfg(feature = "legacy-runtime")]
     fn is_typeof<Other: Value>(mut env: Env, value: Other) -> bool {
         let map = env.class_map();
         match map.get(&TypeId::of::<T>()) {
@@ -242,16 +467,36 @@ impl<T: Class> ValueInternal for T {
             Some(ref metadata) => unsafe { metadata.has_instance(value.to_raw()) },
         }
     }
+
+    #[cfg(feature = "napi-1")]
+    fn is_typeof<Other: Value>(env: Env, value: Other) -> bool {
+        unsafe { !instance_internals::<T>(env, value.to_raw()).is_null() }
+    }
 }
 
 impl<T: Class> Value for T {}
 
+#[cfg(feature = "legacy-runtime")]
+unsafe fn instance_internals_ptr<T: Class>(_lock: &Lock, obj: raw::Local) -> *mut c_void {
+    neon_runtime::class::get_instance_internals(obj)
+}
+
+#[cfg(feature = "napi-1")]
+unsafe fn instance_internals_ptr<T: Class>(lock: &Lock, obj: raw::Local) -> *mut c_void {
+    let ptr = instance_internals::<T>(lock.env, obj);
+
+    // A constructor may return an object other than `this`, which is not an instance
+    assert!(!ptr.is_null(), "object is not an instance of {}", T::name());
+
+    ptr.cast()
+}
+
 impl<'a, T: Class> Borrow for &'a T {
     type Target = &'a mut T::Internals;
 
     fn try_borrow<'b>(self, lock: &'b Lock<'b>) -> Result<Ref<'b, Self::Target>, LoanError> {
         unsafe {
-            let ptr: *mut c_void = neon_runtime::class::get_instance_internals(self.to_raw());
+            let ptr = instance_internals_ptr::<T>(lock, self.to_raw());
             Ref::new(lock, &mut *ptr.cast())
         }
     }
@@ -268,7 +513,7 @@ impl<'a, T: Class> Borrow for &'a mut T {
 impl<'a, T: Class> BorrowMut for &'a mut T {
     fn try_borrow_mut<'b>(self, lock: &'b Lock<'b>) -> Result<RefMut<'b, Self::Target>, LoanError> {
         unsafe {
-            let ptr: *mut c_void = neon_runtime::class::get_instance_internals(self.to_raw());
+            let ptr = instance_internals_ptr::<T>(lock, self.to_raw());
             RefMut::new(lock, &mut *ptr.cast())
         }
     }
@@ -8,9 +8,8 @@
 //! ## Property Keys
 //!
 //! Object properties are accessed by a _property key_, which in JavaScript
-//! can be a string or [symbol][symbol]. (Neon does not yet have support for
-//! symbols.) For convenience, the [`PropertyKey`](PropertyKey) trait allows
-//! Neon programs to use various Rust string types, as well as numeric types,
+//! can be a string or [symbol][symbol]. For convenience, the [`PropertyKey`](PropertyKey)
+//! trait allows Neon programs to use various Rust string types, as well as numeric types,
 //! as keys when accessing object properties, converting the keys to strings
 //! as necessary:
 //!
@@ -31,13 +30,30 @@
 //! # }
 //! ```
 //!
+//! With the N-API backend, a [`JsSymbol`](crate::types::JsSymbol) handle can
+//! also be used as a property key:
+//!
+//! ```
+//! # #[cfg(feature = "napi-1")] {
+//! # use neon::prelude::*;
+//! # use neon::types::JsSymbol;
+//! fn set_hidden<'a>(
+//!     cx: &mut impl Context<'a>,
+//!     obj: Handle<'a, JsObject>
+//! ) -> NeonResult<bool> {
+//!     let key = JsSymbol::with_description(cx, "hidden");
+//!     let value = cx.number(42);
+//!     // symbol-keyed properties are not returned by `Object.keys()`
+//!     obj.set(cx, key, value)
+//! }
+//! # }
+//! ```
+//!
 //! [hierarchy]: crate::types#the-javascript-type-hierarchy
 //! [symbol]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol
 
-#[cfg(feature = "legacy-runtime")]
 pub(crate) mod class;
 
-#[cfg(feature = "legacy-runtime")]
 pub use self::class::{Class, ClassDescriptor};
 pub use self::traits::*;
 
@@ -7,7 +7,6 @@ pub use crate::context::{
     CallContext, CallKind, ComputeContext, Context, ExecuteContext, FunctionContext, MethodContext,
     ModuleContext, TaskContext,
 };
-#[cfg(feature = "legacy-runtime")]
 #[doc(no_inline)]
 pub use crate::declare_types;
 #[cfg(all(not(feature = "napi-1"), feature = "event-handler-api"))]
@@ -22,7 +21,6 @@ pub use crate::event::{Channel, SendError};
 pub use crate::event::{EventQueue, EventQueueError};
 #[doc(no_inline)]
 pub use crate::handle::Handle;
-#[cfg(feature = "legacy-runtime")]
 #[doc(no_inline)]
 pub use crate::object::Class;
 #[doc(no_inline)]
@@ -31,7 +29,7 @@ pub use crate::object::Object;
 pub use crate::register_module;
 #[doc(no_inline)]
 pub use crate::result::{JsResult, JsResultExt, NeonResult};
-#[cfg(feature = "legacy-runtime")]
+#[doc(no_inline)]
 pub use crate::task::Task;
 #[doc(no_inline)]
 pub use crate::types::{
@@ -43,4 +41,6 @@ pub use crate::types::{
 pub use crate::{
     handle::Root,
     types::boxed::{Finalize, JsBox},
+    types::extract::{TryFromJs, TryIntoJs},
+    types::{Deferred, JsPromise},
 };
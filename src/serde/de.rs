@@ -0,0 +1,340 @@
+use ::serde::de::value::StringDeserializer;
+use ::serde::de::{self, DeserializeSeed, Error as _, IntoDeserializer, Unexpected, Visitor};
+use ::serde::forward_to_deserialize_any;
+
+use super::Error;
+use crate::context::Context;
+use crate::handle::Handle;
+use crate::object::Object;
+use crate::types::extract::{own_keys, type_name};
+#[cfg(feature = "napi-6")]
+use crate::types::JsBigInt;
+use crate::types::{
+    JsArray, JsArrayBuffer, JsBoolean, JsBuffer, JsNull, JsNumber, JsObject, JsString,
+    JsTypedArray, JsUndefined, JsValue,
+};
+
+/// The largest integer that a `number` represents exactly, `Number.MAX_SAFE_INTEGER`.
+const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_991.0;
+
+/// Converts a JavaScript value to a Rust value.
+pub(super) struct Deserializer<'a, 'b, C: Context<'a>> {
+    cx: &'b mut C,
+    value: Handle<'a, JsValue>,
+}
+
+impl<'a, 'b, C: Context<'a>> Deserializer<'a, 'b, C> {
+    pub(super) fn new(cx: &'b mut C, value: Handle<'a, JsValue>) -> Self {
+        Deserializer { cx, value }
+    }
+
+    fn is_nullish(&mut self) -> bool {
+        self.value.is_a::<JsUndefined, _>(self.cx) || self.value.is_a::<JsNull, _>(self.cx)
+    }
+
+    /// Copies the bytes of a `Buffer`, an `ArrayBuffer` or a `Uint8Array`.
+    fn bytes(&mut self) -> Option<Vec<u8>> {
+        let cx = &mut *self.cx;
+        let value = self.value;
+
+        if let Ok(buffer) = value.downcast::<JsBuffer, _>(cx) {
+            Some(cx.borrow(&buffer, |data| data.as_slice::<u8>().to_vec()))
+        } else if let Ok(buffer) = value.downcast::<JsArrayBuffer, _>(cx) {
+            Some(cx.borrow(&buffer, |data| data.as_slice::<u8>().to_vec()))
+        } else if let Ok(array) = value.downcast::<JsTypedArray<u8>, _>(cx) {
+            Some(cx.borrow(&array, |data| data.as_slice::<u8>().to_vec()))
+        } else {
+            None
+        }
+    }
+
+    fn unsupported(&mut self) -> Error {
+        let found = type_name(self.cx, self.value);
+
+        Error::custom(format!("cannot deserialize a value of type {}", found))
+    }
+}
+
+/// Visits integral numbers that are represented exactly as integers, so that they can
+/// be deserialized into integer types.
+fn visit_number<'de, V: Visitor<'de>>(n: f64, visitor: V) -> Result<V::Value, Error> {
+    if n.fract() != 0.0 || n.abs() > MAX_SAFE_INTEGER {
+        visitor.visit_f64(n)
+    } else if n < 0.0 {
+        visitor.visit_i64(n as i64)
+    } else {
+        visitor.visit_u64(n as u64)
+    }
+}
+
+#[cfg(feature = "napi-6")]
+fn visit_bigint<'a, 'de, C: Context<'a>, V: Visitor<'de>>(
+    cx: &mut C,
+    bigint: Handle<'a, JsBigInt>,
+    visitor: V,
+) -> Result<V::Value, Error> {
+    if let Ok(n) = bigint.to_i64(cx) {
+        visitor.visit_i64(n)
+    } else if let Ok(n) = bigint.to_u64(cx) {
+        visitor.visit_u64(n)
+    } else if let Ok(n) = bigint.to_i128(cx) {
+        visitor.visit_i128(n)
+    } else if let Ok(n) = bigint.to_u128(cx) {
+        visitor.visit_u128(n)
+    } else {
+        Err(Error::custom(
+            "bigint is out of the range of a 128-bit integer",
+        ))
+    }
+}
+
+impl<'de, 'a, 'b, C: Context<'a>> de::Deserializer<'de> for Deserializer<'a, 'b, C> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(mut self, visitor: V) -> Result<V::Value, Error> {
+        if self.is_nullish() {
+            return visitor.visit_unit();
+        }
+
+        let cx = &mut *self.cx;
+        let value = self.value;
+
+        if let Ok(v) = value.downcast::<JsBoolean, _>(cx) {
+            return visitor.visit_bool(v.value(cx));
+        }
+
+        if let Ok(v) = value.downcast::<JsNumber, _>(cx) {
+            return visit_number(v.value(cx), visitor);
+        }
+
+        if let Ok(v) = value.downcast::<JsString, _>(cx) {
+            return visitor.visit_string(v.value(cx));
+        }
+
+        #[cfg(feature = "napi-6")]
+        {
+            if let Ok(v) = value.downcast::<JsBigInt, _>(cx) {
+                return visit_bigint(cx, v, visitor);
+            }
+        }
+
+        if let Some(bytes) = self.bytes() {
+            return visitor.visit_byte_buf(bytes);
+        }
+
+        let cx = &mut *self.cx;
+
+        if let Ok(array) = value.downcast::<JsArray, _>(cx) {
+            let len = array.len(cx);
+
+            return visitor.visit_seq(ArrayAccess {
+                cx,
+                array,
+                index: 0,
+                len,
+            });
+        }
+
+        if let Ok(object) = value.downcast::<JsObject, _>(cx) {
+            let keys = own_keys(cx, object)?.to_vec(cx)?;
+
+            return visitor.visit_map(ObjectAccess {
+                cx,
+                object,
+                keys: keys.into_iter(),
+                value: None,
+            });
+        }
+
+        Err(self.unsupported())
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(mut self, visitor: V) -> Result<V::Value, Error> {
+        if self.is_nullish() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        let cx = self.cx;
+        let value = self.value;
+
+        if let Ok(variant) = value.downcast::<JsString, _>(cx) {
+            return visitor.visit_enum(variant.value(cx).into_deserializer());
+        }
+
+        let object = match value.downcast::<JsObject, _>(cx) {
+            Ok(object) => object,
+            Err(_) => {
+                let found = type_name(cx, value);
+
+                return Err(Error::invalid_type(
+                    Unexpected::Other(found),
+                    &"a string or an object with a single key",
+                ));
+            }
+        };
+
+        let keys = own_keys(cx, object)?.to_vec(cx)?;
+
+        if keys.len() != 1 {
+            return Err(Error::invalid_length(
+                keys.len(),
+                &"an object with a single key",
+            ));
+        }
+
+        let variant = keys[0].downcast_or_throw::<JsString, _>(cx)?.value(cx);
+        let content = object.get(cx, keys[0])?;
+
+        visitor.visit_enum(EnumAccess {
+            cx,
+            variant,
+            content,
+        })
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_unit()
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct identifier
+    }
+}
+
+/// Visits the elements of an `Array`.
+struct ArrayAccess<'a, 'b, C: Context<'a>> {
+    cx: &'b mut C,
+    array: Handle<'a, JsArray>,
+    index: u32,
+    len: u32,
+}
+
+impl<'de, 'a, 'b, C: Context<'a>> de::SeqAccess<'de> for ArrayAccess<'a, 'b, C> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Error> {
+        if self.index >= self.len {
+            return Ok(None);
+        }
+
+        let value = self.array.get(self.cx, self.index)?;
+
+        self.index += 1;
+
+        seed.deserialize(Deserializer::new(self.cx, value))
+            .map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some((self.len - self.index) as usize)
+    }
+}
+
+/// Visits the own enumerable string-keyed properties of an `Object`.
+struct ObjectAccess<'a, 'b, C: Context<'a>> {
+    cx: &'b mut C,
+    object: Handle<'a, JsObject>,
+    keys: std::vec::IntoIter<Handle<'a, JsValue>>,
+    value: Option<Handle<'a, JsValue>>,
+}
+
+impl<'de, 'a, 'b, C: Context<'a>> de::MapAccess<'de> for ObjectAccess<'a, 'b, C> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Error> {
+        let key = match self.keys.next() {
+            Some(key) => key,
+            None => return Ok(None),
+        };
+
+        self.value = Some(self.object.get(self.cx, key)?);
+
+        let key = key
+            .downcast_or_throw::<JsString, _>(self.cx)?
+            .value(self.cx);
+
+        seed.deserialize(key.into_deserializer()).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        match self.value.take() {
+            Some(value) => seed.deserialize(Deserializer::new(self.cx, value)),
+            None => Err(Error::custom("next_value_seed called before next_key_seed")),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.keys.len())
+    }
+}
+
+/// Visits an enum in the form `{ [variant]: content }`.
+struct EnumAccess<'a, 'b, C: Context<'a>> {
+    cx: &'b mut C,
+    variant: String,
+    content: Handle<'a, JsValue>,
+}
+
+impl<'de, 'a, 'b, C: Context<'a>> de::EnumAccess<'de> for EnumAccess<'a, 'b, C> {
+    type Error = Error;
+    type Variant = Deserializer<'a, 'b, C>;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Self::Variant), Error> {
+        let variant: StringDeserializer<Error> = self.variant.into_deserializer();
+        let variant = seed.deserialize(variant)?;
+
+        Ok((variant, Deserializer::new(self.cx, self.content)))
+    }
+}
+
+/// The content of a variant is deserialized like any other value.
+impl<'de, 'a, 'b, C: Context<'a>> de::VariantAccess<'de> for Deserializer<'a, 'b, C> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<(), Error> {
+        de::Deserialize::deserialize(self)
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, Error> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value, Error> {
+        de::Deserializer::deserialize_seq(self, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        de::Deserializer::deserialize_map(self, visitor)
+    }
+}
@@ -0,0 +1,120 @@
+//! Conversions between JavaScript values and Rust values that implement
+//! [`Serialize`](::serde::Serialize) and [`Deserialize`](::serde::Deserialize).
+//!
+//! Values are converted directly with the N-API, without a round trip through JSON:
+//!
+//! | Rust                              | JavaScript                          |
+//! |-----------------------------------|-------------------------------------|
+//! | `bool`                            | `boolean`                           |
+//! | integers and floats               | `number`                            |
+//! | 64-bit and 128-bit integers       | `number`, or `bigint` if too large  |
+//! | `char`, `String`                  | `string`                            |
+//! | bytes, e.g., with `serde_bytes`   | `Buffer`                            |
+//! | `None`, `()`, unit structs        | `null`                              |
+//! | sequences and tuples              | `Array`                             |
+//! | maps and structs                  | `Object`                            |
+//! | unit variants                     | the name of the variant             |
+//! | other variants                    | `{ [variant]: content }`            |
+//!
+//! Integers outside of the range `Number.MIN_SAFE_INTEGER..=Number.MAX_SAFE_INTEGER`
+//! are converted to a `bigint`, which requires the `napi-6` feature. Without it, they
+//! cannot be converted. Deserializing accepts `undefined` wherever `null` is accepted,
+//! and a `Buffer`, `ArrayBuffer` or `Uint8Array` wherever bytes are accepted.
+//!
+//! Types deriving `Serialize` and `Deserialize` are converted the same way, so a struct
+//! can be passed to and from JavaScript as a plain object:
+//!
+//! ```
+//! # use neon::prelude::*;
+//! # use std::collections::HashMap;
+//! fn count_words(mut cx: FunctionContext) -> JsResult<JsValue> {
+//!     let words = cx.argument::<JsValue>(0)?;
+//!     let words: Vec<String> = neon::serde::from_value(&mut cx, words)?;
+//!     let mut counts = HashMap::new();
+//!
+//!     for word in words {
+//!         *counts.entry(word).or_insert(0) += 1;
+//!     }
+//!
+//!     neon::serde::to_value(&mut cx, &counts)
+//! }
+//! ```
+
+use std::fmt;
+
+use ::serde::de::DeserializeOwned;
+use ::serde::Serialize;
+
+use crate::context::Context;
+use crate::handle::Handle;
+use crate::result::{JsResult, NeonResult, Throw};
+use crate::types::{JsValue, Value};
+
+mod de;
+mod ser;
+
+/// Converts a Rust value to a JavaScript value.
+///
+/// Throws an `Error` if the value cannot be converted, e.g., a map with keys that are
+/// neither strings nor numbers.
+pub fn to_value<'a, C: Context<'a>, T: Serialize + ?Sized>(
+    cx: &mut C,
+    value: &T,
+) -> JsResult<'a, JsValue> {
+    match value.serialize(ser::Serializer::new(cx)) {
+        Ok(value) => Ok(value),
+        Err(Error::Throw(throw)) => Err(throw),
+        Err(Error::Message(message)) => cx.throw_error(message),
+    }
+}
+
+/// Converts a JavaScript value to a Rust value.
+///
+/// Throws a `TypeError` if the value has the wrong shape for `T`.
+pub fn from_value<'a, T: DeserializeOwned>(
+    cx: &mut impl Context<'a>,
+    value: Handle<'a, impl Value>,
+) -> NeonResult<T> {
+    match T::deserialize(de::Deserializer::new(cx, value.upcast())) {
+        Ok(value) => Ok(value),
+        Err(Error::Throw(throw)) => Err(throw),
+        Err(Error::Message(message)) => cx.throw_type_error(message),
+    }
+}
+
+/// The error of a conversion, either a message to throw or an exception that is
+/// already pending.
+#[derive(Debug)]
+enum Error {
+    Message(String),
+    Throw(Throw),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Message(message) => f.write_str(message),
+            Error::Throw(_) => f.write_str("a JavaScript exception was thrown"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl ::serde::ser::Error for Error {
+    fn custom<T: fmt::Display>(message: T) -> Self {
+        Error::Message(message.to_string())
+    }
+}
+
+impl ::serde::de::Error for Error {
+    fn custom<T: fmt::Display>(message: T) -> Self {
+        Error::Message(message.to_string())
+    }
+}
+
+impl From<Throw> for Error {
+    fn from(throw: Throw) -> Self {
+        Error::Throw(throw)
+    }
+}
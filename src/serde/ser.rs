@@ -0,0 +1,472 @@
+use std::marker::PhantomData;
+
+use ::serde::ser::{self, Error as _, Serialize};
+
+use super::Error;
+use crate::context::Context;
+use crate::handle::Handle;
+use crate::object::Object;
+#[cfg(feature = "napi-6")]
+use crate::types::JsBigInt;
+use crate::types::{JsArray, JsBuffer, JsNumber, JsObject, JsString, JsValue};
+
+/// The largest integer that a `number` represents exactly, `Number.MAX_SAFE_INTEGER`.
+const MAX_SAFE_INTEGER: i128 = (1 << 53) - 1;
+
+/// Converts Rust values to JavaScript values, one per serializer.
+pub(super) struct Serializer<'a, 'b, C: Context<'a>> {
+    cx: &'b mut C,
+    _lifetime: PhantomData<&'a ()>,
+}
+
+impl<'a, 'b, C: Context<'a>> Serializer<'a, 'b, C> {
+    pub(super) fn new(cx: &'b mut C) -> Self {
+        Serializer {
+            cx,
+            _lifetime: PhantomData,
+        }
+    }
+}
+
+/// Converts `value` with a serializer borrowing `cx`.
+fn to_value<'a, C: Context<'a>, T: Serialize + ?Sized>(
+    cx: &mut C,
+    value: &T,
+) -> Result<Handle<'a, JsValue>, Error> {
+    value.serialize(Serializer::new(cx))
+}
+
+/// Converts an integer to a `number` if it can be represented exactly, or a `bigint`.
+#[cfg(feature = "napi-6")]
+fn integer<'a, C: Context<'a>>(cx: &mut C, v: i128) -> Result<Handle<'a, JsValue>, Error> {
+    if (-MAX_SAFE_INTEGER..=MAX_SAFE_INTEGER).contains(&v) {
+        Ok(cx.number(v as f64).upcast())
+    } else {
+        Ok(JsBigInt::from_i128(cx, v).upcast())
+    }
+}
+
+/// Converts an integer to a `number` if it can be represented exactly.
+#[cfg(not(feature = "napi-6"))]
+fn integer<'a, C: Context<'a>>(cx: &mut C, v: i128) -> Result<Handle<'a, JsValue>, Error> {
+    if (-MAX_SAFE_INTEGER..=MAX_SAFE_INTEGER).contains(&v) {
+        Ok(cx.number(v as f64).upcast())
+    } else {
+        Err(Error::custom(format!(
+            "{} cannot be represented exactly as a number",
+            v
+        )))
+    }
+}
+
+#[cfg(feature = "napi-6")]
+fn unsigned_integer<'a, C: Context<'a>>(cx: &mut C, v: u128) -> Result<Handle<'a, JsValue>, Error> {
+    if v > i128::MAX as u128 {
+        Ok(JsBigInt::from_u128(cx, v).upcast())
+    } else {
+        integer(cx, v as i128)
+    }
+}
+
+#[cfg(not(feature = "napi-6"))]
+fn unsigned_integer<'a, C: Context<'a>>(cx: &mut C, v: u128) -> Result<Handle<'a, JsValue>, Error> {
+    if v > i128::MAX as u128 {
+        Err(Error::custom(format!(
+            "{} cannot be represented exactly as a number",
+            v
+        )))
+    } else {
+        integer(cx, v as i128)
+    }
+}
+
+/// Wraps the content of a variant in an object with the variant as its only key.
+fn variant<'a, C: Context<'a>>(
+    cx: &mut C,
+    variant: &str,
+    content: Handle<'a, JsValue>,
+) -> Result<Handle<'a, JsValue>, Error> {
+    let object = cx.empty_object();
+
+    object.set(cx, variant, content)?;
+
+    Ok(object.upcast())
+}
+
+impl<'a, 'b, C: Context<'a>> ser::Serializer for Serializer<'a, 'b, C> {
+    type Ok = Handle<'a, JsValue>;
+    type Error = Error;
+
+    type SerializeSeq = ArraySerializer<'a, 'b, C>;
+    type SerializeTuple = ArraySerializer<'a, 'b, C>;
+    type SerializeTupleStruct = ArraySerializer<'a, 'b, C>;
+    type SerializeTupleVariant = VariantSerializer<ArraySerializer<'a, 'b, C>>;
+    type SerializeMap = ObjectSerializer<'a, 'b, C>;
+    type SerializeStruct = ObjectSerializer<'a, 'b, C>;
+    type SerializeStructVariant = VariantSerializer<ObjectSerializer<'a, 'b, C>>;
+
+    fn serialize_bool(self, v: bool) -> Result<Self::Ok, Error> {
+        Ok(self.cx.boolean(v).upcast())
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Self::Ok, Error> {
+        self.serialize_f64(v.into())
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Self::Ok, Error> {
+        self.serialize_f64(v.into())
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Self::Ok, Error> {
+        self.serialize_f64(v.into())
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Self::Ok, Error> {
+        integer(self.cx, v.into())
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<Self::Ok, Error> {
+        integer(self.cx, v)
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Self::Ok, Error> {
+        self.serialize_f64(v.into())
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Self::Ok, Error> {
+        self.serialize_f64(v.into())
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Self::Ok, Error> {
+        self.serialize_f64(v.into())
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Self::Ok, Error> {
+        unsigned_integer(self.cx, v.into())
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<Self::Ok, Error> {
+        unsigned_integer(self.cx, v)
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Self::Ok, Error> {
+        self.serialize_f64(v.into())
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Self::Ok, Error> {
+        Ok(self.cx.number(v).upcast())
+    }
+
+    fn serialize_char(self, v: char) -> Result<Self::Ok, Error> {
+        self.serialize_str(v.encode_utf8(&mut [0; 4]))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Self::Ok, Error> {
+        Ok(self.cx.string(v).upcast())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok, Error> {
+        Ok(JsBuffer::external(self.cx, v.to_vec()).upcast())
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Error> {
+        Ok(self.cx.null().upcast())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Self::Ok, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Error> {
+        Ok(self.cx.null().upcast())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Error> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Error> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Error> {
+        let content = to_value(self.cx, value)?;
+
+        variant(self.cx, name, content)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Error> {
+        let array = JsArray::new(self.cx, len.unwrap_or(0) as u32);
+
+        Ok(ArraySerializer {
+            cx: self.cx,
+            array,
+            index: 0,
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Error> {
+        Ok(VariantSerializer {
+            variant,
+            content: self.serialize_seq(Some(len))?,
+        })
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Error> {
+        let object = self.cx.empty_object();
+
+        Ok(ObjectSerializer {
+            cx: self.cx,
+            object,
+            key: None,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, Error> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant, Error> {
+        Ok(VariantSerializer {
+            variant,
+            content: self.serialize_map(Some(len))?,
+        })
+    }
+}
+
+/// Serializes sequences and tuples to an `Array`.
+pub(super) struct ArraySerializer<'a, 'b, C: Context<'a>> {
+    cx: &'b mut C,
+    array: Handle<'a, JsArray>,
+    index: u32,
+}
+
+impl<'a, 'b, C: Context<'a>> ArraySerializer<'a, 'b, C> {
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        let value = to_value(self.cx, value)?;
+
+        self.array.set(self.cx, self.index, value)?;
+        self.index += 1;
+
+        Ok(())
+    }
+}
+
+impl<'a, 'b, C: Context<'a>> ser::SerializeSeq for ArraySerializer<'a, 'b, C> {
+    type Ok = Handle<'a, JsValue>;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Error> {
+        Ok(self.array.upcast())
+    }
+}
+
+impl<'a, 'b, C: Context<'a>> ser::SerializeTuple for ArraySerializer<'a, 'b, C> {
+    type Ok = Handle<'a, JsValue>;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Error> {
+        Ok(self.array.upcast())
+    }
+}
+
+impl<'a, 'b, C: Context<'a>> ser::SerializeTupleStruct for ArraySerializer<'a, 'b, C> {
+    type Ok = Handle<'a, JsValue>;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Error> {
+        Ok(self.array.upcast())
+    }
+}
+
+/// Serializes maps and structs to an `Object`.
+pub(super) struct ObjectSerializer<'a, 'b, C: Context<'a>> {
+    cx: &'b mut C,
+    object: Handle<'a, JsObject>,
+    key: Option<Handle<'a, JsValue>>,
+}
+
+impl<'a, 'b, C: Context<'a>> ObjectSerializer<'a, 'b, C> {
+    fn set<K: Serialize + ?Sized, T: Serialize + ?Sized>(
+        &mut self,
+        key: &K,
+        value: &T,
+    ) -> Result<(), Error> {
+        let key = self.key(key)?;
+        let value = to_value(self.cx, value)?;
+
+        self.object.set(self.cx, key, value)?;
+
+        Ok(())
+    }
+
+    /// Serializes a key, which must become a string or a number.
+    fn key<K: Serialize + ?Sized>(&mut self, key: &K) -> Result<Handle<'a, JsValue>, Error> {
+        let key = to_value(self.cx, key)?;
+
+        if key.is_a::<JsString, _>(self.cx) || key.is_a::<JsNumber, _>(self.cx) {
+            Ok(key)
+        } else {
+            Err(Error::custom("map keys must be strings or numbers"))
+        }
+    }
+}
+
+impl<'a, 'b, C: Context<'a>> ser::SerializeMap for ObjectSerializer<'a, 'b, C> {
+    type Ok = Handle<'a, JsValue>;
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Error> {
+        self.key = Some(self.key(key)?);
+
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        let key = match self.key.take() {
+            Some(key) => key,
+            None => return Err(Error::custom("serialize_value called before serialize_key")),
+        };
+        let value = to_value(self.cx, value)?;
+
+        self.object.set(self.cx, key, value)?;
+
+        Ok(())
+    }
+
+    fn serialize_entry<K: Serialize + ?Sized, T: Serialize + ?Sized>(
+        &mut self,
+        key: &K,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.set(key, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Error> {
+        Ok(self.object.upcast())
+    }
+}
+
+impl<'a, 'b, C: Context<'a>> ser::SerializeStruct for ObjectSerializer<'a, 'b, C> {
+    type Ok = Handle<'a, JsValue>;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        let value = to_value(self.cx, value)?;
+
+        self.object.set(self.cx, key, value)?;
+
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Error> {
+        Ok(self.object.upcast())
+    }
+}
+
+/// Serializes the content of a tuple or struct variant and wraps it in an object.
+pub(super) struct VariantSerializer<S> {
+    variant: &'static str,
+    content: S,
+}
+
+impl<'a, 'b, C: Context<'a>> ser::SerializeTupleVariant
+    for VariantSerializer<ArraySerializer<'a, 'b, C>>
+{
+    type Ok = Handle<'a, JsValue>;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.content.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Error> {
+        let ArraySerializer { cx, array, .. } = self.content;
+
+        variant(cx, self.variant, array.upcast())
+    }
+}
+
+impl<'a, 'b, C: Context<'a>> ser::SerializeStructVariant
+    for VariantSerializer<ObjectSerializer<'a, 'b, C>>
+{
+    type Ok = Handle<'a, JsValue>;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        ser::SerializeStruct::serialize_field(&mut self.content, key, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Error> {
+        let ObjectSerializer { cx, object, .. } = self.content;
+
+        variant(cx, self.variant, object.upcast())
+    }
+}
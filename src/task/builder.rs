@@ -0,0 +1,208 @@
+use std::thread;
+
+use super::cancel::{abort_error, CancellationToken, Registration};
+use crate::context::internal::Env;
+use crate::context::{Context, TaskContext};
+use crate::handle::Handle;
+use crate::result::{JsResult, NeonResult};
+use crate::types::error::panic_message;
+use crate::types::{Deferred, JsPromise, Value};
+use neon_runtime::async_work::Complete;
+
+/// Node asynchronous task builder
+///
+/// Created with [`Context::task`](crate::context::Context::task). A task runs a closure on
+/// the libuv thread pool and then completes on the JavaScript thread, either by settling
+/// a promise or by calling another closure.
+///
+/// ```
+/// # use neon::prelude::*;
+/// fn fibonacci(n: u64) -> u64 {
+///     if n < 2 { n } else { fibonacci(n - 1) + fibonacci(n - 2) }
+/// }
+///
+/// fn fibonacci_async(mut cx: FunctionContext) -> JsResult<JsPromise> {
+///     let n = cx.argument::<JsNumber>(0)?.value(&mut cx) as u64;
+///     let promise = cx
+///         .task(move || fibonacci(n))
+///         .promise(|mut cx, n| Ok(cx.number(n as f64)));
+///
+///     Ok(promise)
+/// }
+/// ```
+pub struct TaskBuilder<'cx, C, E> {
+    cx: &'cx mut C,
+    execute: E,
+    token: Option<CancellationToken>,
+}
+
+impl<'a: 'cx, 'cx, C, O, E> TaskBuilder<'cx, C, E>
+where
+    C: Context<'a>,
+    O: Send + 'static,
+    E: FnOnce() -> O + Send + 'static,
+{
+    /// Construct a new task builder from an `execute` callback that will be run on the
+    /// libuv thread pool
+    pub fn new(cx: &'cx mut C, execute: E) -> Self {
+        Self {
+            cx,
+            execute,
+            token: None,
+        }
+    }
+
+    /// Allows the task to be cancelled with `token`. See [`CancellationToken`] for how
+    /// cancelling affects the task.
+    pub fn cancel_with(self, token: CancellationToken) -> Self {
+        Self {
+            token: Some(token),
+            ..self
+        }
+    }
+
+    /// Schedules `execute` and registers the work with the token, if any. Returns `data`
+    /// without scheduling if the token has already been cancelled.
+    fn schedule<D: 'static>(
+        cx: &mut C,
+        execute: E,
+        token: Option<CancellationToken>,
+        complete: Complete<O, Tracked<D>>,
+        data: D,
+    ) -> Result<(), D> {
+        let env = cx.env().to_raw();
+        let registration = match token {
+            Some(token) if token.is_cancelled() => return Err(data),
+            Some(token) => Some(Registration::new(token)),
+            None => None,
+        };
+        let tracked = registration
+            .as_ref()
+            .map(|registration| (registration.token.clone(), registration.id));
+
+        let work = unsafe {
+            neon_runtime::async_work::schedule(
+                env,
+                execute,
+                run::<O, E>,
+                complete,
+                (data, registration),
+            )
+        };
+
+        if let Some((token, id)) = tracked {
+            token.register(id, env, work);
+        }
+
+        Ok(())
+    }
+
+    /// Schedules the task, calling `complete` with the output of `execute` on the
+    /// JavaScript thread, e.g., to call a JavaScript callback with the result.
+    ///
+    /// An exception thrown by `complete`, or a panic in `execute`, is reported as an
+    /// uncaught exception. If the task is cancelled, `complete` is not called.
+    pub fn and_then<F>(self, complete: F)
+    where
+        F: FnOnce(TaskContext, O) -> NeonResult<()> + 'static,
+    {
+        let _ = Self::schedule(
+            self.cx,
+            self.execute,
+            self.token,
+            complete_and_then::<O, F>,
+            complete,
+        );
+    }
+
+    /// Schedules the task and returns a promise that is resolved with the value returned
+    /// by `complete`, which is called with the output of `execute` on the JavaScript thread.
+    ///
+    /// If `complete` throws, the promise is rejected with the thrown value. If `execute`
+    /// panics, the promise is rejected with an `Error` describing the panic. If the task
+    /// is cancelled, the promise is rejected with an `AbortError`.
+    pub fn promise<V, F>(self, complete: F) -> Handle<'a, JsPromise>
+    where
+        V: Value,
+        F: FnOnce(TaskContext, O) -> JsResult<V> + 'static,
+    {
+        let (deferred, promise) = self.cx.promise();
+        let scheduled = Self::schedule(
+            self.cx,
+            self.execute,
+            self.token,
+            complete_promise::<O, V, F>,
+            (deferred, complete),
+        );
+
+        if let Err((deferred, _)) = scheduled {
+            if let Ok(err) = abort_error(self.cx) {
+                deferred.reject(self.cx, err);
+            }
+        }
+
+        promise
+    }
+}
+
+/// Data passed to `complete` along with the registration of cancellable work
+type Tracked<D> = (D, Option<Registration>);
+
+/// Deregisters completing work from its token and returns `true` if the task was
+/// cancelled, either before it started executing or while it was running.
+fn deregister<O>(output: &Option<thread::Result<O>>, registration: Option<Registration>) -> bool {
+    let cancelled = registration.map_or(false, Registration::deregister);
+
+    cancelled || output.is_none()
+}
+
+fn run<O, E: FnOnce() -> O>(execute: E) -> O {
+    execute()
+}
+
+fn complete_and_then<O, F>(
+    env: neon_runtime::raw::Env,
+    output: Option<thread::Result<O>>,
+    (complete, registration): Tracked<F>,
+) where
+    F: FnOnce(TaskContext, O) -> NeonResult<()>,
+{
+    if deregister(&output, registration) {
+        return;
+    }
+
+    TaskContext::with_context(Env::from(env), move |mut cx| {
+        let _ = match output {
+            Some(Ok(output)) => complete(cx, output),
+            Some(Err(panic)) => cx.throw_error(panic_message(&*panic)),
+            None => Ok(()),
+        };
+    });
+}
+
+fn complete_promise<O, V, F>(
+    env: neon_runtime::raw::Env,
+    output: Option<thread::Result<O>>,
+    ((deferred, complete), registration): Tracked<(Deferred, F)>,
+) where
+    V: Value,
+    F: FnOnce(TaskContext, O) -> JsResult<V>,
+{
+    let cancelled = deregister(&output, registration);
+
+    TaskContext::with_context(Env::from(env), move |mut cx| match output {
+        Some(Ok(output)) if !cancelled => {
+            deferred.try_catch_settle(cx, move |cx| complete(cx, output))
+        }
+        Some(Err(panic)) if !cancelled => {
+            if let Ok(err) = cx.error(panic_message(&*panic)) {
+                deferred.reject(&mut cx, err);
+            }
+        }
+        _ => {
+            if let Ok(err) = abort_error(&mut cx) {
+                deferred.reject(&mut cx, err);
+            }
+        }
+    });
+}
@@ -0,0 +1,197 @@
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use neon_runtime::async_work::AsyncWork;
+use neon_runtime::raw;
+
+use crate::context::Context;
+use crate::handle::Handle;
+use crate::object::Object;
+use crate::result::{JsResult, NeonResult};
+use crate::types::{JsBoolean, JsError, JsFunction, JsObject, JsUndefined, JsValue};
+
+/// Cooperatively cancels tasks created with [`Context::task`](crate::context::Context::task).
+///
+/// A token is attached to a task with [`TaskBuilder::cancel_with`](super::TaskBuilder::cancel_with),
+/// or to a [`Task`](super::Task) with [`Task::schedule_with`](super::Task::schedule_with).
+/// Cancelling it removes tasks from the libuv queue if they have not started running.
+/// Tasks that are already running continue until they return, so long-running work
+/// should check [`is_cancelled`](CancellationToken::is_cancelled) periodically. Either
+/// way, a cancelled task skips its completion: a promise is rejected with an
+/// `AbortError`, the closure passed to `and_then` is not called, and the callback of a
+/// `Task` is called with an `AbortError` instead of the result of `complete`.
+///
+/// Tokens are cheap to clone and may be sent to other threads; all clones share the
+/// same state.
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::task::CancellationToken;
+///
+/// // Counts up to `n`, unless aborted with the `AbortSignal` passed as the second argument
+/// fn count(mut cx: FunctionContext) -> JsResult<JsPromise> {
+///     let n = cx.argument::<JsNumber>(0)?.value(&mut cx) as u64;
+///     let signal = cx.argument::<JsObject>(1)?;
+///     let token = CancellationToken::from_signal(&mut cx, signal)?;
+///     let cancelled = token.clone();
+///
+///     let promise = cx
+///         .task(move || (0..n).take_while(|_| !cancelled.is_cancelled()).count())
+///         .cancel_with(token)
+///         .promise(|mut cx, count| Ok(cx.number(count as f64)));
+///
+///     Ok(promise)
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct CancellationToken(Arc<Inner>);
+
+#[derive(Default)]
+struct Inner {
+    cancelled: AtomicBool,
+    next_id: AtomicUsize,
+    queued: Mutex<Vec<Queued>>,
+}
+
+/// Async work that may be removed from the queue when the token is cancelled
+struct Queued {
+    id: usize,
+    env: raw::Env,
+    work: AsyncWork,
+}
+
+// # Safety
+// `env` is only compared with the `Env` of a `Context` to cancel work on its own thread.
+unsafe impl Send for Queued {}
+
+impl CancellationToken {
+    /// Creates a token that has not been cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a token that is cancelled when the JavaScript `AbortSignal` is aborted.
+    /// The token is already cancelled if the signal has been aborted.
+    pub fn from_signal<'a, C: Context<'a>>(
+        cx: &mut C,
+        signal: Handle<JsObject>,
+    ) -> NeonResult<Self> {
+        let token = Self::new();
+
+        if signal
+            .get(cx, "aborted")?
+            .downcast_or_throw::<JsBoolean, _>(cx)?
+            .value(cx)
+        {
+            token.0.cancelled.store(true, Ordering::SeqCst);
+            return Ok(token);
+        }
+
+        let add_event_listener = signal
+            .get(cx, "addEventListener")?
+            .downcast_or_throw::<JsFunction, _>(cx)?;
+
+        let listener = {
+            let token = token.clone();
+
+            JsFunction::from_closure(cx, move |mut cx| -> JsResult<JsUndefined> {
+                token.cancel(&mut cx);
+                Ok(cx.undefined())
+            })?
+        };
+
+        let options = cx.empty_object();
+        let once = cx.boolean(true);
+        let event = cx.string("abort");
+
+        options.set(cx, "once", once)?;
+        add_event_listener.call(
+            cx,
+            signal,
+            vec![
+                event.upcast::<JsValue>(),
+                listener.upcast(),
+                options.upcast(),
+            ],
+        )?;
+
+        Ok(token)
+    }
+
+    /// Returns `true` if the token has been cancelled. Safe to call from any thread.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Cancels the token and removes tasks of this JavaScript environment from the
+    /// libuv queue if they have not started running.
+    pub fn cancel<'a, C: Context<'a>>(&self, cx: &mut C) {
+        let env = cx.env().to_raw();
+
+        self.0.cancelled.store(true, Ordering::SeqCst);
+
+        // Cancelling calls `complete` asynchronously, which deregisters the work.
+        // Work of other environments is skipped when it completes.
+        for queued in self.0.queued.lock().unwrap().iter() {
+            if queued.env == env {
+                unsafe {
+                    queued.work.cancel(env);
+                }
+            }
+        }
+    }
+
+    /// Reserves an id for work that is about to be scheduled.
+    fn next_id(&self) -> usize {
+        self.0.next_id.fetch_add(1, Ordering::SeqCst)
+    }
+
+    /// Tracks queued work so that it can be cancelled, until it is deregistered.
+    pub(super) fn register(&self, id: usize, env: raw::Env, work: AsyncWork) {
+        self.0.queued.lock().unwrap().push(Queued { id, env, work });
+    }
+
+    /// Stops tracking work that is completing and can no longer be cancelled.
+    pub(super) fn deregister(&self, id: usize) {
+        self.0
+            .queued
+            .lock()
+            .unwrap()
+            .retain(|queued| queued.id != id);
+    }
+}
+
+/// Tracks scheduled work with the token that may cancel it
+pub(super) struct Registration {
+    pub(super) token: CancellationToken,
+    pub(super) id: usize,
+}
+
+impl Registration {
+    /// Reserves an id for work that is about to be scheduled with `token`.
+    pub(super) fn new(token: CancellationToken) -> Self {
+        Self {
+            id: token.next_id(),
+            token,
+        }
+    }
+
+    /// Deregisters the work, which is completing, and returns `true` if the token was
+    /// cancelled.
+    pub(super) fn deregister(self) -> bool {
+        self.token.deregister(self.id);
+        self.token.is_cancelled()
+    }
+}
+
+/// Creates an `Error` like those Node.js rejects with when an operation is aborted
+pub(super) fn abort_error<'a, C: Context<'a>>(cx: &mut C) -> JsResult<'a, JsError> {
+    let err = cx.error("The operation was aborted")?;
+    let name = cx.string("AbortError");
+    let code = cx.string("ABORT_ERR");
+
+    err.set(cx, "name", name)?;
+    err.set(cx, "code", code)?;
+
+    Ok(err)
+}
@@ -2,15 +2,39 @@
 
 use std::marker::{Send, Sized};
 use std::os::raw::c_void;
+#[cfg(feature = "napi-1")]
+use std::{ptr, thread};
 
+#[cfg(feature = "napi-1")]
+use self::cancel::{abort_error, Registration};
+#[cfg(feature = "napi-1")]
+use crate::context::internal::Env;
 use crate::context::TaskContext;
 use crate::handle::{Handle, Managed};
 use crate::result::JsResult;
+#[cfg(feature = "napi-1")]
+use crate::types::error::panic_message;
 use crate::types::{JsFunction, Value};
 use neon_runtime;
 use neon_runtime::raw;
 
+#[cfg(feature = "napi-1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-1")))]
+pub use self::builder::TaskBuilder;
+#[cfg(feature = "napi-1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-1")))]
+pub use self::cancel::CancellationToken;
+
+#[cfg(feature = "napi-1")]
+mod builder;
+#[cfg(feature = "napi-1")]
+mod cancel;
+
 /// A Rust task that can be executed in the background on the Node thread pool.
+///
+/// With the N-API runtime, tasks are scheduled as [async work][napi-docs].
+///
+/// [napi-docs]: https://nodejs.org/api/n-api.html#n_api_simple_asynchronous_operations
 pub trait Task: Send + Sized + 'static {
     /// The task's result type, which is sent back to the main thread to communicate a successful result back to JavaScript.
     type Output: Send + 'static;
@@ -38,21 +62,42 @@ pub trait Task: Send + Sized + 'static {
     /// ```js
     /// function callback(err, value) {}
     /// ```
+    ///
+    /// With the N-API runtime, a panic in `perform` is passed to `callback` as an `Error`,
+    /// and the task must be scheduled from within a Neon function on the JavaScript thread.
     fn schedule(self, callback: Handle<JsFunction>) {
-        let boxed_self = Box::new(self);
-        let self_raw = Box::into_raw(boxed_self);
-        let callback_raw = callback.to_raw();
-        unsafe {
-            neon_runtime::task::schedule(
-                self_raw.cast(),
-                perform_task::<Self>,
-                complete_task::<Self>,
-                callback_raw,
-            );
-        }
+        schedule(self, callback)
+    }
+
+    /// Schedule a task to be executed on a background thread, as with
+    /// [`schedule`](Task::schedule), that may be cancelled with `token`.
+    ///
+    /// If the task is cancelled before it completes, `complete` is not called and
+    /// `callback` is called with an `AbortError` instead. See [`CancellationToken`] for
+    /// how cancelling affects a task that is already running.
+    #[cfg(feature = "napi-1")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-1")))]
+    fn schedule_with(self, callback: Handle<JsFunction>, token: CancellationToken) {
+        schedule_with(self, callback, Some(token))
+    }
+}
+
+#[cfg(feature = "legacy-runtime")]
+fn schedule<T: Task>(task: T, callback: Handle<JsFunction>) {
+    let boxed_self = Box::new(task);
+    let self_raw = Box::into_raw(boxed_self);
+    let callback_raw = callback.to_raw();
+    unsafe {
+        neon_runtime::task::schedule(
+            self_raw.cast(),
+            perform_task::<T>,
+            complete_task::<T>,
+            callback_raw,
+        );
     }
 }
 
+#[cfg(feature = "legacy-runtime")]
 unsafe extern "C" fn perform_task<T: Task>(task: *mut c_void) -> *mut c_void {
     let task: Box<T> = Box::from_raw(task.cast());
     let result = task.perform();
@@ -60,6 +105,7 @@ unsafe extern "C" fn perform_task<T: Task>(task: *mut c_void) -> *mut c_void {
     Box::into_raw(Box::new(result)).cast()
 }
 
+#[cfg(feature = "legacy-runtime")]
 unsafe extern "C" fn complete_task<T: Task>(
     task: *mut c_void,
     result: *mut c_void,
@@ -73,3 +119,116 @@ unsafe extern "C" fn complete_task<T: Task>(
         }
     })
 }
+
+#[cfg(feature = "napi-1")]
+fn schedule<T: Task>(task: T, callback: Handle<JsFunction>) {
+    schedule_with(task, callback, None)
+}
+
+#[cfg(feature = "napi-1")]
+fn schedule_with<T: Task>(task: T, callback: Handle<JsFunction>, token: Option<CancellationToken>) {
+    let env = Env::current().to_raw();
+    let registration = token.map(Registration::new);
+    let tracked = registration
+        .as_ref()
+        .map(|registration| (registration.token.clone(), registration.id));
+
+    unsafe {
+        let callback = neon_runtime::reference::new(env, callback.to_raw()) as *mut c_void;
+        let work = neon_runtime::async_work::schedule(
+            env,
+            (task, tracked.as_ref().map(|(token, _)| token.clone())),
+            perform_task,
+            complete_task::<T>,
+            (callback, registration),
+        );
+
+        if let Some((token, id)) = tracked {
+            token.register(id, env, work);
+
+            // The callback is still called asynchronously if the token was already cancelled
+            if token.is_cancelled() {
+                work.cancel(env);
+            }
+        }
+    }
+}
+
+/// The task and its result, or `None` if it was cancelled before it was performed
+#[cfg(feature = "napi-1")]
+type TaskOutput<T> = (T, Option<Result<<T as Task>::Output, <T as Task>::Error>>);
+
+#[cfg(feature = "napi-1")]
+fn perform_task<T: Task>((task, token): (T, Option<CancellationToken>)) -> TaskOutput<T> {
+    if token.map_or(false, |token| token.is_cancelled()) {
+        return (task, None);
+    }
+
+    let result = task.perform();
+
+    (task, Some(result))
+}
+
+/// Calls `callback` with the completion of the task, like the legacy runtime: an
+/// exception thrown by `complete` or a panic in `perform` is passed as the first
+/// argument, and otherwise the completion is passed as the second. A cancelled task
+/// passes an `AbortError` as the first argument.
+#[cfg(feature = "napi-1")]
+fn complete_task<T: Task>(
+    env: raw::Env,
+    output: Option<thread::Result<TaskOutput<T>>>,
+    (callback, registration): (*mut c_void, Option<Registration>),
+) {
+    // Work is only cancelled before it executes if it has a token
+    let cancelled = registration.map_or(false, Registration::deregister) || output.is_none();
+
+    unsafe {
+        let mut argv = [ptr::null_mut(); 2];
+
+        neon_runtime::primitive::null(&mut argv[0], env);
+        neon_runtime::primitive::undefined(&mut argv[1], env);
+
+        match output {
+            Some(Ok((task, Some(result)))) if !cancelled => {
+                let completion = TaskContext::with_context(Env::from(env), move |cx| {
+                    task.complete(cx, result)
+                        .map(|completion| completion.to_raw())
+                });
+
+                match completion {
+                    Ok(completion) => argv[1] = completion,
+                    Err(_) => {
+                        neon_runtime::error::catch_error(env, &mut argv[0]);
+                    }
+                }
+            }
+            Some(Err(panic)) if !cancelled => {
+                let message = panic_message(&*panic);
+                let mut msg = ptr::null_mut();
+
+                neon_runtime::string::new(&mut msg, env, message.as_ptr(), message.len() as i32);
+                neon_runtime::error::new_error(env, &mut argv[0], msg);
+            }
+            _ => {
+                let err = TaskContext::with_context(Env::from(env), |mut cx| {
+                    abort_error(&mut cx).map(|err| err.to_raw())
+                });
+
+                match err {
+                    Ok(err) => argv[0] = err,
+                    Err(_) => {
+                        neon_runtime::error::catch_error(env, &mut argv[0]);
+                    }
+                }
+            }
+        }
+
+        let mut this = ptr::null_mut();
+        let mut result = ptr::null_mut();
+        let fun = neon_runtime::reference::get(env, callback.cast());
+
+        neon_runtime::reference::unreference(env, callback.cast());
+        neon_runtime::primitive::undefined(&mut this, env);
+        neon_runtime::fun::call(&mut result, env, fun, this, 2, argv.as_mut_ptr().cast());
+    }
+}
@@ -0,0 +1,276 @@
+use super::{Value, ValueInternal};
+use crate::context::internal::Env;
+use crate::context::Context;
+use crate::handle::{Handle, Managed};
+use crate::result::JsResult;
+use crate::types::build;
+use neon_runtime;
+use neon_runtime::raw;
+use std::error::Error;
+use std::fmt;
+use std::fmt::Debug;
+
+/// A JavaScript BigInt value
+///
+/// Values that fit in a primitive Rust integer can be converted losslessly with the
+/// `from_*` constructors and the `to_*` getters. Getters return a [`BigIntError`](BigIntError)
+/// when the value does not fit in the requested type. Larger values can be read and
+/// written as a sign and a little-endian slice of 64-bit digits.
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// # use neon::types::JsBigInt;
+/// fn add_one(mut cx: FunctionContext) -> JsResult<JsBigInt> {
+///     let n = cx.argument::<JsBigInt>(0)?;
+///     let n = n
+///         .to_i64(&mut cx)
+///         .or_else(|err| cx.throw_range_error(err.to_string()))?;
+///
+///     Ok(JsBigInt::from_i128(&mut cx, i128::from(n) + 1))
+/// }
+/// ```
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+pub struct JsBigInt(raw::Local);
+
+impl Value for JsBigInt {}
+
+impl Managed for JsBigInt {
+    fn to_raw(self) -> raw::Local {
+        self.0
+    }
+
+    fn from_raw(_: Env, h: raw::Local) -> Self {
+        JsBigInt(h)
+    }
+}
+
+/// The sign of a [`JsBigInt`](JsBigInt)
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+pub enum Sign {
+    Positive,
+    Negative,
+}
+
+impl Sign {
+    fn from_sign_bit(negative: bool) -> Self {
+        if negative {
+            Sign::Negative
+        } else {
+            Sign::Positive
+        }
+    }
+
+    fn is_negative(self) -> bool {
+        self == Sign::Negative
+    }
+
+    fn out_of_range(self) -> BigIntError {
+        match self {
+            Sign::Positive => BigIntError(BigIntErrorKind::Overflow),
+            Sign::Negative => BigIntError(BigIntErrorKind::Underflow),
+        }
+    }
+}
+
+/// The Error struct for a BigInt conversion
+#[derive(Debug)]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+pub struct BigIntError(BigIntErrorKind);
+
+impl BigIntError {
+    pub fn kind(&self) -> BigIntErrorKind {
+        self.0
+    }
+}
+
+impl fmt::Display for BigIntError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(self.0.as_str())
+    }
+}
+
+impl Error for BigIntError {}
+
+/// The error kinds corresponding to `BigIntError`
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+pub enum BigIntErrorKind {
+    Overflow,
+    Underflow,
+}
+
+impl BigIntErrorKind {
+    fn as_str(&self) -> &'static str {
+        match *self {
+            BigIntErrorKind::Overflow => "BigInt overflow",
+            BigIntErrorKind::Underflow => "BigInt underflow",
+        }
+    }
+}
+
+impl JsBigInt {
+    /// Creates a BigInt from an `i64`
+    pub fn from_i64<'a, C: Context<'a>>(cx: &mut C, n: i64) -> Handle<'a, JsBigInt> {
+        let local = unsafe { neon_runtime::bigint::new_int64(cx.env().to_raw(), n) };
+        Handle::new_internal(JsBigInt(local))
+    }
+
+    /// Creates a BigInt from a `u64`
+    pub fn from_u64<'a, C: Context<'a>>(cx: &mut C, n: u64) -> Handle<'a, JsBigInt> {
+        let local = unsafe { neon_runtime::bigint::new_uint64(cx.env().to_raw(), n) };
+        Handle::new_internal(JsBigInt(local))
+    }
+
+    /// Creates a BigInt from an `i128`
+    pub fn from_i128<'a, C: Context<'a>>(cx: &mut C, n: i128) -> Handle<'a, JsBigInt> {
+        let sign = Sign::from_sign_bit(n < 0);
+
+        Self::from_magnitude_u128(cx, sign, n.unsigned_abs())
+    }
+
+    /// Creates a BigInt from a `u128`
+    pub fn from_u128<'a, C: Context<'a>>(cx: &mut C, n: u128) -> Handle<'a, JsBigInt> {
+        Self::from_magnitude_u128(cx, Sign::Positive, n)
+    }
+
+    fn from_magnitude_u128<'a, C: Context<'a>>(
+        cx: &mut C,
+        sign: Sign,
+        n: u128,
+    ) -> Handle<'a, JsBigInt> {
+        let digits = [n as u64, (n >> 64) as u64];
+
+        // A two word BigInt is always well within the engine's size limit
+        Self::from_digits_le(cx, sign, &digits).expect("BigInt from u128 should not throw")
+    }
+
+    /// Creates a BigInt from a sign and the little-endian 64-bit digits of its magnitude.
+    /// Throws a `RangeError` if the value exceeds the maximum BigInt size of the engine.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # use neon::types::{JsBigInt, Sign};
+    /// # fn example(mut cx: FunctionContext) -> JsResult<JsBigInt> {
+    /// // -(2^64 + 1)
+    /// let n = JsBigInt::from_digits_le(&mut cx, Sign::Negative, &[1, 1])?;
+    /// # Ok(n)
+    /// # }
+    /// ```
+    pub fn from_digits_le<'a, C: Context<'a>>(
+        cx: &mut C,
+        sign: Sign,
+        digits: &[u64],
+    ) -> JsResult<'a, JsBigInt> {
+        let env = cx.env();
+
+        build(env, |out| unsafe {
+            neon_runtime::bigint::new_words(out, env.to_raw(), sign.is_negative(), digits)
+        })
+    }
+
+    /// Gets the BigInt's value as an `i64`. Errors if the value does not fit in an `i64`
+    pub fn to_i64<'a, C: Context<'a>>(self, cx: &mut C) -> Result<i64, BigIntError> {
+        let env = cx.env().to_raw();
+        let (n, lossless) = unsafe { neon_runtime::bigint::value_int64(env, self.to_raw()) };
+
+        if lossless {
+            Ok(n)
+        } else {
+            Err(self.range_error(cx))
+        }
+    }
+
+    /// Gets the BigInt's value as a `u64`. Errors if the value does not fit in a `u64`
+    pub fn to_u64<'a, C: Context<'a>>(self, cx: &mut C) -> Result<u64, BigIntError> {
+        let env = cx.env().to_raw();
+        let (n, lossless) = unsafe { neon_runtime::bigint::value_uint64(env, self.to_raw()) };
+
+        if lossless {
+            Ok(n)
+        } else {
+            Err(self.range_error(cx))
+        }
+    }
+
+    /// Gets the BigInt's value as an `i128`. Errors if the value does not fit in an `i128`
+    pub fn to_i128<'a, C: Context<'a>>(self, cx: &mut C) -> Result<i128, BigIntError> {
+        let (sign, n) = self.to_magnitude_u128(cx)?;
+
+        match sign {
+            Sign::Positive if n <= i128::MAX as u128 => Ok(n as i128),
+            Sign::Negative if n <= i128::MIN.unsigned_abs() => Ok((n as i128).wrapping_neg()),
+            _ => Err(sign.out_of_range()),
+        }
+    }
+
+    /// Gets the BigInt's value as a `u128`. Errors if the value does not fit in a `u128`
+    pub fn to_u128<'a, C: Context<'a>>(self, cx: &mut C) -> Result<u128, BigIntError> {
+        match self.to_magnitude_u128(cx)? {
+            (Sign::Negative, n) if n != 0 => Err(Sign::Negative.out_of_range()),
+            (_, n) => Ok(n),
+        }
+    }
+
+    fn to_magnitude_u128<'a, C: Context<'a>>(
+        self,
+        cx: &mut C,
+    ) -> Result<(Sign, u128), BigIntError> {
+        let mut digits = [0; 2];
+        let (sign, len) = self.read_digits_le(cx, &mut digits);
+
+        if len > digits.len() {
+            return Err(sign.out_of_range());
+        }
+
+        Ok((sign, u128::from(digits[0]) | (u128::from(digits[1]) << 64)))
+    }
+
+    /// Gets the sign and the little-endian 64-bit digits of the BigInt's magnitude
+    pub fn to_digits_le<'a, C: Context<'a>>(self, cx: &mut C) -> (Sign, Vec<u64>) {
+        let mut digits = vec![0; self.len(cx)];
+        let (sign, _) = self.read_digits_le(cx, &mut digits);
+
+        (sign, digits)
+    }
+
+    /// Reads the little-endian 64-bit digits of the BigInt's magnitude into `digits`.
+    ///
+    /// At most `digits.len()` digits are written. Returns the sign and the total number of
+    /// digits in the BigInt; if this is greater than `digits.len()`, the value was truncated.
+    pub fn read_digits_le<'a, C: Context<'a>>(
+        self,
+        cx: &mut C,
+        digits: &mut [u64],
+    ) -> (Sign, usize) {
+        let env = cx.env().to_raw();
+        let (negative, len) = unsafe { neon_runtime::bigint::words(env, self.to_raw(), digits) };
+
+        (Sign::from_sign_bit(negative), len)
+    }
+
+    /// Gets the number of 64-bit digits needed to represent the BigInt's magnitude
+    pub fn len<'a, C: Context<'a>>(self, cx: &mut C) -> usize {
+        unsafe { neon_runtime::bigint::word_count(cx.env().to_raw(), self.to_raw()) }
+    }
+
+    fn range_error<'a, C: Context<'a>>(self, cx: &mut C) -> BigIntError {
+        let (sign, _) = self.read_digits_le(cx, &mut []);
+
+        sign.out_of_range()
+    }
+}
+
+impl ValueInternal for JsBigInt {
+    fn name() -> String {
+        "bigint".to_string()
+    }
+
+    fn is_typeof<Other: Value>(env: Env, other: Other) -> bool {
+        unsafe { neon_runtime::tag::is_bigint(env.to_raw(), other.to_raw()) }
+    }
+}
@@ -12,6 +12,10 @@ use crate::types::internal::ValueInternal;
 use crate::types::{build, Object, Value};
 use neon_runtime;
 use neon_runtime::raw;
+#[cfg(feature = "napi-1")]
+use neon_runtime::raw::TypedArrayType;
+#[cfg(feature = "napi-1")]
+use std::convert::TryFrom;
 use std::marker::PhantomData;
 use std::mem::{self, MaybeUninit};
 use std::os::raw::c_void;
@@ -131,7 +135,273 @@ impl Value for JsArrayBuffer {}
 
 impl Object for JsArrayBuffer {}
 
-/// A reference to the internal backing buffer data of a `Buffer` or `ArrayBuffer` object, which can be accessed via the `Borrow` and `BorrowMut` traits.
+/// A JavaScript [`TypedArray`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/TypedArray)
+/// view over an `ArrayBuffer`, with elements of type `T`.
+///
+/// For example, a `JsTypedArray<f32>` is a `Float32Array` and a `JsTypedArray<u64>`
+/// is a `BigUint64Array`. Borrowing a typed array yields only the elements in the view.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use neon::prelude::*;
+/// # use neon::types::JsTypedArray;
+/// fn sum(mut cx: FunctionContext) -> JsResult<JsNumber> {
+///     let arr: Handle<JsTypedArray<f64>> = cx.argument(0)?;
+///     let sum = cx.borrow(&arr, |data| data.as_slice::<f64>().iter().sum::<f64>());
+///
+///     Ok(cx.number(sum))
+/// }
+/// ```
+#[cfg(feature = "napi-1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-1")))]
+#[repr(C)]
+pub struct JsTypedArray<T: TypedArrayElement> {
+    local: raw::Local,
+    _type: PhantomData<T>,
+}
+
+#[cfg(feature = "napi-1")]
+impl<T: TypedArrayElement> Clone for JsTypedArray<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+#[cfg(feature = "napi-1")]
+impl<T: TypedArrayElement> Copy for JsTypedArray<T> {}
+
+#[cfg(feature = "napi-1")]
+impl<T: TypedArrayElement + 'static> JsTypedArray<T> {
+    /// Constructs a new typed array of `len` zero-filled elements, backed by a new `ArrayBuffer`.
+    pub fn new<'a, C: Context<'a>>(cx: &mut C, len: u32) -> JsResult<'a, Self> {
+        let size = (len as usize)
+            .checked_mul(mem::size_of::<T>())
+            .and_then(|size| u32::try_from(size).ok());
+
+        let size = match size {
+            Some(size) => size,
+            None => return cx.throw_range_error("Invalid typed array length"),
+        };
+
+        let buffer = JsArrayBuffer::new(cx, size)?;
+
+        Self::from_buffer(cx, buffer, 0, len as usize)
+    }
+
+    /// Constructs a new typed array view of `len` elements over `buffer`, starting at `byte_offset`.
+    ///
+    /// Throws a `RangeError` if `byte_offset` is not a multiple of the element size or if
+    /// the view does not fit in the buffer.
+    pub fn from_buffer<'a, C: Context<'a>>(
+        cx: &mut C,
+        buffer: Handle<JsArrayBuffer>,
+        byte_offset: usize,
+        len: usize,
+    ) -> JsResult<'a, Self> {
+        let env = cx.env();
+
+        build(env, |out| unsafe {
+            neon_runtime::typedarray::new(
+                out,
+                env.to_raw(),
+                T::TYPED_ARRAY_TYPE,
+                len,
+                buffer.to_raw(),
+                byte_offset,
+            )
+        })
+    }
+
+    /// Returns the `ArrayBuffer` backing this typed array.
+    pub fn buffer<'a, C: Context<'a>>(self, cx: &mut C) -> Handle<'a, JsArrayBuffer> {
+        let info = unsafe { neon_runtime::typedarray::info(cx.env().to_raw(), self.to_raw()) };
+
+        Handle::new_internal(JsArrayBuffer(info.buf))
+    }
+
+    /// Returns the offset, in bytes, of this view from the start of its `ArrayBuffer`.
+    pub fn offset<'a, C: Context<'a>>(self, cx: &mut C) -> usize {
+        unsafe { neon_runtime::typedarray::info(cx.env().to_raw(), self.to_raw()).offset }
+    }
+
+    /// Returns the number of elements in this typed array.
+    pub fn len<'a, C: Context<'a>>(self, cx: &mut C) -> usize {
+        unsafe { neon_runtime::typedarray::info(cx.env().to_raw(), self.to_raw()).length }
+    }
+
+    /// Returns `true` if this typed array has no elements.
+    pub fn is_empty<'a, C: Context<'a>>(self, cx: &mut C) -> bool {
+        self.len(cx) == 0
+    }
+
+    fn data<'b>(self, env: Env) -> BinaryData<'b> {
+        let info = unsafe { neon_runtime::typedarray::info(env.to_raw(), self.to_raw()) };
+
+        BinaryData {
+            base: info.data,
+            size: info.length * mem::size_of::<T>(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "napi-1")]
+impl<T: TypedArrayElement + 'static> Managed for JsTypedArray<T> {
+    fn to_raw(self) -> raw::Local {
+        self.local
+    }
+
+    fn from_raw(_env: Env, h: raw::Local) -> Self {
+        JsTypedArray {
+            local: h,
+            _type: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "napi-1")]
+impl<T: TypedArrayElement + 'static> ValueInternal for JsTypedArray<T> {
+    fn name() -> String {
+        let name = match T::TYPED_ARRAY_TYPE {
+            TypedArrayType::I8 => "Int8Array",
+            TypedArrayType::U8 => "Uint8Array",
+            TypedArrayType::U8Clamped => "Uint8ClampedArray",
+            TypedArrayType::I16 => "Int16Array",
+            TypedArrayType::U16 => "Uint16Array",
+            TypedArrayType::I32 => "Int32Array",
+            TypedArrayType::U32 => "Uint32Array",
+            TypedArrayType::F32 => "Float32Array",
+            TypedArrayType::F64 => "Float64Array",
+            TypedArrayType::I64 => "BigInt64Array",
+            TypedArrayType::U64 => "BigUint64Array",
+        };
+
+        name.to_string()
+    }
+
+    fn is_typeof<Other: Value>(env: Env, other: Other) -> bool {
+        unsafe {
+            neon_runtime::tag::is_typedarray(env.to_raw(), other.to_raw())
+                && neon_runtime::typedarray::info(env.to_raw(), other.to_raw()).typ
+                    == T::TYPED_ARRAY_TYPE
+        }
+    }
+}
+
+#[cfg(feature = "napi-1")]
+impl<T: TypedArrayElement + 'static> Value for JsTypedArray<T> {}
+
+#[cfg(feature = "napi-1")]
+impl<T: TypedArrayElement + 'static> Object for JsTypedArray<T> {}
+
+/// A JavaScript [`DataView`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/DataView)
+/// over a window of an `ArrayBuffer`.
+///
+/// Borrowing a `DataView` yields only the bytes in its window, which makes it
+/// possible to parse binary data in place without copying.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use neon::prelude::*;
+/// # use neon::types::JsDataView;
+/// fn read_header(mut cx: FunctionContext) -> JsResult<JsNumber> {
+///     let view: Handle<JsDataView> = cx.argument(0)?;
+///     let len = cx.borrow(&view, |data| {
+///         let bytes = data.as_slice::<u8>();
+///         u16::from_be_bytes([bytes[0], bytes[1]])
+///     });
+///
+///     Ok(cx.number(len))
+/// }
+/// ```
+#[cfg(feature = "napi-1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-1")))]
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct JsDataView(raw::Local);
+
+#[cfg(feature = "napi-1")]
+impl JsDataView {
+    /// Constructs a new `DataView` of `len` bytes over `buffer`, starting at `byte_offset`.
+    ///
+    /// Throws a `RangeError` if the view does not fit in the buffer.
+    pub fn new<'a, C: Context<'a>>(
+        cx: &mut C,
+        buffer: Handle<JsArrayBuffer>,
+        byte_offset: usize,
+        len: usize,
+    ) -> JsResult<'a, JsDataView> {
+        let env = cx.env();
+
+        build(env, |out| unsafe {
+            neon_runtime::dataview::new(out, env.to_raw(), len, buffer.to_raw(), byte_offset)
+        })
+    }
+
+    /// Returns the `ArrayBuffer` backing this view.
+    pub fn buffer<'a, C: Context<'a>>(self, cx: &mut C) -> Handle<'a, JsArrayBuffer> {
+        let info = unsafe { neon_runtime::dataview::info(cx.env().to_raw(), self.to_raw()) };
+
+        Handle::new_internal(JsArrayBuffer(info.buf))
+    }
+
+    /// Returns the offset, in bytes, of this view from the start of its `ArrayBuffer`.
+    pub fn offset<'a, C: Context<'a>>(self, cx: &mut C) -> usize {
+        unsafe { neon_runtime::dataview::info(cx.env().to_raw(), self.to_raw()).offset }
+    }
+
+    /// Returns the length of this view, in bytes.
+    pub fn len<'a, C: Context<'a>>(self, cx: &mut C) -> usize {
+        unsafe { neon_runtime::dataview::info(cx.env().to_raw(), self.to_raw()).byte_length }
+    }
+
+    /// Returns `true` if this view is empty.
+    pub fn is_empty<'a, C: Context<'a>>(self, cx: &mut C) -> bool {
+        self.len(cx) == 0
+    }
+
+    fn data<'b>(self, env: Env) -> BinaryData<'b> {
+        let info = unsafe { neon_runtime::dataview::info(env.to_raw(), self.to_raw()) };
+
+        BinaryData {
+            base: info.data,
+            size: info.byte_length,
+            phantom: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "napi-1")]
+impl Managed for JsDataView {
+    fn to_raw(self) -> raw::Local {
+        self.0
+    }
+
+    fn from_raw(_env: Env, h: raw::Local) -> Self {
+        JsDataView(h)
+    }
+}
+
+#[cfg(feature = "napi-1")]
+impl ValueInternal for JsDataView {
+    fn name() -> String {
+        "DataView".to_string()
+    }
+
+    fn is_typeof<Other: Value>(env: Env, other: Other) -> bool {
+        unsafe { neon_runtime::tag::is_dataview(env.to_raw(), other.to_raw()) }
+    }
+}
+
+#[cfg(feature = "napi-1")]
+impl Value for JsDataView {}
+
+#[cfg(feature = "napi-1")]
+impl Object for JsDataView {}
+
+/// A reference to the internal backing buffer data of a `Buffer`, `ArrayBuffer`, typed array or `DataView` object, which can be accessed via the `Borrow` and `BorrowMut` traits.
 #[derive(Clone, Copy)]
 #[repr(C)]
 pub struct BinaryData<'a> {
@@ -153,16 +423,49 @@ unsafe impl<'a> Pointer for BinaryData<'a> {
 /// The trait for element types by which a buffer's binary data can be indexed.
 pub trait BinaryViewType: Sized {}
 
-impl BinaryViewType for u8 {}
-impl BinaryViewType for i8 {}
-impl BinaryViewType for u16 {}
-impl BinaryViewType for i16 {}
-impl BinaryViewType for u32 {}
-impl BinaryViewType for i32 {}
-impl BinaryViewType for u64 {}
-impl BinaryViewType for i64 {}
-impl BinaryViewType for f32 {}
-impl BinaryViewType for f64 {}
+/// The element types of a [`JsTypedArray`].
+///
+/// This trait is sealed and cannot be implemented for types outside of Neon.
+#[cfg(feature = "napi-1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-1")))]
+pub trait TypedArrayElement: BinaryViewType + private::Sealed {
+    #[doc(hidden)]
+    const TYPED_ARRAY_TYPE: TypedArrayType;
+}
+
+#[cfg(feature = "napi-1")]
+mod private {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_binary_view_type {
+    ($($typ:ty => $typed_array_type:ident,)*) => {
+        $(
+            impl BinaryViewType for $typ {}
+
+            #[cfg(feature = "napi-1")]
+            impl private::Sealed for $typ {}
+
+            #[cfg(feature = "napi-1")]
+            impl TypedArrayElement for $typ {
+                const TYPED_ARRAY_TYPE: TypedArrayType = TypedArrayType::$typed_array_type;
+            }
+        )*
+    };
+}
+
+impl_binary_view_type! {
+    u8 => U8,
+    i8 => I8,
+    u16 => U16,
+    i16 => I16,
+    u32 => U32,
+    i32 => I32,
+    u64 => U64,
+    i64 => I64,
+    f32 => F32,
+    f64 => F64,
+}
 
 impl<'a> BinaryData<'a> {
     /// Produces an immutable slice as a view into the contents of this buffer.
@@ -323,3 +626,59 @@ impl<'a> BorrowMut for &'a mut JsArrayBuffer {
         unsafe { RefMut::new(guard, data.assume_init()) }
     }
 }
+
+#[cfg(feature = "napi-1")]
+impl<'a, T: TypedArrayElement + 'static> Borrow for &'a JsTypedArray<T> {
+    type Target = BinaryData<'a>;
+
+    fn try_borrow<'b>(self, guard: &'b Lock<'b>) -> Result<Ref<'b, Self::Target>, LoanError> {
+        unsafe { Ref::new(guard, self.data(guard.env)) }
+    }
+}
+
+#[cfg(feature = "napi-1")]
+impl<'a, T: TypedArrayElement + 'static> Borrow for &'a mut JsTypedArray<T> {
+    type Target = BinaryData<'a>;
+
+    fn try_borrow<'b>(self, guard: &'b Lock<'b>) -> Result<Ref<'b, Self::Target>, LoanError> {
+        (self as &'a JsTypedArray<T>).try_borrow(guard)
+    }
+}
+
+#[cfg(feature = "napi-1")]
+impl<'a, T: TypedArrayElement + 'static> BorrowMut for &'a mut JsTypedArray<T> {
+    fn try_borrow_mut<'b>(
+        self,
+        guard: &'b Lock<'b>,
+    ) -> Result<RefMut<'b, Self::Target>, LoanError> {
+        unsafe { RefMut::new(guard, self.data(guard.env)) }
+    }
+}
+
+#[cfg(feature = "napi-1")]
+impl<'a> Borrow for &'a JsDataView {
+    type Target = BinaryData<'a>;
+
+    fn try_borrow<'b>(self, guard: &'b Lock<'b>) -> Result<Ref<'b, Self::Target>, LoanError> {
+        unsafe { Ref::new(guard, self.data(guard.env)) }
+    }
+}
+
+#[cfg(feature = "napi-1")]
+impl<'a> Borrow for &'a mut JsDataView {
+    type Target = BinaryData<'a>;
+
+    fn try_borrow<'b>(self, guard: &'b Lock<'b>) -> Result<Ref<'b, Self::Target>, LoanError> {
+        (self as &'a JsDataView).try_borrow(guard)
+    }
+}
+
+#[cfg(feature = "napi-1")]
+impl<'a> BorrowMut for &'a mut JsDataView {
+    fn try_borrow_mut<'b>(
+        self,
+        guard: &'b Lock<'b>,
+    ) -> Result<RefMut<'b, Self::Target>, LoanError> {
+        unsafe { RefMut::new(guard, self.data(guard.env)) }
+    }
+}
@@ -1,16 +1,26 @@
 //! Types and traits representing JavaScript error values.
 
+use std::any::Any;
+#[cfg(feature = "napi-1")]
+use std::error::Error;
+#[cfg(feature = "napi-1")]
+use std::fmt;
 use std::panic::{catch_unwind, UnwindSafe};
+#[cfg(feature = "napi-1")]
+use std::ptr;
 
 use neon_runtime;
 use neon_runtime::raw;
 
 use crate::context::internal::Env;
 use crate::context::Context;
+use crate::object::PropertyKey;
 use crate::result::{NeonResult, Throw};
 use crate::types::internal::ValueInternal;
 use crate::types::utf8::Utf8;
-use crate::types::{build, Handle, Managed, Object, Value};
+use crate::types::{build, Handle, JsFunction, JsString, Managed, Object, Value};
+#[cfg(feature = "napi-1")]
+use crate::types::{JsObject, JsSymbol, JsUndefined, JsValue};
 
 /// A JS `Error` object.
 #[repr(C)]
@@ -41,6 +51,21 @@ impl Value for JsError {}
 
 impl Object for JsError {}
 
+/// The class of error created by [`JsError::with_code`](JsError::with_code).
+#[cfg(feature = "napi-1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-1")))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// [`Error`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Error)
+    Error,
+    /// [`TypeError`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/TypeError)
+    TypeError,
+    /// [`RangeError`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/RangeError)
+    RangeError,
+    /// [`SyntaxError`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/SyntaxError)
+    SyntaxError,
+}
+
 impl JsError {
     /// Creates a direct instance of the [`Error`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Error) class.
     pub fn error<'a, C: Context<'a>, S: AsRef<str>>(
@@ -77,6 +102,314 @@ impl JsError {
             true
         })
     }
+
+    /// Creates an instance of the [`SyntaxError`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/SyntaxError) class.
+    pub fn syntax_error<'a, C: Context<'a>, S: AsRef<str>>(
+        cx: &mut C,
+        msg: S,
+    ) -> NeonResult<Handle<'a, JsError>> {
+        let msg = cx.string(msg.as_ref());
+
+        JsError::new_syntax_error(cx, msg)
+    }
+
+    fn new_syntax_error<'a, C: Context<'a>>(
+        cx: &mut C,
+        msg: Handle<JsString>,
+    ) -> NeonResult<Handle<'a, JsError>> {
+        // Node-API does not provide a constructor for `SyntaxError`
+        let global = cx.global();
+        let ctor = global
+            .get(cx, "SyntaxError")?
+            .downcast_or_throw::<JsFunction, _>(cx)?;
+
+        ctor.construct(cx, vec![msg])?.downcast_or_throw(cx)
+    }
+
+    #[cfg(feature = "napi-1")]
+    /// Creates an error of the given `kind` with a `code` property, in the same way
+    /// as errors thrown by Node.js itself.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # use neon::types::ErrorKind;
+    /// fn open(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    ///     let path = cx.argument::<JsString>(0)?;
+    ///     let err = JsError::with_code(&mut cx, ErrorKind::Error, "ENOENT", "no such file")?;
+    ///     let errno = cx.number(-2);
+    ///
+    ///     err.set_properties(&mut cx, vec![("errno", errno.upcast::<JsValue>()), ("path", path.upcast())])?;
+    ///
+    ///     cx.throw(err)
+    /// }
+    /// ```
+    pub fn with_code<'a, C: Context<'a>, S: AsRef<str>, M: AsRef<str>>(
+        cx: &mut C,
+        kind: ErrorKind,
+        code: S,
+        msg: M,
+    ) -> NeonResult<Handle<'a, JsError>> {
+        let code = cx.string(code.as_ref());
+        let msg = cx.string(msg.as_ref());
+
+        JsError::new_kind(cx, kind, Some(code), msg)
+    }
+
+    #[cfg(feature = "napi-1")]
+    fn new_kind<'a, C: Context<'a>>(
+        cx: &mut C,
+        kind: ErrorKind,
+        code: Option<Handle<JsString>>,
+        msg: Handle<JsString>,
+    ) -> NeonResult<Handle<'a, JsError>> {
+        let env = cx.env().to_raw();
+        let new_error = match kind {
+            ErrorKind::Error => neon_runtime::error::new_error_with_code,
+            ErrorKind::TypeError => neon_runtime::error::new_type_error_with_code,
+            ErrorKind::RangeError => neon_runtime::error::new_range_error_with_code,
+            ErrorKind::SyntaxError => {
+                let err = JsError::new_syntax_error(cx, msg)?;
+                if let Some(code) = code {
+                    err.set(cx, "code", code)?;
+                }
+                return Ok(err);
+            }
+        };
+        let code = code.map(|code| code.to_raw()).unwrap_or_else(ptr::null_mut);
+
+        build(cx.env(), |out| unsafe {
+            new_error(env, out, code, msg.to_raw());
+            true
+        })
+    }
+
+    /// Sets several properties on the error at once, such as the `errno` or `path`
+    /// of a failed system call.
+    pub fn set_properties<'a, C, K, V, I>(self, cx: &mut C, props: I) -> NeonResult<()>
+    where
+        C: Context<'a>,
+        K: PropertyKey,
+        V: Value,
+        I: IntoIterator<Item = (K, Handle<'a, V>)>,
+    {
+        for (key, value) in props {
+            self.set(cx, key, value)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// An owned snapshot of a thrown JavaScript value.
+///
+/// A `Handle<JsValue>` caught with [`Context::try_catch`](crate::context::Context::try_catch)
+/// cannot outlive its handle scope or leave the JavaScript thread. An `ErrorInfo` captures
+/// the `name`, `message`, `stack`, `code` and `cause` of the value as Rust strings, so it
+/// can be sent to other threads, logged, or wrapped in other Rust errors. It can also be
+/// converted back into an equivalent JavaScript error with [`ErrorInfo::throw`](ErrorInfo::throw).
+///
+/// Values that are not objects, such as the result of `throw "oops"`, are captured as
+/// a `message` without a `name`.
+///
+/// ```
+/// # use neon::prelude::*;
+/// # use neon::types::ErrorInfo;
+/// fn call_and_log(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+///     let f = cx.argument::<JsFunction>(0)?;
+///     let this = cx.undefined();
+///     let args: Vec<Handle<JsValue>> = vec![];
+///
+///     if let Err(err) = cx.try_catch(|cx| f.call(cx, this, args)) {
+///         let info = ErrorInfo::from_value(&mut cx, err)?;
+///
+///         std::thread::spawn(move || eprintln!("callback failed: {}", info));
+///     }
+///
+///     Ok(cx.undefined())
+/// }
+/// ```
+#[cfg(feature = "napi-1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-1")))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorInfo {
+    name: Option<String>,
+    message: String,
+    stack: Option<String>,
+    code: Option<String>,
+    cause: Option<Box<ErrorInfo>>,
+}
+
+#[cfg(feature = "napi-1")]
+impl ErrorInfo {
+    // Guards against `cause` cycles, e.g. `err.cause = err`
+    const MAX_CAUSE_DEPTH: usize = 32;
+
+    /// Captures a snapshot of a thrown value.
+    ///
+    /// Reading the properties of the value may run getters, so this may throw.
+    pub fn from_value<'a, C: Context<'a>, V: Value>(
+        cx: &mut C,
+        value: Handle<V>,
+    ) -> NeonResult<ErrorInfo> {
+        ErrorInfo::capture(cx, value.upcast(), ErrorInfo::MAX_CAUSE_DEPTH)
+    }
+
+    fn capture<'a, C: Context<'a>>(
+        cx: &mut C,
+        value: Handle<JsValue>,
+        depth: usize,
+    ) -> NeonResult<ErrorInfo> {
+        let obj = match value.downcast::<JsObject, _>(cx) {
+            Ok(obj) => obj,
+            Err(_) => {
+                let message = match value.downcast::<JsSymbol, _>(cx) {
+                    Ok(sym) => format!("Symbol({})", sym.description(cx)?.unwrap_or_default()),
+                    Err(_) => value.to_string(cx)?.value(cx),
+                };
+
+                return Ok(ErrorInfo {
+                    name: None,
+                    message,
+                    stack: None,
+                    code: None,
+                    cause: None,
+                });
+            }
+        };
+
+        let name = string_property(cx, obj, "name")?;
+        let message = string_property(cx, obj, "message")?.unwrap_or_default();
+        let stack = string_property(cx, obj, "stack")?;
+        let code = string_property(cx, obj, "code")?;
+        let cause = obj.get(cx, "cause")?;
+        let cause = if depth == 0 || cause.is_a::<JsUndefined, _>(cx) {
+            None
+        } else {
+            Some(Box::new(ErrorInfo::capture(cx, cause, depth - 1)?))
+        };
+
+        Ok(ErrorInfo {
+            name,
+            message,
+            stack,
+            code,
+            cause,
+        })
+    }
+
+    /// The `name` of the error, e.g. `"TypeError"`.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// The `message` of the error.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The `stack` trace of the error, if one was captured.
+    pub fn stack(&self) -> Option<&str> {
+        self.stack.as_deref()
+    }
+
+    /// The `code` of the error, e.g. `"ENOENT"`.
+    pub fn code(&self) -> Option<&str> {
+        self.code.as_deref()
+    }
+
+    /// The `cause` of the error.
+    pub fn cause(&self) -> Option<&ErrorInfo> {
+        self.cause.as_deref()
+    }
+
+    /// Creates a JavaScript error equivalent to the captured one, including its
+    /// `stack` and `cause`.
+    ///
+    /// Errors named `TypeError`, `RangeError` or `SyntaxError` are recreated as instances
+    /// of that class. Any other error is recreated as an `Error` with the original `name`.
+    pub fn to_error<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<Handle<'a, JsError>> {
+        let kind = match self.name() {
+            Some("TypeError") => ErrorKind::TypeError,
+            Some("RangeError") => ErrorKind::RangeError,
+            Some("SyntaxError") => ErrorKind::SyntaxError,
+            _ => ErrorKind::Error,
+        };
+        let code = self.code.as_ref().map(|code| cx.string(code));
+        let msg = cx.string(&self.message);
+        let err = JsError::new_kind(cx, kind, code, msg)?;
+
+        if let Some(name) = &self.name {
+            if kind == ErrorKind::Error && name != "Error" {
+                let name = cx.string(name);
+                err.set(cx, "name", name)?;
+            }
+        }
+
+        if let Some(stack) = &self.stack {
+            let stack = cx.string(stack);
+            err.set(cx, "stack", stack)?;
+        }
+
+        if let Some(cause) = &self.cause {
+            let cause = cause.to_error(cx)?;
+            err.set(cx, "cause", cause)?;
+        }
+
+        Ok(err)
+    }
+
+    /// Throws a JavaScript error equivalent to the captured one.
+    /// See [`ErrorInfo::to_error`](ErrorInfo::to_error).
+    pub fn throw<'a, C: Context<'a>, T>(&self, cx: &mut C) -> NeonResult<T> {
+        let err = self.to_error(cx)?;
+        cx.throw(err)
+    }
+}
+
+#[cfg(feature = "napi-1")]
+fn string_property<'a, C: Context<'a>>(
+    cx: &mut C,
+    obj: Handle<JsObject>,
+    key: &str,
+) -> NeonResult<Option<String>> {
+    let value = obj.get(cx, key)?;
+
+    Ok(value
+        .downcast::<JsString, _>(cx)
+        .ok()
+        .map(|value| value.value(cx)))
+}
+
+#[cfg(feature = "napi-1")]
+impl fmt::Display for ErrorInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Matches `Error.prototype.toString`
+        match &self.name {
+            Some(name) if self.message.is_empty() => f.write_str(name),
+            Some(name) => write!(f, "{}: {}", name, self.message),
+            None => f.write_str(&self.message),
+        }
+    }
+}
+
+#[cfg(feature = "napi-1")]
+impl Error for ErrorInfo {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.cause
+            .as_deref()
+            .map(|cause| cause as &(dyn Error + 'static))
+    }
+}
+
+/// Describes a caught panic as the message of the JavaScript `Error` that replaces it.
+pub(crate) fn panic_message(panic: &(dyn Any + Send)) -> String {
+    if let Some(string) = panic.downcast_ref::<String>() {
+        format!("internal error in Neon module: {}", string)
+    } else if let Some(str) = panic.downcast_ref::<&str>() {
+        format!("internal error in Neon module: {}", str)
+    } else {
+        "internal error in Neon module".to_string()
+    }
 }
 
 pub(crate) fn convert_panics<T, F: UnwindSafe + FnOnce() -> NeonResult<T>>(
@@ -86,13 +419,7 @@ pub(crate) fn convert_panics<T, F: UnwindSafe + FnOnce() -> NeonResult<T>>(
     match catch_unwind(|| f()) {
         Ok(result) => result,
         Err(panic) => {
-            let msg = if let Some(string) = panic.downcast_ref::<String>() {
-                format!("internal error in Neon module: {}", string)
-            } else if let Some(str) = panic.downcast_ref::<&str>() {
-                format!("internal error in Neon module: {}", str)
-            } else {
-                "internal error in Neon module".to_string()
-            };
+            let msg = panic_message(&*panic);
             let (data, len) = Utf8::from(&msg[..]).truncate().lower();
             unsafe {
                 #[cfg(feature = "napi-1")]
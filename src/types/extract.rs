@@ -0,0 +1,553 @@
+//! Traits for converting between Rust values and JavaScript values.
+//!
+//! [`TryFromJs`] converts a JavaScript value into a Rust value, checking its type, and
+//! [`TryIntoJs`] converts a Rust value into a JavaScript value. They are implemented for
+//! Rust primitives, `String`, `Option<T>`, `Vec<T>`, tuples, `HashMap<String, T>`,
+//! byte buffers and handles, including handles to a [`JsBox`](crate::types::JsBox),
+//! which dereference to the boxed Rust value.
+//!
+//! [`CallContext::arg`](crate::context::CallContext::arg) uses them to extract the
+//! arguments of a function:
+//!
+//! ```
+//! # use neon::prelude::*;
+//! fn greet(mut cx: FunctionContext) -> JsResult<JsString> {
+//!     let name: String = cx.arg(0)?;
+//!     let times: Option<u32> = cx.arg(1)?;
+//!     let greeting = format!("Hello, {}!", name).repeat(times.unwrap_or(1) as usize);
+//!
+//!     greeting.try_into_js(&mut cx)
+//! }
+//! ```
+//!
+//! Functions exported with `#[neon::export]` may also take and return Rust values
+//! directly:
+//!
+//! ```
+//! # use neon::prelude::*;
+//! #[neon::export]
+//! fn add(a: f64, b: f64) -> f64 {
+//!     a + b
+//! }
+//! ```
+//!
+//! ## Type errors
+//!
+//! A value of the wrong type is reported as a [`ConversionError`]. When extracting an
+//! argument, it is thrown as a `TypeError` that names the argument, e.g.,
+//! `argument 1[2]: expected a string, found number` for the third element of the
+//! second argument.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::BuildHasher;
+
+use crate::context::Context;
+use crate::handle::Handle;
+use crate::object::Object;
+use crate::result::{JsResult, NeonResult, Throw};
+#[cfg(feature = "napi-6")]
+use crate::types::JsBigInt;
+use crate::types::{
+    JsArray, JsArrayBuffer, JsBoolean, JsBuffer, JsFunction, JsNull, JsNumber, JsObject, JsString,
+    JsSymbol, JsTypedArray, JsUndefined, JsValue, Value,
+};
+
+/// The error of a failed conversion of a JavaScript value with [`TryFromJs`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConversionError {
+    expected: String,
+    found: &'static str,
+    path: String,
+}
+
+impl ConversionError {
+    /// Creates an error for `value`, which is not the `expected` kind of value, e.g.,
+    /// `"a string"`.
+    pub fn new<'a, C: Context<'a>, S: Into<String>>(
+        cx: &mut C,
+        expected: S,
+        value: Handle<'a, JsValue>,
+    ) -> Self {
+        ConversionError {
+            expected: expected.into(),
+            found: type_name(cx, value),
+            path: String::new(),
+        }
+    }
+
+    /// Describes the value that was expected.
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+
+    /// The type of the value that was found, as reported by `typeof`, except for
+    /// `"null"` and `"array"`.
+    pub fn found(&self) -> &str {
+        self.found
+    }
+
+    /// The location of the value within the value being converted, e.g., `[2].name` for
+    /// the `name` property of its third element. Empty if it is the value itself.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Marks the error as coming from the element at `index`.
+    pub fn at_index(mut self, index: u32) -> Self {
+        self.path = format!("[{}]{}", index, self.path);
+        self
+    }
+
+    /// Marks the error as coming from the property named `key`.
+    pub fn at_key(mut self, key: &str) -> Self {
+        self.path = format!(".{}{}", key, self.path);
+        self
+    }
+
+    pub(crate) fn describe(&self, subject: &str) -> String {
+        format!(
+            "{}{}: expected {}, found {}",
+            subject, self.path, self.expected, self.found
+        )
+    }
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.describe("value"))
+    }
+}
+
+impl std::error::Error for ConversionError {}
+
+pub(crate) fn type_name<'a, C: Context<'a>>(
+    cx: &mut C,
+    value: Handle<'a, JsValue>,
+) -> &'static str {
+    if value.is_a::<JsUndefined, _>(cx) {
+        "undefined"
+    } else if value.is_a::<JsNull, _>(cx) {
+        "null"
+    } else if value.is_a::<JsBoolean, _>(cx) {
+        "boolean"
+    } else if value.is_a::<JsNumber, _>(cx) {
+        "number"
+    } else if value.is_a::<JsString, _>(cx) {
+        "string"
+    } else if value.is_a::<JsSymbol, _>(cx) {
+        "symbol"
+    } else if is_bigint(cx, value) {
+        "bigint"
+    } else if value.is_a::<JsFunction, _>(cx) {
+        "function"
+    } else if value.is_a::<JsArray, _>(cx) {
+        "array"
+    } else {
+        "object"
+    }
+}
+
+#[cfg(feature = "napi-6")]
+fn is_bigint<'a, C: Context<'a>>(cx: &mut C, value: Handle<'a, JsValue>) -> bool {
+    value.is_a::<JsBigInt, _>(cx)
+}
+
+#[cfg(not(feature = "napi-6"))]
+fn is_bigint<'a, C: Context<'a>>(_: &mut C, _: Handle<'a, JsValue>) -> bool {
+    false
+}
+
+/// A Rust value that can be extracted from a JavaScript value.
+pub trait TryFromJs<'a>: Sized {
+    /// Converts `value`, returning a [`ConversionError`] if it has the wrong type.
+    /// Throws only if JavaScript code run by the conversion, e.g., a getter, throws.
+    fn try_from_js<C: Context<'a>>(
+        cx: &mut C,
+        value: Handle<'a, JsValue>,
+    ) -> NeonResult<Result<Self, ConversionError>>;
+
+    /// Converts `value`, throwing a `TypeError` if it has the wrong type.
+    fn from_js<C: Context<'a>>(cx: &mut C, value: Handle<'a, JsValue>) -> NeonResult<Self> {
+        match Self::try_from_js(cx, value)? {
+            Ok(v) => Ok(v),
+            Err(err) => cx.throw_type_error(err.to_string()),
+        }
+    }
+}
+
+/// A Rust value that can be converted into a JavaScript value.
+pub trait TryIntoJs<'a> {
+    /// The type of the JavaScript value.
+    type Value: Value;
+
+    /// Converts the value, throwing if it cannot be represented in JavaScript.
+    fn try_into_js<C: Context<'a>>(self, cx: &mut C) -> JsResult<'a, Self::Value>;
+}
+
+/// Downcasts `value` or returns a `ConversionError` from the enclosing function.
+macro_rules! downcast {
+    ($cx:expr, $value:expr, $ty:ty, $expected:expr) => {
+        match $value.downcast::<$ty, _>($cx) {
+            Ok(v) => v,
+            Err(_) => return Ok(Err(ConversionError::new($cx, $expected, $value))),
+        }
+    };
+}
+
+/// Describes a value of the type with the given `Value::name`, e.g., `"a string"`.
+fn expected_type(name: &str) -> String {
+    match name {
+        "undefined" | "null" => name.to_string(),
+        "Array" => "an array".to_string(),
+        "Buffer" => "a buffer".to_string(),
+        "ArrayBuffer" => "an array buffer".to_string(),
+        "Error" => "an error".to_string(),
+        "Promise" => "a promise".to_string(),
+        _ if name.starts_with(|c| "aeiouAEIOU".contains(c)) => format!("an {}", name),
+        _ => format!("a {}", name),
+    }
+}
+
+impl<'a, V: Value> TryFromJs<'a> for Handle<'a, V> {
+    fn try_from_js<C: Context<'a>>(
+        cx: &mut C,
+        value: Handle<'a, JsValue>,
+    ) -> NeonResult<Result<Self, ConversionError>> {
+        Ok(Ok(downcast!(cx, value, V, expected_type(&V::name()))))
+    }
+}
+
+impl<'a, V: Value> TryIntoJs<'a> for Handle<'a, V> {
+    type Value = V;
+
+    fn try_into_js<C: Context<'a>>(self, _: &mut C) -> JsResult<'a, V> {
+        Ok(self)
+    }
+}
+
+impl<'a, T: TryIntoJs<'a>> TryIntoJs<'a> for Result<T, Throw> {
+    type Value = T::Value;
+
+    fn try_into_js<C: Context<'a>>(self, cx: &mut C) -> JsResult<'a, T::Value> {
+        self?.try_into_js(cx)
+    }
+}
+
+impl<'a> TryIntoJs<'a> for () {
+    type Value = JsUndefined;
+
+    fn try_into_js<C: Context<'a>>(self, cx: &mut C) -> JsResult<'a, JsUndefined> {
+        Ok(cx.undefined())
+    }
+}
+
+impl<'a> TryFromJs<'a> for bool {
+    fn try_from_js<C: Context<'a>>(
+        cx: &mut C,
+        value: Handle<'a, JsValue>,
+    ) -> NeonResult<Result<Self, ConversionError>> {
+        Ok(Ok(downcast!(cx, value, JsBoolean, "a boolean").value(cx)))
+    }
+}
+
+impl<'a> TryIntoJs<'a> for bool {
+    type Value = JsBoolean;
+
+    fn try_into_js<C: Context<'a>>(self, cx: &mut C) -> JsResult<'a, JsBoolean> {
+        Ok(cx.boolean(self))
+    }
+}
+
+impl<'a> TryFromJs<'a> for String {
+    fn try_from_js<C: Context<'a>>(
+        cx: &mut C,
+        value: Handle<'a, JsValue>,
+    ) -> NeonResult<Result<Self, ConversionError>> {
+        Ok(Ok(downcast!(cx, value, JsString, "a string").value(cx)))
+    }
+}
+
+impl<'a> TryIntoJs<'a> for String {
+    type Value = JsString;
+
+    fn try_into_js<C: Context<'a>>(self, cx: &mut C) -> JsResult<'a, JsString> {
+        Ok(cx.string(self))
+    }
+}
+
+impl<'a, 'b> TryIntoJs<'a> for &'b str {
+    type Value = JsString;
+
+    fn try_into_js<C: Context<'a>>(self, cx: &mut C) -> JsResult<'a, JsString> {
+        Ok(cx.string(self))
+    }
+}
+
+macro_rules! impl_float {
+    ($($ty:ty),*) => {$(
+        impl<'a> TryFromJs<'a> for $ty {
+            fn try_from_js<C: Context<'a>>(
+                cx: &mut C,
+                value: Handle<'a, JsValue>,
+            ) -> NeonResult<Result<Self, ConversionError>> {
+                Ok(Ok(downcast!(cx, value, JsNumber, "a number").value(cx) as $ty))
+            }
+        }
+
+        impl<'a> TryIntoJs<'a> for $ty {
+            type Value = JsNumber;
+
+            fn try_into_js<C: Context<'a>>(self, cx: &mut C) -> JsResult<'a, JsNumber> {
+                Ok(cx.number(self))
+            }
+        }
+    )*};
+}
+
+impl_float!(f32, f64);
+
+// Integers are converted to and from numbers exactly. 64-bit integers outside of
+// `Number.MIN_SAFE_INTEGER..=Number.MAX_SAFE_INTEGER` are rounded to the nearest
+// number when converted to JavaScript.
+macro_rules! impl_integer {
+    ($($ty:ident),*) => {$(
+        impl<'a> TryFromJs<'a> for $ty {
+            fn try_from_js<C: Context<'a>>(
+                cx: &mut C,
+                value: Handle<'a, JsValue>,
+            ) -> NeonResult<Result<Self, ConversionError>> {
+                let expected = concat!("an integer in the range of ", stringify!($ty));
+                let n = downcast!(cx, value, JsNumber, expected).value(cx);
+
+                // `MAX + 1` is exact for every integer type, unlike `MAX`
+                if n.trunc() != n || n < $ty::MIN as f64 || n >= $ty::MAX as f64 + 1.0 {
+                    return Ok(Err(ConversionError::new(cx, expected, value)));
+                }
+
+                Ok(Ok(n as $ty))
+            }
+        }
+
+        impl<'a> TryIntoJs<'a> for $ty {
+            type Value = JsNumber;
+
+            fn try_into_js<C: Context<'a>>(self, cx: &mut C) -> JsResult<'a, JsNumber> {
+                Ok(cx.number(self as f64))
+            }
+        }
+    )*};
+}
+
+impl_integer!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+/// `undefined` and `null` are converted to `None`, and `None` is converted to `null`.
+impl<'a, T: TryFromJs<'a>> TryFromJs<'a> for Option<T> {
+    fn try_from_js<C: Context<'a>>(
+        cx: &mut C,
+        value: Handle<'a, JsValue>,
+    ) -> NeonResult<Result<Self, ConversionError>> {
+        if value.is_a::<JsUndefined, _>(cx) || value.is_a::<JsNull, _>(cx) {
+            return Ok(Ok(None));
+        }
+
+        Ok(T::try_from_js(cx, value)?.map(Some))
+    }
+}
+
+impl<'a, T: TryIntoJs<'a>> TryIntoJs<'a> for Option<T> {
+    type Value = JsValue;
+
+    fn try_into_js<C: Context<'a>>(self, cx: &mut C) -> JsResult<'a, JsValue> {
+        match self {
+            Some(v) => Ok(v.try_into_js(cx)?.upcast()),
+            None => Ok(cx.null().upcast()),
+        }
+    }
+}
+
+impl<'a, T: TryFromJs<'a>> TryFromJs<'a> for Vec<T> {
+    fn try_from_js<C: Context<'a>>(
+        cx: &mut C,
+        value: Handle<'a, JsValue>,
+    ) -> NeonResult<Result<Self, ConversionError>> {
+        let array = downcast!(cx, value, JsArray, "an array");
+        let len = array.len(cx);
+        let mut result = Vec::with_capacity(len as usize);
+
+        for i in 0..len {
+            let element = array.get(cx, i)?;
+
+            match T::try_from_js(cx, element)? {
+                Ok(element) => result.push(element),
+                Err(err) => return Ok(Err(err.at_index(i))),
+            }
+        }
+
+        Ok(Ok(result))
+    }
+}
+
+impl<'a, T: TryIntoJs<'a>> TryIntoJs<'a> for Vec<T> {
+    type Value = JsArray;
+
+    fn try_into_js<C: Context<'a>>(self, cx: &mut C) -> JsResult<'a, JsArray> {
+        let array = JsArray::new(cx, self.len() as u32);
+
+        for (i, element) in self.into_iter().enumerate() {
+            let element = element.try_into_js(cx)?;
+
+            array.set(cx, i as u32, element)?;
+        }
+
+        Ok(array)
+    }
+}
+
+// Tuples are represented as arrays. Extra elements are ignored.
+macro_rules! impl_tuple {
+    ($(($($ty:ident $index:tt),+))*) => {$(
+        impl<'a, $($ty: TryFromJs<'a>),+> TryFromJs<'a> for ($($ty,)+) {
+            fn try_from_js<C: Context<'a>>(
+                cx: &mut C,
+                value: Handle<'a, JsValue>,
+            ) -> NeonResult<Result<Self, ConversionError>> {
+                let array = downcast!(cx, value, JsArray, "an array");
+
+                Ok(Ok(($(
+                    {
+                        let element = array.get(cx, $index)?;
+
+                        match $ty::try_from_js(cx, element)? {
+                            Ok(element) => element,
+                            Err(err) => return Ok(Err(err.at_index($index))),
+                        }
+                    },
+                )+)))
+            }
+        }
+
+        impl<'a, $($ty: TryIntoJs<'a>),+> TryIntoJs<'a> for ($($ty,)+) {
+            type Value = JsArray;
+
+            fn try_into_js<C: Context<'a>>(self, cx: &mut C) -> JsResult<'a, JsArray> {
+                let array = JsArray::new(cx, 0);
+
+                $(
+                    let element = self.$index.try_into_js(cx)?;
+                    array.set(cx, $index, element)?;
+                )+
+
+                Ok(array)
+            }
+        }
+    )*};
+}
+
+impl_tuple! {
+    (A 0)
+    (A 0, B 1)
+    (A 0, B 1, D 2)
+    (A 0, B 1, D 2, E 3)
+    (A 0, B 1, D 2, E 3, F 4)
+    (A 0, B 1, D 2, E 3, F 4, G 5)
+    (A 0, B 1, D 2, E 3, F 4, G 5, H 6)
+    (A 0, B 1, D 2, E 3, F 4, G 5, H 6, I 7)
+}
+
+/// The own enumerable string-keyed property names of `object`.
+#[cfg(feature = "napi-6")]
+pub(crate) fn own_keys<'a, C: Context<'a>>(
+    cx: &mut C,
+    object: Handle<'a, JsObject>,
+) -> JsResult<'a, JsArray> {
+    object.get_own_property_names(cx)
+}
+
+/// The own enumerable string-keyed property names of `object`, from `Object.keys`.
+#[cfg(not(feature = "napi-6"))]
+pub(crate) fn own_keys<'a, C: Context<'a>>(
+    cx: &mut C,
+    object: Handle<'a, JsObject>,
+) -> JsResult<'a, JsArray> {
+    let constructor = cx
+        .global()
+        .get(cx, "Object")?
+        .downcast_or_throw::<JsFunction, _>(cx)?;
+    let keys = constructor
+        .get(cx, "keys")?
+        .downcast_or_throw::<JsFunction, _>(cx)?;
+
+    keys.call(cx, constructor, vec![object])?
+        .downcast_or_throw(cx)
+}
+
+/// Objects are converted to maps of their own enumerable string-keyed properties.
+impl<'a, T: TryFromJs<'a>, S: BuildHasher + Default> TryFromJs<'a> for HashMap<String, T, S> {
+    fn try_from_js<C: Context<'a>>(
+        cx: &mut C,
+        value: Handle<'a, JsValue>,
+    ) -> NeonResult<Result<Self, ConversionError>> {
+        let object = downcast!(cx, value, JsObject, "an object");
+        let keys = own_keys(cx, object)?.to_vec(cx)?;
+        let mut result = HashMap::with_capacity_and_hasher(keys.len(), S::default());
+
+        for key in keys {
+            let key = key.downcast_or_throw::<JsString, _>(cx)?;
+            let element = object.get(cx, key)?;
+            let key = key.value(cx);
+
+            match T::try_from_js(cx, element)? {
+                Ok(element) => result.insert(key, element),
+                Err(err) => return Ok(Err(err.at_key(&key))),
+            };
+        }
+
+        Ok(Ok(result))
+    }
+}
+
+impl<'a, T: TryIntoJs<'a>, S> TryIntoJs<'a> for HashMap<String, T, S> {
+    type Value = JsObject;
+
+    fn try_into_js<C: Context<'a>>(self, cx: &mut C) -> JsResult<'a, JsObject> {
+        let object = cx.empty_object();
+
+        for (key, element) in self {
+            let element = element.try_into_js(cx)?;
+
+            object.set(cx, key.as_str(), element)?;
+        }
+
+        Ok(object)
+    }
+}
+
+/// Bytes are copied out of a `Buffer`, an `ArrayBuffer` or a `Uint8Array`, and into a
+/// new `Buffer`.
+impl<'a> TryFromJs<'a> for Box<[u8]> {
+    fn try_from_js<C: Context<'a>>(
+        cx: &mut C,
+        value: Handle<'a, JsValue>,
+    ) -> NeonResult<Result<Self, ConversionError>> {
+        let bytes = if let Ok(buffer) = value.downcast::<JsBuffer, _>(cx) {
+            cx.borrow(&buffer, |data| data.as_slice::<u8>().into())
+        } else if let Ok(buffer) = value.downcast::<JsArrayBuffer, _>(cx) {
+            cx.borrow(&buffer, |data| data.as_slice::<u8>().into())
+        } else {
+            let array = downcast!(cx, value, JsTypedArray<u8>, "a buffer");
+
+            cx.borrow(&array, |data| data.as_slice::<u8>().into())
+        };
+
+        Ok(Ok(bytes))
+    }
+}
+
+impl<'a> TryIntoJs<'a> for Box<[u8]> {
+    type Value = JsBuffer;
+
+    fn try_into_js<C: Context<'a>>(self, cx: &mut C) -> JsResult<'a, JsBuffer> {
+        Ok(JsBuffer::external(cx, self))
+    }
+}
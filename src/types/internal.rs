@@ -7,8 +7,12 @@ use crate::types::{Handle, JsObject, Managed};
 use neon_runtime;
 use neon_runtime::call::CCallback;
 use neon_runtime::raw;
+#[cfg(feature = "napi-1")]
+use std::marker::PhantomData;
 use std::mem;
 use std::os::raw::c_void;
+#[cfg(feature = "napi-1")]
+use std::panic::AssertUnwindSafe;
 
 pub trait ValueInternal: Managed + 'static {
     fn name() -> String;
@@ -79,6 +83,46 @@ impl<T: Value> Callback<raw::Local> for FunctionCallback<T> {
     }
 }
 
+/// A boxed Rust closure exported as a JavaScript function. The closure is owned by
+/// the function and dropped by `drop_closure` when the function is garbage collected.
+#[cfg(feature = "napi-1")]
+pub struct ClosureCallback<F, T>(pub F, pub PhantomData<T>);
+
+#[cfg(feature = "napi-1")]
+impl<F, T> Callback<raw::Local> for ClosureCallback<F, T>
+where
+    F: Fn(FunctionContext) -> JsResult<T> + 'static,
+    T: Value,
+{
+    extern "C" fn invoke(env: Env, info: CallbackInfo<'_>) -> raw::Local {
+        unsafe {
+            info.with_cx::<JsObject, _, _>(env, |cx| {
+                let data = info.data(env);
+                let callback =
+                    &*(neon_runtime::fun::get_dynamic_callback(env.to_raw(), data) as *const F);
+
+                // Like a `fn`, the closure may be called again after a panic. Interior
+                // mutability in the captured state is the responsibility of the closure.
+                if let Ok(value) = convert_panics(env, AssertUnwindSafe(|| callback(cx))) {
+                    value.to_raw()
+                } else {
+                    std::ptr::null_mut()
+                }
+            })
+        }
+    }
+
+    fn into_ptr(self) -> *mut c_void {
+        Box::into_raw(Box::new(self.0)) as *mut _
+    }
+}
+
+/// Finalizer for the data of a function created from a `ClosureCallback<F, _>`.
+#[cfg(feature = "napi-1")]
+pub unsafe extern "C" fn drop_closure<F>(_env: raw::Env, data: *mut c_void, _hint: *mut c_void) {
+    drop(Box::from_raw(data as *mut F));
+}
+
 /// A dynamically computed callback that can be passed through C to the engine.
 /// This type makes it possible to export a dynamically computed Rust function
 /// as a pair of 1) a raw pointer to the dynamically computed function, and 2)
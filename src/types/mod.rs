@@ -61,27 +61,40 @@
 //!   types all implement the [`Object`](crate::object::Object) trait, which allows
 //!   getting and setting properties.
 //!   - **Standard object types:** [`JsFunction`](JsFunction), [`JsArray`](JsArray),
-//!     [`JsDate`](JsDate), and [`JsError`](JsError).
-//!   - **Typed arrays:** [`JsBuffer`](JsBuffer) and [`JsArrayBuffer`](JsArrayBuffer).
+//!     [`JsDate`](JsDate), [`JsError`](JsError), and [`JsPromise`](JsPromise).
+//!   - **Typed arrays:** [`JsBuffer`](JsBuffer), [`JsArrayBuffer`](JsArrayBuffer),
+//!     [`JsTypedArray`](JsTypedArray), and [`JsDataView`](JsDataView).
 //!   - **Custom types:** [`JsBox`](JsBox), a special Neon type that allows the creation
 //!     of custom objects that own Rust data structures.
 //! - **Primitive types:** These are the built-in JavaScript datatypes that are not
-//!   object types: [`JsNumber`](JsNumber), [`JsBoolean`](JsBoolean),
-//!   [`JsString`](JsString), [`JsNull`](JsNull), and [`JsUndefined`](JsUndefined).
+//!   object types: [`JsNumber`](JsNumber), [`JsBigInt`](JsBigInt), [`JsBoolean`](JsBoolean),
+//!   [`JsString`](JsString), [`JsSymbol`](JsSymbol), [`JsNull`](JsNull), and
+//!   [`JsUndefined`](JsUndefined).
 //!
 //! [types]: https://raw.githubusercontent.com/neon-bindings/neon/main/doc/types.jpg
 //! [unknown]: https://mariusschulz.com/blog/the-unknown-type-in-typescript#the-unknown-type
 
+#[cfg(feature = "napi-6")]
+pub(crate) mod bigint;
 pub(crate) mod binary;
 #[cfg(feature = "napi-1")]
 pub(crate) mod boxed;
 #[cfg(feature = "napi-5")]
 pub(crate) mod date;
 pub(crate) mod error;
+#[cfg(feature = "napi-1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-1")))]
+pub mod extract;
+#[cfg(feature = "napi-1")]
+pub(crate) mod promise;
+#[cfg(feature = "napi-1")]
+pub(crate) mod symbol;
 
 pub(crate) mod internal;
 pub(crate) mod utf8;
 
+#[cfg(feature = "napi-1")]
+use self::internal::{drop_closure, ClosureCallback};
 use self::internal::{FunctionCallback, ValueInternal};
 use self::utf8::Utf8;
 use crate::context::internal::Env;
@@ -99,12 +112,22 @@ use std::fmt::Debug;
 use std::marker::PhantomData;
 use std::os::raw::c_void;
 
+#[cfg(feature = "napi-6")]
+pub use self::bigint::{BigIntError, BigIntErrorKind, JsBigInt, Sign};
 pub use self::binary::{BinaryData, BinaryViewType, JsArrayBuffer, JsBuffer};
 #[cfg(feature = "napi-1")]
+pub use self::binary::{JsDataView, JsTypedArray, TypedArrayElement};
+#[cfg(feature = "napi-1")]
 pub use self::boxed::JsBox;
 #[cfg(feature = "napi-5")]
 pub use self::date::{DateError, DateErrorKind, JsDate};
 pub use self::error::JsError;
+#[cfg(feature = "napi-1")]
+pub use self::error::{ErrorInfo, ErrorKind};
+#[cfg(feature = "napi-1")]
+pub use self::promise::{Deferred, JsPromise};
+#[cfg(feature = "napi-1")]
+pub use self::symbol::JsSymbol;
 
 pub(crate) fn build<'a, T: Managed, F: FnOnce(&mut raw::Local) -> bool>(
     env: Env,
@@ -446,10 +469,246 @@ impl JsString {
         }
     }
 
+    #[cfg(feature = "napi-1")]
+    /// Copies the UTF-8 contents of the string into `buf`, returning the number of
+    /// bytes written.
+    ///
+    /// N-API always writes a null terminator after the copied bytes, so at most
+    /// `buf.len() - 1` bytes are written. Only complete characters are copied, so
+    /// `&buf[..n]` is always valid UTF-8. If the result is less than
+    /// [`JsString::size`](JsString::size), the string was truncated.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn first_byte(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     let s = cx.argument::<JsString>(0)?;
+    ///     let mut buf = [0u8; 16];
+    ///     let n = s.write_utf8(&mut cx, &mut buf);
+    ///
+    ///     if (n as isize) < s.size(&mut cx) {
+    ///         return cx.throw_range_error("string is too long");
+    ///     }
+    ///
+    ///     Ok(cx.number(buf.first().copied().unwrap_or(0)))
+    /// }
+    /// ```
+    pub fn write_utf8<'a, C: Context<'a>>(self, cx: &mut C, buf: &mut [u8]) -> usize {
+        let env = cx.env().to_raw();
+
+        unsafe {
+            neon_runtime::string::data(env, buf.as_mut_ptr(), buf.len() as isize, self.to_raw())
+                as usize
+        }
+    }
+
+    #[cfg(feature = "napi-1")]
+    /// Reads the contents of the string into `buf`, replacing its previous contents.
+    ///
+    /// The existing capacity of `buf` is reused, so reading many strings into the same
+    /// buffer only allocates when a string is longer than any read before it.
+    pub fn value_into<'a, C: Context<'a>>(self, cx: &mut C, buf: &mut String) {
+        let env = cx.env().to_raw();
+
+        unsafe {
+            // Only complete UTF-8 characters are written, so the buffer remains valid
+            let bytes = buf.as_mut_vec();
+
+            bytes.clear();
+
+            let len = match self.read_utf8(env, bytes.as_mut_ptr(), bytes.capacity()) {
+                Some(len) => len,
+                None => {
+                    let capacity = neon_runtime::string::utf8_len(env, self.to_raw()) as usize + 1;
+
+                    bytes.reserve_exact(capacity);
+                    self.read_utf8(env, bytes.as_mut_ptr(), bytes.capacity())
+                        .expect("Buffer should fit the entire string")
+                }
+            };
+
+            bytes.set_len(len);
+        }
+    }
+
+    #[cfg(feature = "napi-1")]
+    /// Returns the UTF-8 contents of the string in a [`SmallVec`](smallvec::SmallVec).
+    ///
+    /// Strings that fit in the inline capacity of `A` (less a byte for the null
+    /// terminator written by N-API) are read without allocating. Longer strings
+    /// fall back to a heap allocation. The returned bytes are always valid UTF-8.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn is_keyword(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    ///     let key = cx.argument::<JsString>(0)?.value_small::<_, [u8; 32]>(&mut cx);
+    ///     let key = std::str::from_utf8(&key).unwrap();
+    ///
+    ///     Ok(cx.boolean(matches!(key, "if" | "else" | "while")))
+    /// }
+    /// ```
+    pub fn value_small<'a, C: Context<'a>, A: smallvec::Array<Item = u8>>(
+        self,
+        cx: &mut C,
+    ) -> SmallVec<A> {
+        let env = cx.env().to_raw();
+        let mut buf = SmallVec::<A>::new();
+
+        unsafe {
+            let len = match self.read_utf8(env, buf.as_mut_ptr(), buf.capacity()) {
+                Some(len) => len,
+                None => {
+                    let capacity = neon_runtime::string::utf8_len(env, self.to_raw()) as usize + 1;
+
+                    buf.reserve_exact(capacity);
+                    self.read_utf8(env, buf.as_mut_ptr(), buf.capacity())
+                        .expect("Buffer should fit the entire string")
+                }
+            };
+
+            buf.set_len(len);
+        }
+
+        buf
+    }
+
+    #[cfg(feature = "napi-1")]
+    /// Reads the string into `capacity` bytes at `out`, returning `None` if the
+    /// buffer may have been too small to hold the entire string.
+    unsafe fn read_utf8(self, env: raw::Env, out: *mut u8, capacity: usize) -> Option<usize> {
+        if capacity == 0 {
+            return None;
+        }
+
+        let len = neon_runtime::string::data(env, out, capacity as isize, self.to_raw()) as usize;
+
+        // N-API reserves a byte for the null terminator and only writes complete
+        // characters, so a truncated read leaves at most 4 bytes of the buffer unused.
+        // Checking this first avoids measuring the string on the fast path.
+        if len + 4 < capacity || len == neon_runtime::string::utf8_len(env, self.to_raw()) as usize
+        {
+            Some(len)
+        } else {
+            None
+        }
+    }
+
+    #[cfg(feature = "napi-1")]
+    /// Returns the length of the string in UTF-16 code units.
+    ///
+    /// This is also the length of the string in Latin-1 bytes.
+    pub fn size_utf16<'a, C: Context<'a>>(self, cx: &mut C) -> usize {
+        let env = cx.env().to_raw();
+
+        unsafe { neon_runtime::string::utf16_len(env, self.to_raw()) }
+    }
+
+    #[cfg(feature = "napi-1")]
+    /// Returns the contents of the string as UTF-16 code units.
+    ///
+    /// Unlike [`JsString::value`](JsString::value), this conversion is exact: lone
+    /// surrogates are preserved rather than replaced.
+    pub fn value_utf16<'a, C: Context<'a>>(self, cx: &mut C) -> Vec<u16> {
+        let env = cx.env().to_raw();
+
+        unsafe {
+            // Leave room for the null terminator written by N-API
+            let capacity = neon_runtime::string::utf16_len(env, self.to_raw()) + 1;
+            let mut buffer: Vec<u16> = Vec::with_capacity(capacity);
+            let len =
+                neon_runtime::string::data_utf16(env, buffer.as_mut_ptr(), capacity, self.to_raw());
+
+            buffer.set_len(len);
+            buffer
+        }
+    }
+
+    #[cfg(feature = "napi-1")]
+    /// Copies the UTF-16 code units of the string into `buf`, returning the number of
+    /// code units written.
+    ///
+    /// N-API always writes a null terminator after the copied code units, so at most
+    /// `buf.len() - 1` code units of the string are written. If the result is less than
+    /// [`JsString::size_utf16`](JsString::size_utf16), the string was truncated.
+    pub fn write_utf16<'a, C: Context<'a>>(self, cx: &mut C, buf: &mut [u16]) -> usize {
+        let env = cx.env().to_raw();
+
+        unsafe { neon_runtime::string::data_utf16(env, buf.as_mut_ptr(), buf.len(), self.to_raw()) }
+    }
+
+    #[cfg(feature = "napi-1")]
+    /// Returns the contents of the string as Latin-1 (ISO-8859-1) bytes.
+    ///
+    /// Each UTF-16 code unit is converted to a single byte. Code units outside of the
+    /// Latin-1 range are truncated to their low byte, so the conversion is lossy for
+    /// strings that are not entirely Latin-1.
+    pub fn value_latin1<'a, C: Context<'a>>(self, cx: &mut C) -> Vec<u8> {
+        let env = cx.env().to_raw();
+
+        unsafe {
+            // Leave room for the null terminator written by N-API
+            let capacity = neon_runtime::string::utf16_len(env, self.to_raw()) + 1;
+            let mut buffer: Vec<u8> = Vec::with_capacity(capacity);
+            let len = neon_runtime::string::data_latin1(
+                env,
+                buffer.as_mut_ptr(),
+                capacity,
+                self.to_raw(),
+            );
+
+            buffer.set_len(len);
+            buffer
+        }
+    }
+
+    #[cfg(feature = "napi-1")]
+    /// Copies the Latin-1 bytes of the string into `buf`, returning the number of bytes
+    /// written.
+    ///
+    /// As with [`JsString::write_utf16`](JsString::write_utf16), at most `buf.len() - 1`
+    /// bytes are written, and the conversion is lossy for strings that are not
+    /// entirely Latin-1.
+    pub fn write_latin1<'a, C: Context<'a>>(self, cx: &mut C, buf: &mut [u8]) -> usize {
+        let env = cx.env().to_raw();
+
+        unsafe {
+            neon_runtime::string::data_latin1(env, buf.as_mut_ptr(), buf.len(), self.to_raw())
+        }
+    }
+
     pub fn new<'a, C: Context<'a>, S: AsRef<str>>(cx: &mut C, val: S) -> Handle<'a, JsString> {
         JsString::try_new(cx, val).unwrap()
     }
 
+    #[cfg(feature = "napi-1")]
+    /// Creates a string from UTF-16 code units. Lone surrogates are preserved.
+    pub fn from_utf16<'a, C: Context<'a>>(cx: &mut C, val: &[u16]) -> StringResult<'a> {
+        let env = cx.env().to_raw();
+
+        unsafe {
+            let mut local: raw::Local = std::mem::zeroed();
+            if neon_runtime::string::new_utf16(&mut local, env, val.as_ptr(), val.len()) {
+                Ok(Handle::new_internal(JsString(local)))
+            } else {
+                Err(StringOverflow(val.len()))
+            }
+        }
+    }
+
+    #[cfg(feature = "napi-1")]
+    /// Creates a string from Latin-1 (ISO-8859-1) bytes.
+    pub fn from_latin1<'a, C: Context<'a>>(cx: &mut C, val: &[u8]) -> StringResult<'a> {
+        let env = cx.env().to_raw();
+
+        unsafe {
+            let mut local: raw::Local = std::mem::zeroed();
+            if neon_runtime::string::new_latin1(&mut local, env, val.as_ptr(), val.len()) {
+                Ok(Handle::new_internal(JsString(local)))
+            } else {
+                Err(StringOverflow(val.len()))
+            }
+        }
+    }
+
     pub fn try_new<'a, C: Context<'a>, S: AsRef<str>>(cx: &mut C, val: S) -> StringResult<'a> {
         let val = val.as_ref();
         match JsString::new_internal(cx.env(), val) {
@@ -504,6 +763,149 @@ impl JsNumber {
         let env = cx.env().to_raw();
         unsafe { neon_runtime::primitive::number_value(env, self.to_raw()) }
     }
+
+    #[cfg(feature = "napi-1")]
+    /// Creates a number from an `i32`.
+    pub fn from_i32<'a, C: Context<'a>>(cx: &mut C, x: i32) -> Handle<'a, JsNumber> {
+        unsafe {
+            let mut local: raw::Local = std::mem::zeroed();
+            neon_runtime::primitive::number_i32(&mut local, cx.env().to_raw(), x);
+            Handle::new_internal(JsNumber(local))
+        }
+    }
+
+    #[cfg(feature = "napi-1")]
+    /// Creates a number from a `u32`.
+    pub fn from_u32<'a, C: Context<'a>>(cx: &mut C, x: u32) -> Handle<'a, JsNumber> {
+        unsafe {
+            let mut local: raw::Local = std::mem::zeroed();
+            neon_runtime::primitive::number_u32(&mut local, cx.env().to_raw(), x);
+            Handle::new_internal(JsNumber(local))
+        }
+    }
+
+    #[cfg(feature = "napi-1")]
+    /// Creates a number from an `i64`.
+    ///
+    /// Values outside of the range `Number.MIN_SAFE_INTEGER..=Number.MAX_SAFE_INTEGER`
+    /// are rounded to the nearest representable number. Use
+    /// [`JsBigInt`](crate::types::JsBigInt) to represent them exactly.
+    pub fn from_i64<'a, C: Context<'a>>(cx: &mut C, x: i64) -> Handle<'a, JsNumber> {
+        unsafe {
+            let mut local: raw::Local = std::mem::zeroed();
+            neon_runtime::primitive::number_i64(&mut local, cx.env().to_raw(), x);
+            Handle::new_internal(JsNumber(local))
+        }
+    }
+
+    #[cfg(feature = "napi-1")]
+    /// Gets the value of the number as an `i32`. Throws a `RangeError` if the number
+    /// is not an integer in the range of an `i32`.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn repeat(mut cx: FunctionContext) -> JsResult<JsString> {
+    ///     let s = cx.argument::<JsString>(0)?.value(&mut cx);
+    ///     let n = cx.argument::<JsNumber>(1)?.value_u32(&mut cx)?;
+    ///
+    ///     Ok(cx.string(s.repeat(n as usize)))
+    /// }
+    /// ```
+    pub fn value_i32<'a, C: Context<'a>>(self, cx: &mut C) -> NeonResult<i32> {
+        let v = self.value_integer(cx, i32::MIN as f64, -(i32::MIN as f64), "i32")?;
+
+        Ok(v as i32)
+    }
+
+    #[cfg(feature = "napi-1")]
+    /// Gets the value of the number as a `u32`. Throws a `RangeError` if the number
+    /// is not an integer in the range of a `u32`.
+    pub fn value_u32<'a, C: Context<'a>>(self, cx: &mut C) -> NeonResult<u32> {
+        let v = self.value_integer(cx, 0.0, u32::MAX as f64 + 1.0, "u32")?;
+
+        Ok(v as u32)
+    }
+
+    #[cfg(feature = "napi-1")]
+    /// Gets the value of the number as an `i64`. Throws a `RangeError` if the number
+    /// is not an integer in the range of an `i64`.
+    ///
+    /// Note that integers outside of `Number.MIN_SAFE_INTEGER..=Number.MAX_SAFE_INTEGER`
+    /// may already have been rounded when the number was created.
+    pub fn value_i64<'a, C: Context<'a>>(self, cx: &mut C) -> NeonResult<i64> {
+        let v = self.value_integer(cx, i64::MIN as f64, -(i64::MIN as f64), "i64")?;
+
+        Ok(v as i64)
+    }
+
+    #[cfg(feature = "napi-1")]
+    /// Gets the value of the number, checking that it is an integer in `min..max`.
+    fn value_integer<'a, C: Context<'a>>(
+        self,
+        cx: &mut C,
+        min: f64,
+        max: f64,
+        ty: &str,
+    ) -> NeonResult<f64> {
+        let v = self.value(cx);
+
+        if v.trunc() != v {
+            return cx.throw_range_error(format!("{} is not an integer", v));
+        }
+
+        if v < min || v >= max {
+            return cx.throw_range_error(format!("{} is out of range for {}", v, ty));
+        }
+
+        Ok(v)
+    }
+
+    #[cfg(feature = "napi-1")]
+    /// Gets the value of the number as an `i32`, rounding towards zero and clamping
+    /// to `i32::MIN..=i32::MAX`. `NaN` is converted to `0`.
+    pub fn value_i32_saturating<'a, C: Context<'a>>(self, cx: &mut C) -> i32 {
+        self.value(cx) as i32
+    }
+
+    #[cfg(feature = "napi-1")]
+    /// Gets the value of the number as a `u32`, rounding towards zero and clamping
+    /// to `0..=u32::MAX`. `NaN` is converted to `0`.
+    pub fn value_u32_saturating<'a, C: Context<'a>>(self, cx: &mut C) -> u32 {
+        self.value(cx) as u32
+    }
+
+    #[cfg(feature = "napi-1")]
+    /// Gets the value of the number as an `i64`, rounding towards zero and clamping
+    /// to `i64::MIN..=i64::MAX`. `NaN` is converted to `0`.
+    pub fn value_i64_saturating<'a, C: Context<'a>>(self, cx: &mut C) -> i64 {
+        self.value(cx) as i64
+    }
+
+    #[cfg(feature = "napi-1")]
+    /// Gets the value of the number as an `i32` with the semantics of the JavaScript
+    /// [`ToInt32`](https://tc39.es/ecma262/#sec-toint32) operation, as used by `x | 0`.
+    /// Out of range values wrap around and non-finite values are converted to `0`.
+    pub fn value_i32_truncating<'a, C: Context<'a>>(self, cx: &mut C) -> i32 {
+        let env = cx.env().to_raw();
+        unsafe { neon_runtime::primitive::number_value_i32(env, self.to_raw()) }
+    }
+
+    #[cfg(feature = "napi-1")]
+    /// Gets the value of the number as a `u32` with the semantics of the JavaScript
+    /// [`ToUint32`](https://tc39.es/ecma262/#sec-touint32) operation, as used by `x >>> 0`.
+    /// Out of range values wrap around and non-finite values are converted to `0`.
+    pub fn value_u32_truncating<'a, C: Context<'a>>(self, cx: &mut C) -> u32 {
+        let env = cx.env().to_raw();
+        unsafe { neon_runtime::primitive::number_value_u32(env, self.to_raw()) }
+    }
+
+    #[cfg(feature = "napi-1")]
+    /// Gets the value of the number as an `i64`, rounding towards zero. Non-finite values
+    /// are converted to `0`; out of range values are converted by the engine.
+    pub fn value_i64_truncating<'a, C: Context<'a>>(self, cx: &mut C) -> i64 {
+        let env = cx.env().to_raw();
+        unsafe { neon_runtime::primitive::number_value_i64(env, self.to_raw()) }
+    }
 }
 
 impl Value for JsNumber {}
@@ -682,7 +1084,7 @@ impl<T: Object> Object for JsFunction<T> {}
 // Maximum number of function arguments in V8.
 const V8_ARGC_LIMIT: usize = 65535;
 
-unsafe fn prepare_call<'a, 'b, C: Context<'a>, A>(
+pub(crate) unsafe fn prepare_call<'a, 'b, C: Context<'a>, A>(
     cx: &mut C,
     args: &mut [Handle<'b, A>],
 ) -> NeonResult<(i32, *mut c_void)>
@@ -698,6 +1100,11 @@ where
 }
 
 impl JsFunction {
+    /// Creates an anonymous function.
+    ///
+    /// Use [`JsFunction::with_name`](JsFunction::with_name) to create a named function,
+    /// or [`ModuleContext::export_function`](crate::context::ModuleContext::export_function)
+    /// to export a function named after its key.
     pub fn new<'a, C, U>(
         cx: &mut C,
         f: fn(FunctionContext) -> JsResult<U>,
@@ -714,9 +1121,121 @@ impl JsFunction {
             }
         })
     }
+
+    #[cfg(feature = "napi-1")]
+    /// Creates a function with the given `name`.
+    ///
+    /// The name is reported by the `name` property of the function and identifies the
+    /// function in stack traces and CPU profiles, where functions created with
+    /// [`JsFunction::new`](JsFunction::new) appear as anonymous.
+    pub fn with_name<'a, C, U>(
+        cx: &mut C,
+        name: &str,
+        f: fn(FunctionContext) -> JsResult<U>,
+    ) -> JsResult<'a, JsFunction>
+    where
+        C: Context<'a>,
+        U: Value,
+    {
+        build(cx.env(), |out| {
+            let env = cx.env().to_raw();
+            unsafe {
+                let callback = FunctionCallback(f).into_c_callback();
+                neon_runtime::fun::new_named(out, env, name, callback)
+            }
+        })
+    }
+
+    #[cfg(feature = "napi-1")]
+    /// Creates a function from a Rust closure.
+    ///
+    /// Unlike [`JsFunction::new`](JsFunction::new), the closure may capture state. The
+    /// closure is owned by the function and dropped when the function is garbage collected.
+    /// As with any Neon function, a panic in the closure is converted to a JavaScript
+    /// exception.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// use std::cell::Cell;
+    ///
+    /// fn counter(mut cx: FunctionContext) -> JsResult<JsFunction> {
+    ///     let count = Cell::new(0);
+    ///
+    ///     JsFunction::from_closure(&mut cx, move |mut cx| {
+    ///         count.set(count.get() + 1);
+    ///         Ok(cx.number(count.get()))
+    ///     })
+    /// }
+    /// ```
+    ///
+    /// The function is anonymous; use
+    /// [`JsFunction::from_closure_with_name`](JsFunction::from_closure_with_name) to name it.
+    pub fn from_closure<'a, C, F, U>(cx: &mut C, f: F) -> JsResult<'a, JsFunction>
+    where
+        C: Context<'a>,
+        F: Fn(FunctionContext) -> JsResult<U> + 'static,
+        U: Value,
+    {
+        JsFunction::from_closure_with_name(cx, "", f)
+    }
+
+    #[cfg(feature = "napi-1")]
+    /// Creates a function with the given `name` from a Rust closure, as with
+    /// [`JsFunction::from_closure`](JsFunction::from_closure).
+    pub fn from_closure_with_name<'a, C, F, U>(
+        cx: &mut C,
+        name: &str,
+        f: F,
+    ) -> JsResult<'a, JsFunction>
+    where
+        C: Context<'a>,
+        F: Fn(FunctionContext) -> JsResult<U> + 'static,
+        U: Value,
+    {
+        build(cx.env(), |out| {
+            let env = cx.env().to_raw();
+            unsafe {
+                let callback = ClosureCallback(f, PhantomData).into_c_callback();
+                let data = callback.dynamic_callback;
+                let created = neon_runtime::fun::new_with_finalizer(
+                    out,
+                    env,
+                    name,
+                    callback,
+                    drop_closure::<F>,
+                );
+
+                if !created {
+                    drop_closure::<F>(env, data, std::ptr::null_mut());
+                }
+
+                created
+            }
+        })
+    }
 }
 
 impl<CL: Object> JsFunction<CL> {
+    #[cfg(feature = "napi-1")]
+    /// Sets the `length` property of the function, the number of arguments it expects.
+    ///
+    /// Neon cannot infer the number of arguments of a Rust function, so the `length` of
+    /// a function created by Neon is `0` unless it is set explicitly.
+    pub fn set_length<'a, C: Context<'a>>(self, cx: &mut C, length: u32) -> NeonResult<()> {
+        let env = cx.env().to_raw();
+
+        if unsafe { neon_runtime::fun::set_length(env, self.to_raw(), length) } {
+            return Ok(());
+        }
+
+        // N-API fails without throwing if the property can not be redefined
+        if unsafe { neon_runtime::error::is_throwing(env) } {
+            Err(Throw)
+        } else {
+            cx.throw_type_error("Cannot redefine the length of the function")
+        }
+    }
+
     pub fn call<'a, 'b, C: Context<'a>, T, A, AS>(
         self,
         cx: &mut C,
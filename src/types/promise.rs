@@ -0,0 +1,257 @@
+//! Types and traits for working with JavaScript promises.
+
+#[cfg(feature = "napi-6")]
+use std::sync::Arc;
+
+use neon_runtime::raw;
+#[cfg(feature = "napi-6")]
+use neon_runtime::tsfn::ThreadsafeFunction;
+
+use crate::context::internal::Env;
+use crate::context::Context;
+#[cfg(all(feature = "napi-4", feature = "channel-api"))]
+use crate::context::TaskContext;
+#[cfg(all(feature = "napi-4", feature = "channel-api"))]
+use crate::event::{Channel, SendError};
+use crate::handle::{Handle, Managed};
+#[cfg(feature = "napi-6")]
+use crate::lifecycle::{DropData, InstanceData};
+use crate::object::Object;
+use crate::result::JsResult;
+use crate::types::internal::ValueInternal;
+use crate::types::Value;
+
+/// A JavaScript [`Promise`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise) object.
+///
+/// A `JsPromise` is created together with a [`Deferred`](Deferred) handle, which
+/// is used to settle the promise. The `Deferred` may be moved to another thread
+/// and settled later through a [`Channel`](crate::event::Channel).
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(all(feature = "napi-4", feature = "channel-api"))] {
+/// # use neon::prelude::*;
+/// fn resolve_later(mut cx: FunctionContext) -> JsResult<JsPromise> {
+///     let n = cx.argument::<JsNumber>(0)?.value(&mut cx);
+///     let (deferred, promise) = cx.promise();
+///     let channel = cx.channel();
+///
+///     std::thread::spawn(move || {
+///         let result = n * 2.0;
+///
+///         deferred.settle_with(&channel, move |mut cx| Ok(cx.number(result)));
+///     });
+///
+///     Ok(promise)
+/// }
+/// # }
+/// ```
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct JsPromise(raw::Local);
+
+impl JsPromise {
+    pub(crate) fn new<'a, C: Context<'a>>(cx: &mut C) -> (Deferred, Handle<'a, Self>) {
+        let (deferred, promise) = unsafe { neon_runtime::promise::create(cx.env().to_raw()) };
+        let deferred = Deferred {
+            internal: Some(NodeApiDeferred(deferred)),
+            #[cfg(feature = "napi-6")]
+            drop_queue: InstanceData::drop_queue(cx),
+        };
+
+        (deferred, Handle::new_internal(JsPromise(promise)))
+    }
+}
+
+impl Value for JsPromise {}
+
+impl Managed for JsPromise {
+    fn to_raw(self) -> raw::Local {
+        self.0
+    }
+
+    fn from_raw(_env: Env, h: raw::Local) -> Self {
+        JsPromise(h)
+    }
+}
+
+impl ValueInternal for JsPromise {
+    fn name() -> String {
+        "Promise".to_string()
+    }
+
+    fn is_typeof<Other: Value>(env: Env, other: Other) -> bool {
+        unsafe { neon_runtime::tag::is_promise(env.to_raw(), other.to_raw()) }
+    }
+}
+
+impl Object for JsPromise {}
+
+/// A handle to a [`JsPromise`](JsPromise) that may be used to resolve or reject it.
+///
+/// `Deferred` is `Send` and may be moved to another thread. Settling it requires a
+/// [`Context`](crate::context::Context), so it is typically settled on the JavaScript
+/// thread with [`Deferred::settle_with`](Deferred::settle_with).
+///
+/// # Drop Safety
+///
+/// A `Deferred` must be settled with [`Deferred::resolve`](Deferred::resolve),
+/// [`Deferred::reject`](Deferred::reject) or [`Deferred::settle_with`](Deferred::settle_with).
+/// With N-API 6 or greater, dropping an unsettled `Deferred` rejects the promise with
+/// an `Error`. With earlier versions, dropping an unsettled `Deferred` panics.
+pub struct Deferred {
+    // `Option` is used to skip `Drop` when the `Deferred` is settled.
+    // It will *always* be `Some` when a user is interacting with `Deferred`.
+    internal: Option<NodeApiDeferred>,
+    #[cfg(feature = "napi-6")]
+    drop_queue: Arc<ThreadsafeFunction<DropData>>,
+}
+
+impl Deferred {
+    /// Resolve a [`JsPromise`](JsPromise) with a JavaScript value
+    pub fn resolve<'a, V, C>(self, cx: &mut C, value: Handle<V>)
+    where
+        V: Value,
+        C: Context<'a>,
+    {
+        unsafe {
+            neon_runtime::promise::resolve(cx.env().to_raw(), self.into_inner(), value.to_raw());
+        }
+    }
+
+    /// Reject a [`JsPromise`](JsPromise) with a JavaScript value
+    pub fn reject<'a, V, C>(self, cx: &mut C, value: Handle<V>)
+    where
+        V: Value,
+        C: Context<'a>,
+    {
+        unsafe {
+            neon_runtime::promise::reject(cx.env().to_raw(), self.into_inner(), value.to_raw());
+        }
+    }
+
+    #[cfg(all(feature = "napi-4", feature = "channel-api"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "napi-4", feature = "channel-api"))))]
+    /// Settle the [`JsPromise`](JsPromise) by sending a closure across a [`Channel`](Channel)
+    /// to be executed on the main JavaScript thread.
+    ///
+    /// Usage is identical to [`Deferred::settle_with`](Deferred::settle_with).
+    ///
+    /// Returns a [`SendError`](SendError) if sending the closure to the main JavaScript thread fails.
+    /// See [`Channel::try_send`](Channel::try_send) for more details.
+    pub fn try_settle_with<V, F>(self, channel: &Channel, complete: F) -> Result<(), SendError>
+    where
+        V: Value,
+        F: FnOnce(TaskContext) -> JsResult<V> + Send + 'static,
+    {
+        channel.try_send(move |cx| {
+            self.try_catch_settle(cx, complete);
+            Ok(())
+        })
+    }
+
+    #[cfg(all(feature = "napi-4", feature = "channel-api"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "napi-4", feature = "channel-api"))))]
+    /// Settle the [`JsPromise`](JsPromise) by sending a closure across a [`Channel`](Channel)
+    /// to be executed on the main JavaScript thread.
+    ///
+    /// The promise is resolved with the value returned by `complete`. If `complete`
+    /// throws, the promise is rejected with the thrown value.
+    ///
+    /// Panics if there is a libuv error.
+    pub fn settle_with<V, F>(self, channel: &Channel, complete: F)
+    where
+        V: Value,
+        F: FnOnce(TaskContext) -> JsResult<V> + Send + 'static,
+    {
+        self.try_settle_with(channel, complete).unwrap()
+    }
+
+    pub(crate) fn try_catch_settle<'a, C, V, F>(self, cx: C, f: F)
+    where
+        C: Context<'a>,
+        V: Value,
+        F: FnOnce(C) -> JsResult<'a, V>,
+    {
+        let env = cx.env().to_raw();
+        let result = f(cx);
+
+        unsafe {
+            let mut err = std::mem::MaybeUninit::uninit();
+
+            if neon_runtime::error::catch_error(env, err.as_mut_ptr()) {
+                neon_runtime::promise::reject(env, self.into_inner(), err.assume_init());
+            } else if let Ok(value) = result {
+                neon_runtime::promise::resolve(env, self.into_inner(), value.to_raw());
+            } else {
+                panic!("Deferred: unexpected Err(Throw) when VM is not in a throwing state");
+            }
+        }
+    }
+
+    fn into_inner(mut self) -> raw::Deferred {
+        self.internal
+            .take()
+            // `unwrap` will not `panic` because this is the only place
+            // `internal` is replaced with `None` and it consumes `self`.
+            .unwrap()
+            .into_inner()
+    }
+}
+
+impl std::fmt::Debug for Deferred {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Deferred")
+    }
+}
+
+impl Drop for Deferred {
+    #[cfg(not(feature = "napi-6"))]
+    fn drop(&mut self) {
+        // If `None`, the `Deferred` has already been settled
+        if self.internal.is_none() {
+            return;
+        }
+
+        // Destructors are called during stack unwinding, prevent a double
+        // panic and instead prefer to leak.
+        if std::thread::panicking() {
+            eprintln!("Warning: neon::types::Deferred leaked during a panic");
+            return;
+        }
+
+        // Only panic if the event loop is still running
+        if let Ok(true) = crate::context::internal::IS_RUNNING.try_with(|v| *v.borrow()) {
+            panic!(
+                "Must settle a `neon::types::Deferred` with \
+                `neon::types::Deferred::resolve` or `neon::types::Deferred::reject` \
+                https://docs.rs/neon/latest/neon/types/struct.Deferred.html#drop-safety"
+            );
+        }
+    }
+
+    #[cfg(feature = "napi-6")]
+    fn drop(&mut self) {
+        // If `None`, the `Deferred` has already been settled
+        if let Some(internal) = self.internal.take() {
+            let _ = self.drop_queue.call(DropData::Deferred(internal), None);
+        }
+    }
+}
+
+#[repr(transparent)]
+pub(crate) struct NodeApiDeferred(raw::Deferred);
+
+// # Safety
+// A `napi_deferred` may only be settled on the JavaScript thread that created it.
+// Since settling requires a `Context`, access is serialized and it may be moved
+// across threads.
+unsafe impl Send for NodeApiDeferred {}
+unsafe impl Sync for NodeApiDeferred {}
+
+impl NodeApiDeferred {
+    pub(crate) fn into_inner(self) -> raw::Deferred {
+        self.0
+    }
+}
@@ -0,0 +1,169 @@
+use super::{Value, ValueInternal};
+use crate::context::internal::Env;
+use crate::context::Context;
+use crate::handle::{Handle, Managed};
+use crate::object::Object;
+use crate::result::{JsResult, NeonResult};
+use crate::types::{build, JsFunction, JsString, JsValue};
+use neon_runtime;
+use neon_runtime::raw;
+use std::ptr;
+
+/// A JavaScript [symbol](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol)
+/// primitive value.
+///
+/// A `Handle<JsSymbol>` can be used as a [`PropertyKey`](crate::object::PropertyKey)
+/// to get and set symbol-keyed properties on objects.
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// # use neon::types::JsSymbol;
+/// fn tagged_object(mut cx: FunctionContext) -> JsResult<JsObject> {
+///     let obj = cx.empty_object();
+///     let tag = JsSymbol::to_string_tag(&mut cx)?;
+///     let name = cx.string("Tagged");
+///
+///     // Object.prototype.toString.call(obj) === "[object Tagged]"
+///     obj.set(&mut cx, tag, name)?;
+///
+///     Ok(obj)
+/// }
+/// ```
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-1")))]
+pub struct JsSymbol(raw::Local);
+
+impl JsSymbol {
+    /// Creates a new unique symbol without a description.
+    pub fn new<'a, C: Context<'a>>(cx: &mut C) -> Handle<'a, JsSymbol> {
+        JsSymbol::new_internal(cx.env(), ptr::null_mut())
+    }
+
+    /// Creates a new unique symbol with a description.
+    pub fn with_description<'a, C: Context<'a>, S: AsRef<str>>(
+        cx: &mut C,
+        desc: S,
+    ) -> Handle<'a, JsSymbol> {
+        let desc = cx.string(desc);
+
+        JsSymbol::new_internal(cx.env(), desc.to_raw())
+    }
+
+    pub(crate) fn new_internal<'a>(env: Env, desc: raw::Local) -> Handle<'a, JsSymbol> {
+        unsafe {
+            let mut local: raw::Local = std::mem::zeroed();
+            let created = neon_runtime::symbol::new(&mut local, env.to_raw(), desc);
+
+            // Creating a symbol only fails for descriptions that are not strings
+            assert!(created, "Failed to create a symbol");
+
+            Handle::new_internal(JsSymbol(local))
+        }
+    }
+
+    /// Looks up a symbol by `key` in the global symbol registry, creating it if it does not
+    /// yet exist. This is equivalent to calling
+    /// [`Symbol.for(key)`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/for).
+    pub fn for_key<'a, C: Context<'a>, S: AsRef<str>>(
+        cx: &mut C,
+        key: S,
+    ) -> JsResult<'a, JsSymbol> {
+        let ctor = symbol_constructor(cx)?;
+        let symbol_for = ctor
+            .get(cx, "for")?
+            .downcast_or_throw::<JsFunction, _>(cx)?;
+        let key = cx.string(key);
+
+        symbol_for.call(cx, ctor, vec![key])?.downcast_or_throw(cx)
+    }
+
+    /// Gets the description of the symbol, or `None` if it was created without one.
+    pub fn description<'a, C: Context<'a>>(self, cx: &mut C) -> NeonResult<Option<String>> {
+        let env = cx.env();
+        let key = "description";
+        let desc: Handle<JsValue> = build(env, |out| unsafe {
+            neon_runtime::object::get_string(
+                env.to_raw(),
+                out,
+                self.to_raw(),
+                key.as_ptr(),
+                key.len() as i32,
+            )
+        })?;
+
+        Ok(desc
+            .downcast::<JsString, _>(cx)
+            .ok()
+            .map(|desc| desc.value(cx)))
+    }
+
+    fn well_known<'a, C: Context<'a>>(cx: &mut C, name: &str) -> JsResult<'a, JsSymbol> {
+        symbol_constructor(cx)?.get(cx, name)?.downcast_or_throw(cx)
+    }
+
+    /// Returns the well-known symbol `Symbol.asyncIterator`.
+    pub fn async_iterator<'a, C: Context<'a>>(cx: &mut C) -> JsResult<'a, JsSymbol> {
+        JsSymbol::well_known(cx, "asyncIterator")
+    }
+
+    /// Returns the well-known symbol `Symbol.hasInstance`.
+    pub fn has_instance<'a, C: Context<'a>>(cx: &mut C) -> JsResult<'a, JsSymbol> {
+        JsSymbol::well_known(cx, "hasInstance")
+    }
+
+    /// Returns the well-known symbol `Symbol.isConcatSpreadable`.
+    pub fn is_concat_spreadable<'a, C: Context<'a>>(cx: &mut C) -> JsResult<'a, JsSymbol> {
+        JsSymbol::well_known(cx, "isConcatSpreadable")
+    }
+
+    /// Returns the well-known symbol `Symbol.iterator`.
+    pub fn iterator<'a, C: Context<'a>>(cx: &mut C) -> JsResult<'a, JsSymbol> {
+        JsSymbol::well_known(cx, "iterator")
+    }
+
+    /// Returns the well-known symbol `Symbol.species`.
+    pub fn species<'a, C: Context<'a>>(cx: &mut C) -> JsResult<'a, JsSymbol> {
+        JsSymbol::well_known(cx, "species")
+    }
+
+    /// Returns the well-known symbol `Symbol.toPrimitive`.
+    pub fn to_primitive<'a, C: Context<'a>>(cx: &mut C) -> JsResult<'a, JsSymbol> {
+        JsSymbol::well_known(cx, "toPrimitive")
+    }
+
+    /// Returns the well-known symbol `Symbol.toStringTag`.
+    pub fn to_string_tag<'a, C: Context<'a>>(cx: &mut C) -> JsResult<'a, JsSymbol> {
+        JsSymbol::well_known(cx, "toStringTag")
+    }
+}
+
+fn symbol_constructor<'a, C: Context<'a>>(cx: &mut C) -> JsResult<'a, JsFunction> {
+    let global = cx.global();
+
+    global.get(cx, "Symbol")?.downcast_or_throw(cx)
+}
+
+impl Value for JsSymbol {}
+
+impl Managed for JsSymbol {
+    fn to_raw(self) -> raw::Local {
+        self.0
+    }
+
+    fn from_raw(_: Env, h: raw::Local) -> Self {
+        JsSymbol(h)
+    }
+}
+
+impl ValueInternal for JsSymbol {
+    fn name() -> String {
+        "symbol".to_string()
+    }
+
+    fn is_typeof<Other: Value>(env: Env, other: Other) -> bool {
+        unsafe { neon_runtime::tag::is_symbol(env.to_raw(), other.to_raw()) }
+    }
+}
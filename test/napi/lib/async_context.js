@@ -0,0 +1,75 @@
+const addon = require('..');
+const assert = require('chai').assert;
+const { AsyncLocalStorage, createHook } = require('async_hooks');
+
+describe('AsyncResource', function() {
+  const storage = new AsyncLocalStorage();
+
+  it('calls a function in the async context it was created in', function () {
+    const resource = storage.run('created', () => addon.async_resource_new('neon:test'));
+
+    storage.run('called', () => {
+      const result = addon.async_resource_call(resource, (n) => [n, storage.getStore()]);
+
+      assert.deepEqual(result, [42, 'created']);
+    });
+  });
+
+  it('propagates exceptions from a called function', function () {
+    const resource = addon.async_resource_new('neon:test');
+
+    assert.throws(() => addon.async_resource_call(resource, () => {
+      throw new Error('thrown');
+    }), /thrown/);
+  });
+
+  it('runs a closure in the async context it was created in', function () {
+    const resource = storage.run('created', () => addon.async_resource_new('neon:test'));
+    const store = storage.run('entered', () => {
+      return addon.async_resource_enter(resource, () => storage.getStore());
+    });
+
+    assert.strictEqual(store, 'created');
+  });
+
+  it('reports its name and destruction to async_hooks', function (cb) {
+    const ids = new Map();
+    const hook = createHook({
+      init(id, type) {
+        if (type === 'neon:destroy_test') {
+          ids.set(id, type);
+        }
+      },
+      destroy(id) {
+        if (ids.has(id)) {
+          hook.disable();
+          cb();
+        }
+      }
+    }).enable();
+
+    addon.async_resource_destroy('neon:destroy_test');
+    assert.strictEqual(ids.size, 1);
+  });
+
+  it('restores the async context of a channel', function (cb) {
+    storage.run('channel', () => {
+      addon.channel_with_async_context(() => {
+        try {
+          assert.strictEqual(storage.getStore(), 'channel');
+          cb();
+        } catch (err) {
+          cb(err);
+        }
+      });
+    });
+  });
+
+  it('settles a deferred in the async context of a channel', async function () {
+    const store = await storage.run('deferred', () => {
+      return addon.settle_with_async_context(() => storage.getStore());
+    });
+
+    assert.strictEqual(store, 'deferred');
+  });
+});
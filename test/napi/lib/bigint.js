@@ -0,0 +1,62 @@
+const addon = require('..');
+const assert = require('chai').assert;
+
+describe('JsBigInt', function () {
+  it('should create a BigInt from an i64', function () {
+    assert.strictEqual(addon.bigint_from_i64(), -(2n ** 63n));
+  });
+
+  it('should create a BigInt from a u64', function () {
+    assert.strictEqual(addon.bigint_from_u64(), 2n ** 64n - 1n);
+  });
+
+  it('should create a BigInt from an i128', function () {
+    assert.strictEqual(addon.bigint_from_i128(), -(2n ** 127n));
+  });
+
+  it('should create a BigInt from a u128', function () {
+    assert.strictEqual(addon.bigint_from_u128(), 2n ** 128n - 1n);
+  });
+
+  it('should create a BigInt from digits', function () {
+    assert.strictEqual(addon.bigint_from_digits_le(), -(1n + 2n * 2n ** 64n + 3n * 2n ** 128n));
+  });
+
+  it('should losslessly convert to 64-bit integers', function () {
+    assert.strictEqual(addon.bigint_to_i64(-(2n ** 63n)), '-9223372036854775808');
+    assert.strictEqual(addon.bigint_to_i64(2n ** 63n - 1n), '9223372036854775807');
+    assert.strictEqual(addon.bigint_to_u64(2n ** 64n - 1n), '18446744073709551615');
+    assert.strictEqual(addon.bigint_to_u64(0n), '0');
+  });
+
+  it('should losslessly convert to 128-bit integers', function () {
+    assert.strictEqual(addon.bigint_to_i128(-(2n ** 127n)), (-(2n ** 127n)).toString());
+    assert.strictEqual(addon.bigint_to_i128(2n ** 127n - 1n), (2n ** 127n - 1n).toString());
+    assert.strictEqual(addon.bigint_to_u128(2n ** 128n - 1n), (2n ** 128n - 1n).toString());
+  });
+
+  it('should report lossy conversions', function () {
+    assert.throws(() => addon.bigint_to_i64(2n ** 63n), RangeError, /BigInt overflow/);
+    assert.throws(() => addon.bigint_to_i64(-(2n ** 63n) - 1n), RangeError, /BigInt underflow/);
+    assert.throws(() => addon.bigint_to_u64(2n ** 64n), RangeError, /BigInt overflow/);
+    assert.throws(() => addon.bigint_to_u64(-1n), RangeError, /BigInt underflow/);
+    assert.throws(() => addon.bigint_to_i128(2n ** 127n), RangeError, /BigInt overflow/);
+    assert.throws(() => addon.bigint_to_i128(-(2n ** 127n) - 1n), RangeError, /BigInt underflow/);
+    assert.throws(() => addon.bigint_to_u128(2n ** 128n), RangeError, /BigInt overflow/);
+    assert.throws(() => addon.bigint_to_u128(-1n), RangeError, /BigInt underflow/);
+  });
+
+  it('should read digits', function () {
+    const n = 5n + 6n * 2n ** 64n;
+
+    assert.deepEqual(addon.bigint_to_digits_le(n), [1, '5', '6']);
+    assert.deepEqual(addon.bigint_to_digits_le(-n), [-1, '5', '6']);
+    assert.deepEqual(addon.bigint_to_digits_le(0n), [1]);
+  });
+
+  it('should check if a value is a BigInt', function () {
+    assert.isTrue(addon.is_bigint(42n));
+    assert.isFalse(addon.is_bigint(42));
+    assert.isFalse(addon.is_bigint('42'));
+  });
+});
@@ -0,0 +1,190 @@
+const addon = require('..');
+const assert = require('chai').assert;
+const { Worker } = require('worker_threads');
+
+describe('JsClass', function() {
+  it('return a JsClass built in Rust', function () {
+    assert.isFunction(addon.User);
+    assert.strictEqual(addon.User.name, 'User');
+  });
+
+  it('constructs an instance of a JsClass', function () {
+    const u = new addon.User(1, "some", "thing", "else");
+    assert.instanceOf(u, addon.User);
+  });
+
+  it('can use getter function defined in Rust', function () {
+    const u = new addon.User(1, "some", "thing", "else");
+    assert.strictEqual(u.get('id'), 1);
+    assert.strictEqual(u.get('first_name'), "some");
+    assert.strictEqual(u.get('last_name'), "thing");
+    assert.strictEqual(u.get('email'), "else");
+    assert.throws(function() { u.get('not_a_field') }, TypeError);
+  });
+
+  it('defines methods on the prototype', function () {
+    const u = new addon.User(1, "some", "thing", "else");
+    assert.isFalse(Object.prototype.hasOwnProperty.call(u, 'get'));
+    assert.isFalse(Object.keys(addon.User.prototype).includes('get'));
+    assert.isFunction(addon.User.prototype.get);
+  });
+
+  it('throws when a method is called on the wrong type', function () {
+    const u = new addon.User(1, "some", "thing", "else");
+    const counter = new addon.Counter();
+    assert.throws(function() { u.get.call({}, 'id') }, TypeError);
+    assert.throws(function() { u.get.call(counter, 'id') }, TypeError);
+    assert.throws(function() { u.get.call(Object.create(addon.User.prototype), 'id') }, TypeError);
+  });
+
+  it('throws when a constructor is called without new', function () {
+    assert.throws(function() { addon.User(1, "some", "thing", "else") }, TypeError, /User constructor called without new/);
+  });
+
+  it('can customize calling a constructor without new', function () {
+    const counter = addon.Counter();
+    assert.instanceOf(counter, addon.Counter);
+    assert.strictEqual(addon.counter_value(counter), 0);
+  });
+
+  it('mutates the internals of an instance', function () {
+    const counter = new addon.Counter(10);
+    assert.strictEqual(counter.increment(), 11);
+    assert.strictEqual(counter.increment(), 12);
+    assert.strictEqual(addon.counter_value(counter), 12);
+  });
+
+  it('downcasts instances of a JsClass', function () {
+    assert.isTrue(addon.is_counter(new addon.Counter()));
+    assert.isFalse(addon.is_counter(new addon.User(1, "some", "thing", "else")));
+    assert.isFalse(addon.is_counter({}));
+    assert.isFalse(addon.is_counter(Object.create(addon.Counter.prototype)));
+    assert.isFalse(addon.is_counter(42));
+    assert.throws(function() { addon.counter_value({}) }, TypeError);
+  });
+
+  it('downcasts without inspecting the prototype chain', function () {
+    const proxy = new Proxy(new addon.Counter(), {
+      getPrototypeOf() { throw new Error('should not be called'); }
+    });
+
+    assert.isFalse(addon.is_counter(proxy));
+  });
+
+  it('defines classes in each worker thread', function (cb) {
+    const worker = new Worker(`
+      const { parentPort } = require('worker_threads');
+      const addon = require(${JSON.stringify(require.resolve('..'))});
+      const counter = new addon.Counter(41);
+      counter.increment();
+      parentPort.postMessage(addon.counter_value(counter));
+    `, { eval: true });
+
+    let value;
+    worker.on('message', (message) => value = message);
+    worker.on('error', cb);
+    worker.on('exit', (code) => {
+      assert.strictEqual(code, 0);
+      assert.strictEqual(value, 42);
+      assert.instanceOf(new addon.Counter(), addon.Counter);
+      cb();
+    });
+  });
+
+  it('can be subclassed', function () {
+    class Doubler extends addon.Counter {
+      increment() {
+        super.increment();
+        return super.increment();
+      }
+    }
+
+    const doubler = new Doubler(1);
+    assert.instanceOf(doubler, addon.Counter);
+    assert.strictEqual(doubler.increment(), 3);
+    assert.isTrue(addon.is_counter(doubler));
+  });
+
+  it('propagates exceptions thrown by a constructor', function () {
+    assert.instanceOf(new addon.Validated(true), addon.Validated);
+    assert.throws(function() { new addon.Validated(false) }, RangeError, /invalid/);
+  });
+
+  it('converts a Rust panic to a throw in a method', function() {
+    const u = new addon.User(1, "some", "thing", "else");
+    assert.throws(function() { u.panic() }, Error, /^internal error in Neon module: User.prototype.panic$/);
+  });
+
+  it('converts a Rust panic to a throw in a constructor call', function() {
+    assert.throws(function() { addon.PanickyConstructor() }, Error, /^internal error in Neon module: constructor call panicking$/);
+  });
+
+  it('converts a Rust panic to a throw in a constructor new', function() {
+    assert.throws(function() { new addon.PanickyConstructor() }, Error, /^internal error in Neon module: constructor panicking$/);
+  });
+
+  it('converts a Rust panic to a throw in a constructor allocator', function() {
+    assert.throws(function() { new addon.PanickyAllocator() }, Error, /^internal error in Neon module: allocator panicking$/);
+  });
+});
+
+describe('#[neon::class]', function() {
+  it('constructs an instance', function () {
+    const account = new addon.Account("Alice");
+    assert.instanceOf(account, addon.Account);
+    assert.strictEqual(addon.Account.name, 'Account');
+    assert.strictEqual(Object.prototype.toString.call(account), '[object Account]');
+  });
+
+  it('calls methods taking &mut self', function () {
+    const account = new addon.Account("Alice");
+    assert.strictEqual(account.deposit(10), 10);
+    assert.strictEqual(account.deposit(5.5), 15.5);
+    assert.throws(() => account.deposit(-1), RangeError, /amount must be positive/);
+  });
+
+  it('defines getters and setters', function () {
+    const account = new addon.Account("Alice");
+    account.deposit(0.125);
+    assert.strictEqual(account.owner, "Alice");
+    assert.strictEqual(account.balance, 0.13);
+    account.owner = "Bob";
+    assert.strictEqual(account.owner, "Bob");
+    assert.throws(() => { account.owner = 42 }, TypeError);
+    assert.throws(() => { 'use strict'; account.balance = 1 }, TypeError);
+
+    const descriptor = Object.getOwnPropertyDescriptor(addon.Account.prototype, 'owner');
+    assert.isFunction(descriptor.get);
+    assert.isFunction(descriptor.set);
+    assert.isFalse(descriptor.enumerable);
+  });
+
+  it('defines static methods', function () {
+    const account = addon.Account.withBalance("Alice", 20);
+    assert.instanceOf(account, addon.Account);
+    assert.strictEqual(account.balance, 20);
+  });
+
+  it('renames and skips members', function () {
+    assert.isFunction(addon.Account.prototype.withLock);
+    assert.isUndefined(addon.Account.prototype.with_lock);
+    assert.isUndefined(addon.Account.prototype.rounded_balance);
+    assert.isUndefined(addon.Account.rounded_balance);
+  });
+
+  it('throws on conflicting borrows', function () {
+    const account = new addon.Account("Alice");
+    assert.throws(() => account.withLock(() => account.deposit(1)), Error, /Account is already borrowed/);
+    assert.throws(() => account.withLock(() => account.balance), Error, /Account is already mutably borrowed/);
+    assert.strictEqual(account.withLock(() => 42), 42);
+    assert.strictEqual(account.deposit(1), 1);
+  });
+
+  it('throws when a member is called on the wrong type', function () {
+    const account = new addon.Account("Alice");
+    assert.throws(() => account.deposit.call({}, 1), TypeError);
+    assert.throws(() => account.deposit.call(new addon.Counter(), 1), TypeError);
+    const { get } = Object.getOwnPropertyDescriptor(addon.Account.prototype, 'balance');
+    assert.throws(() => get.call(new addon.Counter()), TypeError);
+  });
+});
@@ -28,6 +28,122 @@ describe('errors', function() {
     assert.strictEqual(err.message, msg);
   });
 
+  it('should be able to create a syntax error', function () {
+    const msg = "Unexpected token";
+    const err = addon.new_syntax_error(msg);
+
+    assert.instanceOf(err, SyntaxError);
+    assert.instanceOf(err, Error);
+    assert.strictEqual(err.message, msg);
+  });
+
+  it('should be able to create errors with a code', function () {
+    for (const [kind, ctor] of [['Error', Error], ['TypeError', TypeError], ['RangeError', RangeError], ['SyntaxError', SyntaxError]]) {
+      const err = addon.new_error_with_code(kind, 'ERR_' + kind.toUpperCase(), 'Oh, no!');
+
+      assert.instanceOf(err, ctor);
+      assert.strictEqual(err.code, 'ERR_' + kind.toUpperCase());
+      assert.strictEqual(err.message, 'Oh, no!');
+    }
+  });
+
+  it('should be able to throw an error with a code', function () {
+    assert.throws(() => addon.throw_error_with_code('ERR_INVALID_ARG_TYPE', 'bad arg'), TypeError, 'bad arg');
+
+    try {
+      addon.throw_error_with_code('ERR_INVALID_ARG_TYPE', 'bad arg');
+    } catch (err) {
+      assert.strictEqual(err.code, 'ERR_INVALID_ARG_TYPE');
+    }
+  });
+
+  it('should be able to attach properties to an error', function () {
+    try {
+      addon.throw_error_with_properties('/missing');
+      assert.fail('should have thrown');
+    } catch (err) {
+      assert.instanceOf(err, Error);
+      assert.strictEqual(err.code, 'ENOENT');
+      assert.strictEqual(err.errno, -2);
+      assert.strictEqual(err.syscall, 'open');
+      assert.strictEqual(err.path, '/missing');
+    }
+  });
+
+  it('should capture an error as an owned snapshot', function () {
+    const err = new TypeError('bad arg');
+    err.code = 'ERR_INVALID_ARG_TYPE';
+
+    const info = addon.capture_error_info(err);
+
+    assert.strictEqual(info.name, 'TypeError');
+    assert.strictEqual(info.message, 'bad arg');
+    assert.strictEqual(info.code, 'ERR_INVALID_ARG_TYPE');
+    assert.strictEqual(info.stack, err.stack);
+    assert.strictEqual(info.display, 'TypeError: bad arg');
+    assert.isUndefined(info.cause);
+  });
+
+  it('should capture the cause of an error', function () {
+    const root = new RangeError('root');
+    const err = new Error('outer');
+    err.cause = root;
+
+    const info = addon.capture_error_info(err);
+
+    assert.strictEqual(info.message, 'outer');
+    assert.strictEqual(info.cause.name, 'RangeError');
+    assert.strictEqual(info.cause.message, 'root');
+  });
+
+  it('should capture errors with a cyclic cause', function () {
+    const err = new Error('cycle');
+    err.cause = err;
+
+    const info = addon.capture_error_info(err);
+
+    assert.strictEqual(info.cause.cause.message, 'cycle');
+  });
+
+  it('should capture thrown values that are not errors', function () {
+    assert.strictEqual(addon.capture_error_info('oops').display, 'oops');
+    assert.strictEqual(addon.capture_error_info(42).message, '42');
+    assert.strictEqual(addon.capture_error_info(Symbol('sym')).message, 'Symbol(sym)');
+    assert.isUndefined(addon.capture_error_info({}).name);
+  });
+
+  it('should rethrow an equivalent error', function () {
+    const root = new Error('root');
+    root.code = 'EROOT';
+    const err = new SyntaxError('outer');
+    err.cause = root;
+
+    try {
+      addon.rethrow_error_info(err);
+      assert.fail('should have thrown');
+    } catch (e) {
+      assert.notStrictEqual(e, err);
+      assert.instanceOf(e, SyntaxError);
+      assert.strictEqual(e.message, 'outer');
+      assert.strictEqual(e.stack, err.stack);
+      assert.instanceOf(e.cause, Error);
+      assert.strictEqual(e.cause.code, 'EROOT');
+    }
+  });
+
+  it('should rethrow custom errors with their name', function () {
+    class ValidationError extends Error {}
+    ValidationError.prototype.name = 'ValidationError';
+
+    assert.throws(() => addon.rethrow_error_info(new ValidationError('invalid')), Error, /^invalid$/);
+
+    try {
+      addon.rethrow_error_info(new ValidationError('invalid'));
+    } catch (e) {
+      assert.strictEqual(e.name, 'ValidationError');
+    }
+  });
+
   it('should be able to throw an error', function () {
     const msg = "Out of Bounds";
     
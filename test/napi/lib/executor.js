@@ -0,0 +1,96 @@
+const addon = require('..');
+const assert = require('chai').assert;
+const { Worker } = require('worker_threads');
+
+(function () {
+  // These tests require GC exposed to shutdown properly; skip if it is not
+  return typeof global.gc === 'function' ? describe : describe.skip;
+})()('executor', function() {
+  afterEach(() => {
+    // Force garbage collection to shutdown `Channel`
+    global.gc();
+  });
+
+  it('should run a spawned future on the runtime', function (cb) {
+    addon.spawn_add(1, 2, function (err, sum) {
+      if (err) {
+        return cb(err);
+      }
+
+      try {
+        assert.strictEqual(sum, 3);
+        cb();
+      } catch (err) {
+        cb(err);
+      }
+    });
+  });
+
+  it('should run futures on the threads of the tokio runtime', function (cb) {
+    addon.runtime_thread_name(function (err, name) {
+      if (err) {
+        return cb(err);
+      }
+
+      try {
+        assert.strictEqual(name, 'neon-runtime');
+        cb();
+      } catch (err) {
+        cb(err);
+      }
+    });
+  });
+
+  it('should run futures on a runtime installed with set_runtime', function (cb) {
+    const worker = new Worker(`
+      const { parentPort } = require('worker_threads');
+      const addon = require(${JSON.stringify(require.resolve('..'))});
+
+      addon.set_custom_runtime();
+      addon.runtime_thread_name((err, name) => {
+        addon.async_add(2, 3).then((sum) => parentPort.postMessage({ name, sum }));
+      });
+    `, { eval: true });
+
+    let result;
+
+    worker.on('message', (message) => result = message);
+    worker.on('error', cb);
+    worker.on('exit', (code) => {
+      try {
+        assert.strictEqual(code, 0);
+        assert.deepEqual(result, { name: 'custom-runtime', sum: 5 });
+        cb();
+      } catch (err) {
+        cb(err);
+      }
+    });
+  });
+
+  it('should not replace a runtime that is in use', function () {
+    assert.throws(() => addon.set_runtime_after_use(), /already been created/);
+  });
+
+  it('should shut down the runtime when a worker exits', function (cb) {
+    const worker = new Worker(`
+      const { parentPort } = require('worker_threads');
+      const addon = require(${JSON.stringify(require.resolve('..'))});
+
+      addon.spawn_add(2, 3, (err, sum) => parentPort.postMessage(sum));
+    `, { eval: true });
+
+    let sum;
+
+    worker.on('message', (message) => sum = message);
+    worker.on('error', cb);
+    worker.on('exit', (code) => {
+      try {
+        assert.strictEqual(code, 0);
+        assert.strictEqual(sum, 5);
+        cb();
+      } catch (err) {
+        cb(err);
+      }
+    });
+  });
+});
@@ -0,0 +1,141 @@
+const addon = require('..');
+const assert = require('chai').assert;
+
+describe('TryFromJs and TryIntoJs', function() {
+  it('extracts primitive arguments', function () {
+    assert.deepEqual(addon.extract_values(true, 'hello', 1.5, 42, -7), [true, 'hello', 1.5, 42, -7]);
+    assert.deepEqual(addon.extract_values(false, '', 0, 0), [false, '', 0, 0, null]);
+    assert.deepEqual(addon.extract_values(false, '', 0, 0, null), [false, '', 0, 0, null]);
+  });
+
+  it('throws a TypeError naming the argument', function () {
+    assert.throws(() => addon.extract_values('true', 'hello', 1.5, 42), TypeError, /^argument 0: expected a boolean, found string$/);
+    assert.throws(() => addon.extract_values(true), TypeError, /^argument 1: expected a string, found undefined$/);
+    assert.throws(() => addon.extract_values(true, 'hello', 1.5, 42, 'x'), TypeError, /^argument 4: expected an integer in the range of i32, found string$/);
+  });
+
+  it('checks the range of integers', function () {
+    assert.throws(() => addon.extract_values(true, '', 0, -1), TypeError, /argument 3: expected an integer in the range of u32, found number/);
+    assert.throws(() => addon.extract_values(true, '', 0, 1.5), TypeError, /argument 3/);
+    assert.throws(() => addon.extract_values(true, '', 0, 2 ** 32), TypeError, /argument 3/);
+    assert.deepEqual(addon.extract_values(true, '', 0, 2 ** 32 - 1), [true, '', 0, 2 ** 32 - 1, null]);
+  });
+
+  it('converts a value with from_js', function () {
+    assert.strictEqual(addon.extract_from_value([1, -2, 3]), '1,-2,3');
+    assert.throws(() => addon.extract_from_value([1, null]), TypeError, /^value\[1\]: expected an integer in the range of i64, found null$/);
+  });
+
+  it('exports functions taking and returning Rust values', function () {
+    assert.strictEqual(addon.sum([1, 2, 3.5]), 6.5);
+    assert.strictEqual(addon.sum([]), 0);
+    assert.strictEqual(addon.sum.length, 1);
+    assert.isUndefined(addon.unit());
+    assert.strictEqual(addon.unit.length, 0);
+  });
+
+  it('converts arrays and tuples', function () {
+    assert.deepEqual(addon.swap_pair(['a', 1]), [1, 'a']);
+    assert.throws(() => addon.sum([1, 'a']), TypeError, /^argument 0\[1\]: expected a number, found string$/);
+    assert.throws(() => addon.sum({}), TypeError, /^argument 0: expected an array, found object$/);
+    assert.throws(() => addon.swap_pair(['a']), TypeError, /^argument 0\[1\]: expected a number, found undefined$/);
+  });
+
+  it('converts objects to and from maps', function () {
+    assert.deepEqual(addon.total_scores({ a: [1, 2], b: [] }), { a: 3, b: 0 });
+    assert.throws(() => addon.total_scores({ a: [1, 'x'] }), TypeError, /^argument 0\.a\[1\]: expected an integer in the range of u32, found string$/);
+  });
+
+  it('converts buffers', function () {
+    const reversed = addon.reverse_bytes(Buffer.from([1, 2, 3]));
+    assert.instanceOf(reversed, Buffer);
+    assert.deepEqual([...reversed], [3, 2, 1]);
+    assert.deepEqual([...addon.reverse_bytes(new Uint8Array([4, 5]))], [5, 4]);
+    assert.deepEqual([...addon.reverse_bytes(new Uint8Array([6, 7]).buffer)], [7, 6]);
+    assert.throws(() => addon.reverse_bytes([1, 2]), TypeError, /expected a buffer, found array/);
+  });
+
+  it('converts options', function () {
+    assert.strictEqual(addon.optional_greeting('Neon'), 'Hello, Neon!');
+    assert.isNull(addon.optional_greeting());
+    assert.isNull(addon.optional_greeting(null));
+  });
+
+  it('passes a borrowed context and propagates throws', function () {
+    assert.strictEqual(addon.checked_add_u8(1, 2), 3);
+    assert.strictEqual(addon.checked_add_u8.length, 2);
+    assert.throws(() => addon.checked_add_u8(200, 100), RangeError, /overflow/);
+    assert.throws(() => addon.checked_add_u8(256, 0), TypeError, /argument 0: expected an integer in the range of u8/);
+  });
+
+  it('extracts handles to a JsBox', function () {
+    const named = addon.create_named('Neon');
+    assert.strictEqual(addon.named_name(named), 'Neon');
+    assert.throws(() => addon.named_name({}), TypeError, /^argument 0: expected a .*JsBox/);
+  });
+
+  it('describes the expected type of a handle', function () {
+    assert.strictEqual(addon.shout('neon'), 'NEON');
+    assert.throws(() => addon.shout(1), TypeError, /^argument 0: expected a string, found number$/);
+    assert.throws(() => addon.array_length({}, Buffer.alloc(0)), TypeError, /^argument 0: expected an array, found object$/);
+    assert.throws(() => addon.array_length([], []), TypeError, /^argument 1: expected a buffer, found array$/);
+  });
+});
+
+describe('#[derive(FromJs, IntoJs)]', function() {
+  it('converts structs to and from objects', function () {
+    const options = { name: 'neon', retries: { maxAttempts: 3, delayMs: 10 }, label: 'x', verbose: true };
+    assert.deepEqual(addon.normalize_options(options), options);
+  });
+
+  it('fills in defaults and optional fields', function () {
+    assert.deepEqual(addon.normalize_options({ name: 'neon', retries: { maxAttempts: 1 } }), {
+      name: 'neon',
+      retries: { maxAttempts: 1, delayMs: 100 },
+      label: null,
+      verbose: false
+    });
+  });
+
+  it('names the path of a field that cannot be converted', function () {
+    assert.throws(
+      () => addon.normalize_options({ name: 'neon', retries: { maxAttempts: 'many' } }),
+      TypeError,
+      /^argument 0\.retries\.maxAttempts: expected an integer in the range of u32, found string$/
+    );
+    assert.throws(() => addon.normalize_options({ retries: {} }), TypeError, /^argument 0\.name: expected a string, found undefined$/);
+    assert.throws(() => addon.normalize_options('neon'), TypeError, /^argument 0: expected an object, found string$/);
+  });
+
+  it('converts tuple and newtype structs', function () {
+    assert.deepEqual(addon.midpoint([0, 0], [2, 4]), [1, 2]);
+    assert.throws(() => addon.midpoint([0, 0], [2]), TypeError, /^argument 1\[1\]: expected a number, found undefined$/);
+    assert.strictEqual(addon.double_meters(1.5), 3);
+  });
+
+  it('converts externally tagged enums', function () {
+    assert.strictEqual(addon.scale_shape('empty', 2), 'empty');
+    assert.deepEqual(addon.scale_shape({ circle: 1 }, 2), { circle: 2 });
+    assert.deepEqual(addon.scale_shape({ rectangle: { width: 1, height: 2 } }, 2), { rectangle: { width: 2, height: 4 } });
+    assert.deepEqual(addon.scale_shape({ segment: [[0, 1], [2, 3]] }, 2), { segment: [[0, 2], [4, 6]] });
+    assert.throws(() => addon.scale_shape('square', 2), TypeError, /^argument 0: expected one of "empty", "circle", "rectangle", "segment", found string$/);
+    assert.throws(() => addon.scale_shape({ circle: 1, empty: 1 }, 2), TypeError, /^argument 0: expected an object with one of "circle", "rectangle", "segment"/);
+    assert.throws(() => addon.scale_shape({ rectangle: { width: 1 } }, 2), TypeError, /^argument 0\.rectangle\.height: expected a number, found undefined$/);
+  });
+
+  it('converts internally tagged enums', function () {
+    assert.deepEqual(addon.echo_event({ kind: 'key-press', key: 'a' }), { kind: 'key-press', key: 'a' });
+    assert.deepEqual(addon.echo_event({ kind: 'mouse-click', x: 1, y: 2 }), { kind: 'mouse-click', x: 1, y: 2 });
+    assert.deepEqual(addon.echo_event({ kind: 'closed' }), { kind: 'closed' });
+    assert.throws(() => addon.echo_event({ kind: 'resized' }), TypeError, /^argument 0: expected a number, found object$/);
+    assert.throws(() => addon.echo_event({ kind: 'scroll' }), TypeError, /^argument 0\.kind: expected one of "key-press", "mouse-click", "resized", "closed", found string$/);
+    assert.throws(() => addon.echo_event({}), TypeError, /^argument 0\.kind: expected a string, found undefined$/);
+  });
+
+  it('converts adjacently tagged enums', function () {
+    assert.deepEqual(addon.echo_message({ type: 'text', data: 'hi' }), { type: 'text', data: 'hi' });
+    assert.deepEqual(addon.echo_message({ type: 'move', data: [1, 2] }), { type: 'move', data: [1, 2] });
+    assert.deepEqual(addon.echo_message({ type: 'quit' }), { type: 'quit' });
+    assert.throws(() => addon.echo_message({ type: 'text', data: 1 }), TypeError, /^argument 0\.data: expected a string, found number$/);
+  });
+});
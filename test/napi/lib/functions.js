@@ -114,4 +114,114 @@ describe('JsFunction', function() {
     assert.equal(addon.is_construct.call({}).wasConstructed, false);
     assert.equal((new addon.is_construct()).wasConstructed, true);
   });
+
+  it('return a JsFunction built from a Rust closure', function () {
+    const greet = addon.return_js_closure('Hello, ');
+
+    assert.typeOf(greet, 'function');
+    assert.strictEqual(greet('World'), 'Hello, World (1)');
+    assert.strictEqual(greet('Neon'), 'Hello, Neon (2)');
+    assert.strictEqual(addon.return_js_closure('Bye, ')('World'), 'Bye, World (1)');
+  });
+
+  it('converts a Rust panic in a closure to a throw', function () {
+    const f = addon.return_panicking_closure('closure panicked');
+
+    assert.throws(f, /internal error in Neon module: closure panicked/);
+    assert.throws(f, /internal error in Neon module: closure panicked/);
+  });
+
+  it('does not treat a closure as an instance of a class', function () {
+    const f = addon.return_js_closure('Hello, ');
+
+    Object.setPrototypeOf(f, addon.Counter.prototype);
+
+    assert.isFalse(addon.is_counter(f));
+    assert.throws(() => addon.Counter.prototype.increment.call(f), TypeError);
+    assert.strictEqual(f('World'), 'Hello, World (1)');
+  });
+
+  (typeof global.gc === 'function' ? it : it.skip)('drops a closure when its function is garbage collected', function (cb) {
+    const before = addon.count_dropped_closures();
+
+    // IIFE to allow GC
+    (function () {
+      addon.return_counted_closure()();
+    })();
+
+    global.gc();
+
+    // Finalizers may be deferred until after the GC completes
+    setTimeout(() => {
+      global.gc();
+      assert.isAbove(addon.count_dropped_closures(), before);
+      cb();
+    }, 10);
+  });
+
+  it('names exported functions after their key', function () {
+    assert.strictEqual(addon.return_js_function.name, 'return_js_function');
+    assert.strictEqual(addon.call_js_function.name, 'call_js_function');
+    assert.strictEqual(addon.return_js_function.length, 0);
+  });
+
+  it('exports a function with a length', function () {
+    assert.strictEqual(addon.add_with_length.name, 'add_with_length');
+    assert.strictEqual(addon.add_with_length.length, 2);
+    assert.strictEqual(addon.add_with_length(1, 2), 3);
+  });
+
+  it('return a JsFunction with a name and length', function () {
+    const f = addon.return_named_js_function('add1', 1);
+
+    assert.strictEqual(f.name, 'add1');
+    assert.strictEqual(f.length, 1);
+    assert.strictEqual(f(41), 42);
+
+    const descriptor = Object.getOwnPropertyDescriptor(f, 'length');
+    assert.isFalse(descriptor.writable);
+    assert.isFalse(descriptor.enumerable);
+    assert.isTrue(descriptor.configurable);
+  });
+
+  it('throws when the length of a function can not be redefined', function () {
+    const f = function (a, b) {};
+
+    Object.defineProperty(f, 'length', { configurable: false });
+
+    assert.throws(() => addon.set_function_length(f, 3), TypeError, /length/);
+    assert.strictEqual(f.length, 2);
+  });
+
+  it('return a JsFunction with a name built from a Rust closure', function () {
+    const f = addon.return_named_js_closure('greet');
+
+    assert.strictEqual(f.name, 'greet');
+    assert.strictEqual(f(), 'Hello from greet');
+    assert.strictEqual(addon.return_js_closure('Hello, ').name, '');
+  });
+
+  it('return a JsFunction with a non-ASCII name', function () {
+    assert.strictEqual(addon.return_named_js_function('añadir 🦀', 0).name, 'añadir 🦀');
+  });
+
+  it('return anonymous functions from JsFunction::new', function () {
+    assert.strictEqual(addon.return_js_function().name, '');
+  });
+
+  it('exports functions annotated with #[neon::export]', function () {
+    assert.strictEqual(addon.exported_function(), 'exported');
+    assert.strictEqual(addon.exported_function.name, 'exported_function');
+    assert.strictEqual(addon.nested_export(), true);
+  });
+
+  it('exports a function under the name given to #[neon::export]', function () {
+    assert.strictEqual(addon.exportedWithName(21), 42);
+    assert.strictEqual(addon.exportedWithName.name, 'exportedWithName');
+    assert.isUndefined(addon.exported_with_name);
+  });
+
+  it('lets #[neon::main] replace exports of #[neon::export]', function () {
+    assert.strictEqual(addon.overridden_export, 'main');
+  });
 });
@@ -0,0 +1,81 @@
+const addon = require('..');
+const assert = require('chai').assert;
+const { Worker } = require('worker_threads');
+
+// Runs `source` in a worker with the addon loaded and resolves with the messages
+// logged by cleanup hooks once it has exited
+function runWorker(source) {
+  return new Promise((resolve, reject) => {
+    const worker = new Worker(`
+      const { parentPort } = require('worker_threads');
+      const addon = require(${JSON.stringify(require.resolve('..'))});
+
+      ${source}
+    `, { eval: true });
+
+    worker.on('error', reject);
+    worker.on('exit', (code) => {
+      if (code !== 0) {
+        return reject(new Error(`Worker exited with code ${code}`));
+      }
+
+      resolve(addon.take_cleanup_log());
+    });
+  });
+}
+
+describe('cleanup hooks', function() {
+  it('calls hooks in reverse order when a worker exits', async function () {
+    const log = await runWorker(`
+      addon.on_cleanup_log('first');
+      addon.on_cleanup_log('second');
+    `);
+
+    assert.deepEqual(log, ['second', 'first']);
+  });
+
+  it('does not call a removed hook', async function () {
+    const log = await runWorker(`
+      addon.on_cleanup_log('kept');
+
+      if (!addon.on_cleanup_removed('removed')) {
+        throw new Error('Expected the hook to be removed');
+      }
+    `);
+
+    assert.deepEqual(log, ['kept']);
+  });
+
+  it('waits for async hooks before a worker exits', async function () {
+    const log = await runWorker(`
+      addon.on_cleanup_log('first');
+      addon.on_async_cleanup_log('async');
+      addon.on_cleanup_log('last');
+
+      if (!addon.on_async_cleanup_removed('removed')) {
+        throw new Error('Expected the hook to be removed');
+      }
+    `);
+
+    assert.deepEqual(log, ['last', 'first', 'async']);
+  });
+
+  it('terminates a worker with a pending async hook', async function () {
+    const log = await new Promise((resolve, reject) => {
+      const worker = new Worker(`
+        const { parentPort } = require('worker_threads');
+        const addon = require(${JSON.stringify(require.resolve('..'))});
+
+        addon.on_async_cleanup_log('terminated');
+        parentPort.postMessage('ready');
+        setInterval(() => {}, 1000);
+      `, { eval: true });
+
+      worker.on('message', () => worker.terminate());
+      worker.on('error', reject);
+      worker.on('exit', () => resolve(addon.take_cleanup_log()));
+    });
+
+    assert.deepEqual(log, ['terminated']);
+  });
+});
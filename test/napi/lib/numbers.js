@@ -47,4 +47,52 @@ describe('JsNumber', function() {
       assert.equal(addon.accept_and_return_negative_js_number(-55), -55);
     });
   });
+
+  describe('integers', function () {
+    it('should create numbers from integers', function () {
+      assert.strictEqual(addon.return_js_number_from_i32(), -2147483648);
+      assert.strictEqual(addon.return_js_number_from_u32(), 4294967295);
+      assert.strictEqual(addon.return_js_number_from_i64(), -9007199254740992);
+    });
+
+    it('should get exact integer values', function () {
+      assert.strictEqual(addon.number_value_i32(-2147483648), -2147483648);
+      assert.strictEqual(addon.number_value_i32(2147483647), 2147483647);
+      assert.strictEqual(addon.number_value_u32(4294967295), 4294967295);
+      assert.strictEqual(addon.number_value_i64(-9007199254740991), -9007199254740991);
+      assert.strictEqual(addon.number_value_i64(4294967296), 4294967296);
+    });
+
+    it('should throw a RangeError for non-integers', function () {
+      assert.throws(() => addon.number_value_i32(1.5), RangeError, /not an integer/);
+      assert.throws(() => addon.number_value_u32(NaN), RangeError, /not an integer/);
+      assert.throws(() => addon.number_value_i64(-0.1), RangeError, /not an integer/);
+    });
+
+    it('should throw a RangeError for integers out of range', function () {
+      assert.throws(() => addon.number_value_i32(2147483648), RangeError, /out of range for i32/);
+      assert.throws(() => addon.number_value_u32(-1), RangeError, /out of range for u32/);
+      assert.throws(() => addon.number_value_u32(4294967296), RangeError, /out of range for u32/);
+      assert.throws(() => addon.number_value_i64(2 ** 63), RangeError, /out of range for i64/);
+      assert.throws(() => addon.number_value_i64(Infinity), RangeError, /out of range for i64/);
+    });
+
+    it('should saturate integer values', function () {
+      assert.strictEqual(addon.number_value_i32_saturating(1e10), 2147483647);
+      assert.strictEqual(addon.number_value_i32_saturating(-1e10), -2147483648);
+      assert.strictEqual(addon.number_value_i32_saturating(-1.9), -1);
+      assert.strictEqual(addon.number_value_u32_saturating(-5), 0);
+      assert.strictEqual(addon.number_value_u32_saturating(NaN), 0);
+    });
+
+    it('should truncate integer values like ToInt32 and ToUint32', function () {
+      for (const n of [1.9, -1.9, 2147483648, 4294967297, -1, 1e20, NaN, Infinity]) {
+        assert.strictEqual(addon.number_value_i32_truncating(n), n | 0);
+        assert.strictEqual(addon.number_value_u32_truncating(n), n >>> 0);
+      }
+
+      assert.strictEqual(addon.number_value_i64_truncating(-4294967296.5), -4294967296);
+      assert.strictEqual(addon.number_value_i64_truncating(NaN), 0);
+    });
+  });
 });
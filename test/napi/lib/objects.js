@@ -155,6 +155,86 @@ describe('JsObject', function() {
     assert.equal(b.readUInt32LE(12), 66012);
   });
 
+  it('gets a zeroed TypedArray', function() {
+    var a = addon.return_typed_array();
+    assert.instanceOf(a, Uint16Array);
+    assert.deepEqual(Array.from(a), [0, 0, 0, 0]);
+  });
+
+  it('creates a TypedArray over an existing ArrayBuffer', function() {
+    var buf = new ArrayBuffer(16);
+    var a = addon.return_typed_array_from_buffer(buf, 4, 2);
+    assert.instanceOf(a, Uint32Array);
+    assert.strictEqual(a.buffer, buf);
+    assert.equal(a.byteOffset, 4);
+    assert.equal(a.length, 2);
+  });
+
+  it('throws when a TypedArray does not fit its ArrayBuffer', function() {
+    var buf = new ArrayBuffer(16);
+    assert.throws(() => addon.return_typed_array_from_buffer(buf, 2, 1), RangeError);
+    assert.throws(() => addon.return_typed_array_from_buffer(buf, 8, 4), RangeError);
+  });
+
+  it('correctly reads a TypedArray using the borrow API', function() {
+    var buf = new Float32Array([1, 2, 3, 4, 5]).buffer;
+    var a = new Float32Array(buf, 4, 3);
+    assert.deepEqual(addon.read_typed_array_with_borrow(a), [2, 3, 4]);
+  });
+
+  it('correctly writes to a TypedArray using the borrow_mut API', function() {
+    var whole = new Int32Array([1, 2, 3, 4]);
+    var a = new Int32Array(whole.buffer, 4, 2);
+    addon.increment_typed_array_with_borrow_mut(a);
+    assert.deepEqual(Array.from(whole), [1, 3, 4, 4]);
+  });
+
+  it('gets the offset, length and buffer of a TypedArray', function() {
+    var buf = new ArrayBuffer(8);
+    var info = addon.get_typed_array_info(new Uint8Array(buf, 2, 5));
+    assert.equal(info.length, 5);
+    assert.equal(info.offset, 2);
+    assert.strictEqual(info.buffer, buf);
+  });
+
+  it('checks the element type when downcasting a TypedArray', function() {
+    assert.throws(() => addon.read_typed_array_with_borrow(new Float64Array(2)), TypeError);
+    assert.throws(() => addon.get_typed_array_info(new Uint8ClampedArray(2)), TypeError);
+    assert.throws(() => addon.get_typed_array_info(new DataView(new ArrayBuffer(2))), TypeError);
+  });
+
+  it('creates a DataView over an existing ArrayBuffer', function() {
+    var buf = new ArrayBuffer(16);
+    var view = addon.return_data_view(buf, 3, 5);
+    assert.instanceOf(view, DataView);
+    assert.strictEqual(view.buffer, buf);
+    assert.equal(view.byteOffset, 3);
+    assert.equal(view.byteLength, 5);
+    assert.throws(() => addon.return_data_view(buf, 12, 8), RangeError);
+  });
+
+  it('correctly reads a DataView using the borrow API', function() {
+    var buf = new Uint8Array([1, 2, 3, 4, 5, 6]).buffer;
+    assert.deepEqual(addon.read_data_view_with_borrow(new DataView(buf, 2, 3)), [3, 4, 5]);
+  });
+
+  it('correctly writes to a DataView using the borrow_mut API', function() {
+    var buf = new ArrayBuffer(8);
+    var view = new DataView(buf, 4);
+    addon.write_data_view_with_borrow_mut(view, 0x1234);
+    assert.equal(view.getUint16(0), 0x1234);
+    assert.deepEqual(Array.from(new Uint8Array(buf)), [0, 0, 0, 0, 0x12, 0x34, 0, 0]);
+  });
+
+  it('gets the offset, length and buffer of a DataView', function() {
+    var buf = new ArrayBuffer(8);
+    var info = addon.get_data_view_info(new DataView(buf, 1, 6));
+    assert.equal(info.length, 6);
+    assert.equal(info.offset, 1);
+    assert.strictEqual(info.buffer, buf);
+    assert.throws(() => addon.get_data_view_info(new Uint8Array(buf)), TypeError);
+  });
+
   it('returns only own properties from get_own_property_names', function() {
     var superObject = {
       a: 1
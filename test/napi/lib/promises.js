@@ -0,0 +1,90 @@
+const addon = require('..');
+const assert = require('chai').assert;
+
+describe('JsPromise', function () {
+  it('should resolve a promise', async function () {
+    const promise = addon.resolve_promise(42);
+
+    assert.instanceOf(promise, Promise);
+    assert.strictEqual(await promise, 42);
+  });
+
+  it('should reject a promise', async function () {
+    const err = new Error('Oh, no!');
+
+    try {
+      await addon.reject_promise(err);
+      assert.fail('expected promise to reject');
+    } catch (e) {
+      assert.strictEqual(e, err);
+    }
+  });
+
+  it('should check whether a value is a promise', function () {
+    assert.isTrue(addon.is_promise(Promise.resolve()));
+    assert.isFalse(addon.is_promise({ then() {} }));
+    assert.isFalse(addon.is_promise(42));
+  });
+
+  it('should settle a promise from another thread', async function () {
+    assert.strictEqual(await addon.promise_thread(21), 42);
+  });
+
+  it('should reject when the settle closure throws', async function () {
+    try {
+      await addon.promise_thread_throw('Oh, no!');
+      assert.fail('expected promise to reject');
+    } catch (err) {
+      assert.instanceOf(err, Error);
+      assert.strictEqual(err.message, 'Oh, no!');
+    }
+  });
+
+  it('should reject a promise when the deferred is dropped', async function () {
+    try {
+      await addon.leak_deferred();
+      assert.fail('expected promise to reject');
+    } catch (err) {
+      assert.instanceOf(err, Error);
+      assert.match(err.message, /dropped without being settled/);
+    }
+  });
+});
+
+describe('async #[neon::export]', function () {
+  it('resolves a promise with the output', async function () {
+    const promise = addon.async_add(1, 2);
+
+    assert.instanceOf(promise, Promise);
+    assert.strictEqual(await promise, 3);
+    assert.strictEqual(addon.async_add.length, 2);
+  });
+
+  it('resolves a promise with the value of an Ok', async function () {
+    assert.strictEqual(await addon.async_parse('42'), 42);
+  });
+
+  it('rejects a promise with an Err', async function () {
+    try {
+      await addon.async_parse('forty-two');
+      assert.fail('expected promise to reject');
+    } catch (err) {
+      assert.instanceOf(err, Error);
+      assert.strictEqual(err.message, 'invalid digit found in string');
+    }
+  });
+
+  it('rejects a promise when the future panics', async function () {
+    try {
+      await addon.async_panic('oh no');
+      assert.fail('expected promise to reject');
+    } catch (err) {
+      assert.instanceOf(err, Error);
+      assert.match(err.message, /oh no/);
+    }
+  });
+
+  it('throws synchronously for invalid arguments', function () {
+    assert.throws(() => addon.async_add('1', 2), TypeError, /^argument 0: expected a number, found string$/);
+  });
+});
@@ -0,0 +1,59 @@
+const addon = require('..');
+const assert = require('chai').assert;
+
+describe('serde', function() {
+  it('converts structs, sequences and maps', function () {
+    const order = {
+      id: 7,
+      customer: { name: 'Ada', vip: true },
+      items: [['widget', 2, 9.5], ['gadget', 1, 20]],
+      notes: null,
+      tags: { color: 'red', size: 'large' }
+    };
+
+    assert.deepEqual(addon.serde_round_trip_order(order), order);
+  });
+
+  it('treats undefined and missing properties as null', function () {
+    const order = addon.serde_round_trip_order({
+      id: 7,
+      customer: { name: 'Ada', vip: false },
+      items: [],
+      tags: {}
+    });
+
+    assert.strictEqual(order.notes, null);
+  });
+
+  it('converts enums', function () {
+    const shapes = ['empty', { circle: 1 }, { line: [0, 1] }, { rectangle: { width: 2, height: 3 } }];
+
+    assert.deepEqual(addon.serde_round_trip_shapes(shapes), shapes);
+  });
+
+  it('converts bytes to and from buffers', function () {
+    const blob = addon.serde_reverse_blob({ data: Buffer.from([1, 2, 3]) });
+
+    assert.instanceOf(blob.data, Buffer);
+    assert.deepEqual([...blob.data], [3, 2, 1]);
+    assert.deepEqual([...addon.serde_reverse_blob({ data: new Uint8Array([4, 5]) }).data], [5, 4]);
+    assert.deepEqual([...addon.serde_reverse_blob({ data: new Uint8Array([6, 7]).buffer }).data], [7, 6]);
+  });
+
+  it('converts large integers to and from bigints', function () {
+    assert.deepEqual(addon.serde_large_integers(), [2n ** 64n - 1n, -(2n ** 63n), 2n ** 53n, -(2 ** 53) + 1]);
+    assert.deepEqual(addon.serde_round_trip_integers([1, -2n, 2n ** 100n]), [1, -2, 2n ** 100n]);
+  });
+
+  it('throws a TypeError for values of the wrong shape', function () {
+    assert.throws(() => addon.serde_round_trip_order({ id: 'seven' }), TypeError, /invalid type: string "seven", expected u64/);
+    assert.throws(() => addon.serde_round_trip_order({ id: 1.5 }), TypeError, /expected u64/);
+    assert.throws(() => addon.serde_round_trip_shapes(['square']), TypeError, /unknown variant `square`/);
+    assert.throws(() => addon.serde_round_trip_shapes([{ circle: 1, line: [] }]), TypeError, /an object with a single key/);
+    assert.throws(() => addon.serde_round_trip_order({ id: 1, customer: () => {} }), TypeError, /function/);
+  });
+
+  it('throws an Error for values that cannot be converted', function () {
+    assert.throws(() => addon.serde_invalid_keys(), Error, /map keys must be strings or numbers/);
+  });
+});
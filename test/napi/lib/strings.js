@@ -5,6 +5,65 @@ describe('JsString', function() {
   it('should return a JsString built in Rust', function () {
     assert.equal(addon.return_js_string(), "hello node");
   });
+  describe('UTF-8 buffers', function () {
+    it('should write a string into a buffer', function () {
+      assert.strictEqual(addon.string_write_utf8('hello', 16), 'hello');
+      assert.strictEqual(addon.string_write_utf8('hello', 6), 'hello');
+      assert.strictEqual(addon.string_write_utf8('', 1), '');
+    });
+    it('should truncate on a character boundary', function () {
+      assert.strictEqual(addon.string_write_utf8('hello', 3), 'he');
+      assert.strictEqual(addon.string_write_utf8('a🦀b', 5), 'a');
+      assert.strictEqual(addon.string_write_utf8('a🦀b', 6), 'a🦀');
+      assert.strictEqual(addon.string_write_utf8('hello', 0), '');
+    });
+    it('should read strings into a reused buffer', function () {
+      const strings = ['a much longer string than the rest', 'short', '', 'héllo 🦀', 'x'.repeat(1000)];
+
+      assert.deepEqual(addon.string_value_into(strings), strings);
+    });
+    it('should read short and long strings into a small vector', function () {
+      for (const s of ['', 'abc', 'abcdef', 'abcdefg', 'abcdefgh', '🦀🦀', 'a much longer string', 'é'.repeat(100)]) {
+        assert.strictEqual(addon.string_value_small(s), s);
+      }
+    });
+  });
+  describe('UTF-16', function () {
+    const codeUnits = (s) => Array.from({ length: s.length }, (_, i) => s.charCodeAt(i));
+
+    it('should create a string from UTF-16 code units', function () {
+      assert.strictEqual(addon.string_from_utf16(codeUnits('héllo 🦀')), 'héllo 🦀');
+    });
+    it('should read a string as UTF-16 code units', function () {
+      assert.deepEqual(addon.string_value_utf16('héllo 🦀'), codeUnits('héllo 🦀'));
+      assert.deepEqual(addon.string_value_utf16(''), []);
+    });
+    it('should preserve lone surrogates', function () {
+      const lone = 'a\ud83eb\udd80';
+
+      assert.deepEqual(addon.string_value_utf16(lone), [0x61, 0xd83e, 0x62, 0xdd80]);
+      assert.strictEqual(addon.string_from_utf16([0x61, 0xd83e, 0x62, 0xdd80]), lone);
+    });
+    it('should write UTF-16 code units into a buffer', function () {
+      assert.deepEqual(addon.string_write_utf16('hello', 16), codeUnits('hello'));
+      assert.deepEqual(addon.string_write_utf16('hello', 3), codeUnits('he'));
+      assert.deepEqual(addon.string_write_utf16('hello', 0), []);
+    });
+    it('should get the length in UTF-16 code units', function () {
+      assert.strictEqual(addon.string_size_utf16('🦀'), 2);
+    });
+  });
+  describe('Latin-1', function () {
+    it('should create a string from Latin-1 bytes', function () {
+      assert.strictEqual(addon.string_from_latin1([0x63, 0x61, 0x66, 0xe9]), 'café');
+    });
+    it('should read a string as Latin-1 bytes', function () {
+      assert.deepEqual(addon.string_value_latin1('café'), [0x63, 0x61, 0x66, 0xe9]);
+    });
+    it('should write Latin-1 bytes into a buffer', function () {
+      assert.deepEqual(addon.string_write_latin1('café', 3), [0x63, 0x61]);
+    });
+  });
   describe('run_as_script', function () {
     it('should return the evaluated value', function () {
       assert.equal(addon.run_string_as_script('6 * 7'), 42);
@@ -0,0 +1,61 @@
+const addon = require('..');
+const assert = require('chai').assert;
+
+describe('JsSymbol', function () {
+  it('should create a symbol with a description', function () {
+    const symbol = addon.create_symbol('neon:description');
+
+    assert.typeOf(symbol, 'symbol');
+    assert.strictEqual(symbol.description, 'neon:description');
+    assert.notStrictEqual(symbol, addon.create_symbol('neon:description'));
+  });
+
+  it('should create a symbol without a description', function () {
+    const symbol = addon.create_symbol();
+
+    assert.typeOf(symbol, 'symbol');
+    assert.isUndefined(symbol.description);
+  });
+
+  it('should get the description of a symbol', function () {
+    assert.strictEqual(addon.symbol_description(Symbol('foo')), 'foo');
+    assert.strictEqual(addon.symbol_description(Symbol('')), '');
+    assert.isUndefined(addon.symbol_description(Symbol()));
+  });
+
+  it('should look up symbols in the global registry', function () {
+    assert.strictEqual(addon.symbol_for('neon:registry'), Symbol.for('neon:registry'));
+  });
+
+  it('should get well-known symbols', function () {
+    const symbols = addon.well_known_symbols();
+
+    assert.strictEqual(symbols.iterator, Symbol.iterator);
+    assert.strictEqual(symbols.asyncIterator, Symbol.asyncIterator);
+    assert.strictEqual(symbols.toStringTag, Symbol.toStringTag);
+  });
+
+  it('should get symbol-keyed properties', function () {
+    const key = Symbol('key');
+
+    assert.strictEqual(addon.get_symbol_property({ [key]: 42 }, key), 42);
+    assert.isUndefined(addon.get_symbol_property({ key: 42 }, key));
+  });
+
+  it('should set symbol-keyed properties', function () {
+    const array = [1, 2, 3];
+    const obj = addon.tagged_iterable(array);
+
+    assert.strictEqual(obj, array);
+    assert.strictEqual(Object.prototype.toString.call(obj), '[object Counter]');
+    assert.deepEqual([...obj], [1, 2, 3]);
+    assert.deepEqual(Object.keys(obj), ['0', '1', '2']);
+    assert.lengthOf(Object.getOwnPropertySymbols(obj), 3);
+  });
+
+  it('should check if a value is a symbol', function () {
+    assert.isTrue(addon.is_symbol(Symbol()));
+    assert.isFalse(addon.is_symbol('symbol'));
+    assert.isFalse(addon.is_symbol({}));
+  });
+});
@@ -0,0 +1,205 @@
+const addon = require('..');
+const assert = require('chai').assert;
+
+describe('Task', function() {
+  it('completes a successful task', function (done) {
+    addon.perform_async_task((err, n) => {
+      if (err) {
+        done(err);
+      } else if (n === 17) {
+        done();
+      } else {
+        done(new Error("not 17 but: " + n));
+      }
+    });
+  });
+
+  it('completes a failing task', function (done) {
+    addon.perform_failing_task((err, n) => {
+      if (err) {
+        if (err.message === 'I am a failing task') {
+          done();
+        } else {
+          done(new Error("expected error message 'I am a failing task', got: " + err.message));
+        }
+      } else {
+        done(new Error("expected task to fail, got: " + n));
+      }
+    });
+  });
+
+  it('passes a panic in perform to the callback', function (done) {
+    addon.perform_panicking_task((err, value) => {
+      try {
+        assert.instanceOf(err, Error);
+        assert.include(err.message, 'I am a panicking task');
+        assert.strictEqual(value, undefined);
+        done();
+      } catch (err) {
+        done(err);
+      }
+    });
+  });
+
+  it('performs the task off of the JavaScript thread', function (done) {
+    addon.perform_thread_task((err, offThread) => {
+      try {
+        assert.isNull(err);
+        assert.isTrue(offThread);
+        done();
+      } catch (err) {
+        done(err);
+      }
+    });
+  });
+
+  it('executes microtasks after callback', function () {
+    return new Promise((resolve, reject) => {
+      addon.perform_async_task((err, res) => {
+        if (err) {
+          reject(err);
+        } else {
+          resolve(res);
+        }
+      });
+    });
+  });
+});
+
+describe('TaskBuilder', function() {
+  it('resolves a promise with the completion', async function () {
+    assert.strictEqual(await addon.fibonacci_promise(20), 6765);
+  });
+
+  it('rejects a promise when the completion throws', async function () {
+    try {
+      await addon.task_reject_promise('Rejected by a task');
+    } catch (err) {
+      assert.instanceOf(err, Error);
+      assert.strictEqual(err.message, 'Rejected by a task');
+      return;
+    }
+
+    throw new Error('Expected the promise to reject');
+  });
+
+  it('rejects a promise when the task panics', async function () {
+    try {
+      await addon.task_panic_promise();
+    } catch (err) {
+      assert.instanceOf(err, Error);
+      assert.include(err.message, 'I am a panicking closure');
+      return;
+    }
+
+    throw new Error('Expected the promise to reject');
+  });
+
+  it('calls a callback with the output', function (done) {
+    addon.fibonacci_and_then(20, (err, n) => {
+      try {
+        assert.isNull(err);
+        assert.strictEqual(n, 6765);
+        done();
+      } catch (err) {
+        done(err);
+      }
+    });
+  });
+});
+
+describe('CancellationToken', function() {
+  async function assertAborted(promise) {
+    try {
+      await promise;
+    } catch (err) {
+      assert.instanceOf(err, Error);
+      assert.strictEqual(err.name, 'AbortError');
+      assert.strictEqual(err.code, 'ABORT_ERR');
+      return;
+    }
+
+    throw new Error('Expected the promise to reject');
+  }
+
+  it('completes a task that is not aborted', async function () {
+    const controller = new AbortController();
+
+    assert.strictEqual(await addon.sleep_cancellable(5, controller.signal), 5);
+  });
+
+  it('rejects a running task that is aborted', async function () {
+    const controller = new AbortController();
+    const promise = addon.sleep_cancellable(10000, controller.signal);
+
+    setTimeout(() => controller.abort(), 20);
+
+    await assertAborted(promise);
+  });
+
+  it('rejects a task with a signal that is already aborted', async function () {
+    const controller = new AbortController();
+
+    controller.abort();
+
+    await assertAborted(addon.sleep_cancellable(10000, controller.signal));
+    assert.isFalse(addon.sleep_started());
+  });
+
+  it('removes a queued task that is aborted', async function () {
+    const controller = new AbortController();
+    // Occupy every thread of the libuv thread pool
+    const size = Number(process.env.UV_THREADPOOL_SIZE || 4);
+    const blocked = Array.from({ length: size }, () => addon.block_thread_pool(100));
+    const promise = addon.sleep_cancellable(10000, controller.signal);
+
+    controller.abort();
+
+    await assertAborted(promise);
+    assert.isFalse(addon.sleep_started());
+    await Promise.all(blocked);
+  });
+
+  function sleepTask(ms, signal) {
+    return new Promise((resolve, reject) => {
+      addon.sleep_task_cancellable(ms, signal, (err, slept) => err ? reject(err) : resolve(slept));
+    });
+  }
+
+  it('completes a Task that is not aborted', async function () {
+    const controller = new AbortController();
+
+    assert.strictEqual(await sleepTask(5, controller.signal), 5);
+  });
+
+  it('calls the callback of a running Task that is aborted with an AbortError', async function () {
+    const controller = new AbortController();
+    const promise = sleepTask(10000, controller.signal);
+
+    setTimeout(() => controller.abort(), 20);
+
+    await assertAborted(promise);
+  });
+
+  it('removes a queued Task that is aborted', async function () {
+    const controller = new AbortController();
+    const size = Number(process.env.UV_THREADPOOL_SIZE || 4);
+    const blocked = Array.from({ length: size }, () => addon.block_thread_pool(100));
+    const promise = sleepTask(10000, controller.signal);
+
+    controller.abort();
+
+    await assertAborted(promise);
+    assert.isFalse(addon.sleep_started());
+    await Promise.all(blocked);
+  });
+
+  it('does not run a Task with a signal that is already aborted', async function () {
+    const controller = new AbortController();
+
+    controller.abort();
+
+    await assertAborted(sleepTask(10000, controller.signal));
+    assert.isFalse(addon.sleep_started());
+  });
+});
@@ -0,0 +1,84 @@
+use std::thread;
+
+use neon::event::AsyncResource;
+use neon::prelude::*;
+
+#[neon::export]
+fn async_resource_new(mut cx: FunctionContext) -> JsResult<JsBox<AsyncResource>> {
+    let name = cx.argument::<JsString>(0)?.value(&mut cx);
+    let resource = AsyncResource::new(&mut cx, &name);
+
+    Ok(cx.boxed(resource))
+}
+
+#[neon::export]
+fn async_resource_call(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let resource = cx.argument::<JsBox<AsyncResource>>(0)?;
+    let callback = cx.argument::<JsFunction>(1)?;
+    let this = cx.global();
+    let arg = cx.number(42);
+
+    resource.call(&mut cx, callback, this, vec![arg])
+}
+
+#[neon::export]
+fn async_resource_enter(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let resource = cx.argument::<JsBox<AsyncResource>>(0)?;
+    let callback = cx.argument::<JsFunction>(1)?;
+
+    resource.enter(&mut cx, |cx| {
+        let this = cx.undefined();
+
+        callback.call(cx, this, Vec::<Handle<JsValue>>::new())
+    })
+}
+
+#[neon::export]
+fn async_resource_destroy(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let name = cx.argument::<JsString>(0)?.value(&mut cx);
+
+    AsyncResource::new(&mut cx, &name).destroy(&mut cx);
+
+    Ok(cx.undefined())
+}
+
+#[neon::export]
+fn channel_with_async_context(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let callback = cx.argument::<JsFunction>(0)?.root(&mut cx);
+    let mut channel = cx.channel();
+
+    channel.capture_async_context(&mut cx, "neon:channel_test");
+
+    thread::spawn(move || {
+        channel.send(move |mut cx| {
+            let callback = callback.into_inner(&mut cx);
+            let this = cx.undefined();
+
+            callback.call(&mut cx, this, Vec::<Handle<JsValue>>::new())?;
+
+            Ok(())
+        });
+    });
+
+    Ok(cx.undefined())
+}
+
+#[neon::export]
+fn settle_with_async_context(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let callback = cx.argument::<JsFunction>(0)?.root(&mut cx);
+    let (deferred, promise) = cx.promise();
+    let mut channel = cx.channel();
+
+    channel.capture_async_context(&mut cx, "neon:settle_test");
+
+    thread::spawn(move || {
+        deferred.settle_with(&channel, move |mut cx| {
+            let callback = callback.into_inner(&mut cx);
+            let this = cx.undefined();
+
+            callback.call(&mut cx, this, Vec::<Handle<JsValue>>::new())
+        });
+    });
+
+    Ok(promise)
+}
@@ -0,0 +1,80 @@
+use neon::prelude::*;
+use neon::types::{JsBigInt, Sign};
+
+pub fn bigint_from_i64(mut cx: FunctionContext) -> JsResult<JsBigInt> {
+    Ok(JsBigInt::from_i64(&mut cx, i64::MIN))
+}
+
+pub fn bigint_from_u64(mut cx: FunctionContext) -> JsResult<JsBigInt> {
+    Ok(JsBigInt::from_u64(&mut cx, u64::MAX))
+}
+
+pub fn bigint_from_i128(mut cx: FunctionContext) -> JsResult<JsBigInt> {
+    Ok(JsBigInt::from_i128(&mut cx, i128::MIN))
+}
+
+pub fn bigint_from_u128(mut cx: FunctionContext) -> JsResult<JsBigInt> {
+    Ok(JsBigInt::from_u128(&mut cx, u128::MAX))
+}
+
+pub fn bigint_from_digits_le(mut cx: FunctionContext) -> JsResult<JsBigInt> {
+    JsBigInt::from_digits_le(&mut cx, Sign::Negative, &[1, 2, 3])
+}
+
+pub fn bigint_to_i64(mut cx: FunctionContext) -> JsResult<JsString> {
+    let n = cx.argument::<JsBigInt>(0)?;
+    let n = n
+        .to_i64(&mut cx)
+        .or_else(|err| cx.throw_range_error(err.to_string()))?;
+
+    Ok(cx.string(n.to_string()))
+}
+
+pub fn bigint_to_u64(mut cx: FunctionContext) -> JsResult<JsString> {
+    let n = cx.argument::<JsBigInt>(0)?;
+    let n = n
+        .to_u64(&mut cx)
+        .or_else(|err| cx.throw_range_error(err.to_string()))?;
+
+    Ok(cx.string(n.to_string()))
+}
+
+pub fn bigint_to_i128(mut cx: FunctionContext) -> JsResult<JsString> {
+    let n = cx.argument::<JsBigInt>(0)?;
+    let n = n
+        .to_i128(&mut cx)
+        .or_else(|err| cx.throw_range_error(err.to_string()))?;
+
+    Ok(cx.string(n.to_string()))
+}
+
+pub fn bigint_to_u128(mut cx: FunctionContext) -> JsResult<JsString> {
+    let n = cx.argument::<JsBigInt>(0)?;
+    let n = n
+        .to_u128(&mut cx)
+        .or_else(|err| cx.throw_range_error(err.to_string()))?;
+
+    Ok(cx.string(n.to_string()))
+}
+
+pub fn bigint_to_digits_le(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let n = cx.argument::<JsBigInt>(0)?;
+    let (sign, digits) = n.to_digits_le(&mut cx);
+    let arr = cx.empty_array();
+    let sign = cx.number(if sign == Sign::Negative { -1 } else { 1 });
+
+    arr.set(&mut cx, 0, sign)?;
+
+    for (i, digit) in digits.into_iter().enumerate() {
+        let digit = cx.string(digit.to_string());
+        arr.set(&mut cx, i as u32 + 1, digit)?;
+    }
+
+    Ok(arr)
+}
+
+pub fn is_bigint(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let val: Handle<JsValue> = cx.argument(0)?;
+    let result = val.is_a::<JsBigInt, _>(&mut cx);
+    Ok(cx.boolean(result))
+}
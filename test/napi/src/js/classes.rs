@@ -0,0 +1,216 @@
+use neon::prelude::*;
+
+pub struct User {
+    id: i32,
+    first_name: String,
+    last_name: String,
+    email: String,
+}
+
+pub struct Counter {
+    count: f64,
+}
+
+type Unit = ();
+
+declare_types! {
+    pub class JsPanickyAllocator for Unit {
+        init(_) {
+            panic!("allocator panicking")
+        }
+    }
+
+    pub class JsPanickyConstructor for Unit {
+        init(_) {
+            Ok(())
+        }
+
+        call(_) {
+            panic!("constructor call panicking")
+        }
+
+        constructor(_) {
+            panic!("constructor panicking")
+        }
+    }
+
+    pub class JsUser for User {
+        init(mut cx) {
+            let id = cx.argument::<JsNumber>(0)?;
+            let first_name = cx.argument::<JsString>(1)?;
+            let last_name = cx.argument::<JsString>(2)?;
+            let email = cx.argument::<JsString>(3)?;
+
+            Ok(User {
+                id: id.value(&mut cx) as i32,
+                first_name: first_name.value(&mut cx),
+                last_name: last_name.value(&mut cx),
+                email: email.value(&mut cx),
+            })
+        }
+
+        method get(mut cx) {
+            let attr = cx.argument::<JsString>(0)?.value(&mut cx);
+            let this = cx.this();
+            let (id, first_name, last_name, email) = {
+                let guard = cx.lock();
+                let user = this.borrow(&guard);
+                (user.id, user.first_name.clone(), user.last_name.clone(), user.email.clone())
+            };
+
+            match &attr[..] {
+                "id" => Ok(cx.number(id).upcast()),
+                "first_name" => Ok(cx.string(first_name).upcast()),
+                "last_name" => Ok(cx.string(last_name).upcast()),
+                "email" => Ok(cx.string(email).upcast()),
+                _ => cx.throw_type_error("property does not exist"),
+            }
+        }
+
+        method panic(_) {
+            panic!("User.prototype.panic")
+        }
+    }
+
+    pub class JsCounter for Counter {
+        init(mut cx) {
+            let count = match cx.argument_opt(0) {
+                Some(arg) => arg.downcast_or_throw::<JsNumber, _>(&mut cx)?.value(&mut cx),
+                None => 0.0,
+            };
+
+            Ok(Counter { count })
+        }
+
+        call(mut cx) {
+            let counter = JsCounter::new::<_, JsValue, _>(&mut cx, vec![])?;
+            Ok(counter.upcast())
+        }
+
+        method increment(mut cx) {
+            let mut this = cx.this();
+            let count = {
+                let guard = cx.lock();
+                let mut counter = this.borrow_mut(&guard);
+                counter.count += 1.0;
+                counter.count
+            };
+
+            Ok(cx.number(count).upcast())
+        }
+    }
+
+    pub class JsValidated for Unit {
+        init(_) {
+            Ok(())
+        }
+
+        constructor(mut cx) {
+            let valid = cx.argument::<JsBoolean>(0)?.value(&mut cx);
+
+            if !valid {
+                return cx.throw_range_error("invalid");
+            }
+
+            Ok(None)
+        }
+    }
+}
+
+pub fn counter_value(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let counter = cx.argument::<JsCounter>(0)?;
+    let count = {
+        let guard = cx.lock();
+        let counter = counter.borrow(&guard);
+        counter.count
+    };
+
+    Ok(cx.number(count))
+}
+
+pub fn is_counter(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let value = cx.argument::<JsValue>(0)?;
+    let is_counter = value.is_a::<JsCounter, _>(&mut cx);
+
+    Ok(cx.boolean(is_counter))
+}
+
+#[neon::class]
+pub struct Account {
+    owner: String,
+    balance: f64,
+}
+
+#[neon::class]
+impl Account {
+    #[neon(constructor)]
+    fn new(mut cx: FunctionContext) -> NeonResult<Self> {
+        let owner = cx.argument::<JsString>(0)?.value(&mut cx);
+
+        Ok(Account {
+            owner,
+            balance: 0.0,
+        })
+    }
+
+    fn deposit<'a>(&mut self, mut cx: FunctionContext<'a>) -> JsResult<'a, JsNumber> {
+        let amount = cx.argument::<JsNumber>(0)?.value(&mut cx);
+
+        if amount <= 0.0 {
+            return cx.throw_range_error("amount must be positive");
+        }
+
+        self.balance += amount;
+
+        Ok(cx.number(self.balance))
+    }
+
+    /// Calls `f` while the account is mutably borrowed.
+    #[neon(name = "withLock")]
+    fn with_lock<'a>(&mut self, mut cx: FunctionContext<'a>) -> JsResult<'a, JsValue> {
+        let f = cx.argument::<JsFunction>(0)?;
+        let this = cx.this();
+        let args: Vec<Handle<JsValue>> = vec![];
+
+        f.call(&mut cx, this, args)
+    }
+
+    #[neon(getter)]
+    fn owner<'a>(&self, mut cx: FunctionContext<'a>) -> JsResult<'a, JsString> {
+        Ok(cx.string(&self.owner))
+    }
+
+    #[neon(getter)]
+    fn balance<'a>(&self, mut cx: FunctionContext<'a>) -> JsResult<'a, JsNumber> {
+        Ok(cx.number(self.rounded_balance()))
+    }
+
+    #[neon(setter)]
+    fn set_owner<'a>(
+        &mut self,
+        mut cx: FunctionContext<'a>,
+        owner: Handle<'a, JsString>,
+    ) -> NeonResult<()> {
+        self.owner = owner.value(&mut cx);
+        Ok(())
+    }
+
+    #[neon(name = "withBalance")]
+    fn with_balance(mut cx: FunctionContext) -> JsResult<JsAccount> {
+        let owner = cx.argument::<JsString>(0)?;
+        let amount = cx.argument::<JsNumber>(1)?;
+        let account = JsAccount::new(&mut cx, vec![owner])?;
+        let deposit = account
+            .get(&mut cx, "deposit")?
+            .downcast_or_throw::<JsFunction, _>(&mut cx)?;
+
+        deposit.call(&mut cx, account, vec![amount])?;
+
+        Ok(account)
+    }
+
+    #[neon(skip)]
+    fn rounded_balance(&self) -> f64 {
+        (self.balance * 100.0).round() / 100.0
+    }
+}
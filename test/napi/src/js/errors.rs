@@ -1,4 +1,5 @@
 use neon::prelude::*;
+use neon::types::{ErrorInfo, ErrorKind};
 
 pub fn new_error(mut cx: FunctionContext) -> JsResult<JsError> {
     let msg = cx.argument::<JsString>(0)?.value(&mut cx);
@@ -32,3 +33,100 @@ pub fn downcast_error(mut cx: FunctionContext) -> JsResult<JsString> {
         panic!()
     }
 }
+
+pub fn new_syntax_error(mut cx: FunctionContext) -> JsResult<JsError> {
+    let msg = cx.argument::<JsString>(0)?.value(&mut cx);
+
+    cx.syntax_error(msg)
+}
+
+fn error_kind(cx: &mut FunctionContext, kind: &str) -> NeonResult<ErrorKind> {
+    match kind {
+        "Error" => Ok(ErrorKind::Error),
+        "TypeError" => Ok(ErrorKind::TypeError),
+        "RangeError" => Ok(ErrorKind::RangeError),
+        "SyntaxError" => Ok(ErrorKind::SyntaxError),
+        _ => cx.throw_type_error(format!("unknown error kind: {}", kind)),
+    }
+}
+
+pub fn new_error_with_code(mut cx: FunctionContext) -> JsResult<JsError> {
+    let kind = cx.argument::<JsString>(0)?.value(&mut cx);
+    let kind = error_kind(&mut cx, &kind)?;
+    let code = cx.argument::<JsString>(1)?.value(&mut cx);
+    let msg = cx.argument::<JsString>(2)?.value(&mut cx);
+
+    JsError::with_code(&mut cx, kind, code, msg)
+}
+
+pub fn throw_error_with_code(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let code = cx.argument::<JsString>(0)?.value(&mut cx);
+    let msg = cx.argument::<JsString>(1)?.value(&mut cx);
+
+    cx.throw_error_with_code(ErrorKind::TypeError, code, msg)
+}
+
+pub fn throw_error_with_properties(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let path = cx.argument::<JsString>(0)?;
+    let err = cx.error_with_code(ErrorKind::Error, "ENOENT", "no such file or directory")?;
+    let errno = cx.number(-2);
+    let syscall = cx.string("open");
+
+    err.set_properties(
+        &mut cx,
+        vec![
+            ("errno", errno.upcast::<JsValue>()),
+            ("syscall", syscall.upcast()),
+            ("path", path.upcast()),
+        ],
+    )?;
+
+    cx.throw(err)
+}
+
+fn error_info_to_object<'a>(
+    cx: &mut FunctionContext<'a>,
+    info: &ErrorInfo,
+) -> JsResult<'a, JsObject> {
+    let obj = cx.empty_object();
+    let message = cx.string(info.message());
+    let display = cx.string(info.to_string());
+
+    obj.set(cx, "message", message)?;
+    obj.set(cx, "display", display)?;
+
+    for (key, value) in [
+        ("name", info.name()),
+        ("stack", info.stack()),
+        ("code", info.code()),
+    ] {
+        if let Some(value) = value {
+            let value = cx.string(value);
+            obj.set(cx, key, value)?;
+        }
+    }
+
+    if let Some(cause) = info.cause() {
+        let cause = error_info_to_object(cx, cause)?;
+        obj.set(cx, "cause", cause)?;
+    }
+
+    Ok(obj)
+}
+
+pub fn capture_error_info(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let value = cx.argument::<JsValue>(0)?;
+    let info = ErrorInfo::from_value(&mut cx, value)?;
+
+    // `ErrorInfo` can be moved to and from another thread
+    let info = std::thread::spawn(move || info).join().unwrap();
+
+    error_info_to_object(&mut cx, &info)
+}
+
+pub fn rethrow_error_info(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let value = cx.argument::<JsValue>(0)?;
+    let info = ErrorInfo::from_value(&mut cx, value)?;
+
+    info.throw(&mut cx)
+}
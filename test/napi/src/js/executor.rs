@@ -0,0 +1,86 @@
+use std::thread;
+use std::time::Duration;
+
+use neon::executor::{self, BoxFuture, Executor};
+use neon::prelude::*;
+
+/// An executor that is never used because the runtime already exists when it is installed
+struct Unused;
+
+impl Executor for Unused {
+    fn spawn(&self, _future: BoxFuture) {
+        unreachable!("Unused executor should not be installed");
+    }
+}
+
+#[neon::export]
+fn spawn_add(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let a = cx.argument::<JsNumber>(0)?.value(&mut cx);
+    let b = cx.argument::<JsNumber>(1)?.value(&mut cx);
+    let callback = cx.argument::<JsFunction>(2)?.root(&mut cx);
+    let channel = cx.channel();
+
+    cx.spawn(async move {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        channel.send(move |mut cx| {
+            let callback = callback.into_inner(&mut cx);
+            let this = cx.undefined();
+            let args = vec![cx.null().upcast::<JsValue>(), cx.number(a + b).upcast()];
+
+            callback.call(&mut cx, this, args)?;
+
+            Ok(())
+        });
+    });
+
+    Ok(cx.undefined())
+}
+
+#[neon::export]
+fn runtime_thread_name(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let callback = cx.argument::<JsFunction>(0)?.root(&mut cx);
+    let channel = cx.channel();
+
+    cx.spawn(async move {
+        let name = thread::current().name().map(String::from);
+
+        channel.send(move |mut cx| {
+            let callback = callback.into_inner(&mut cx);
+            let this = cx.undefined();
+            let name = match name {
+                Some(name) => cx.string(name).upcast::<JsValue>(),
+                None => cx.undefined().upcast(),
+            };
+            let args = vec![cx.null().upcast::<JsValue>(), name];
+
+            callback.call(&mut cx, this, args)?;
+
+            Ok(())
+        });
+    });
+
+    Ok(cx.undefined())
+}
+
+#[neon::export]
+fn set_custom_runtime(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(1)
+        .thread_name("custom-runtime")
+        .enable_all()
+        .build()
+        .or_else(|err| cx.throw_error(err.to_string()))?;
+
+    executor::set_runtime(&mut cx, runtime)?;
+
+    Ok(cx.undefined())
+}
+
+#[neon::export]
+fn set_runtime_after_use(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    cx.runtime();
+    executor::set_runtime(&mut cx, Unused)?;
+
+    Ok(cx.undefined())
+}
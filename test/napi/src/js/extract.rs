@@ -0,0 +1,200 @@
+use std::collections::HashMap;
+
+use neon::prelude::*;
+use neon::{FromJs, IntoJs};
+
+pub fn extract_values(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let flag: bool = cx.arg(0)?;
+    let text: String = cx.arg(1)?;
+    let float: f64 = cx.arg(2)?;
+    let integer: u32 = cx.arg(3)?;
+    let optional: Option<i32> = cx.arg(4)?;
+
+    (flag, text, float, integer, optional).try_into_js(&mut cx)
+}
+
+pub fn extract_from_value(mut cx: FunctionContext) -> JsResult<JsString> {
+    let value = cx.argument::<JsValue>(0)?;
+    let values = Vec::<i64>::from_js(&mut cx, value)?;
+    let text = values
+        .iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+
+    Ok(cx.string(text))
+}
+
+#[neon::export]
+fn sum(values: Vec<f64>) -> f64 {
+    values.into_iter().sum()
+}
+
+#[neon::export]
+fn swap_pair(pair: (String, f64)) -> (f64, String) {
+    (pair.1, pair.0)
+}
+
+#[neon::export]
+fn total_scores(scores: HashMap<String, Vec<u32>>) -> HashMap<String, u32> {
+    scores
+        .into_iter()
+        .map(|(name, scores)| (name, scores.into_iter().sum()))
+        .collect()
+}
+
+#[neon::export]
+fn reverse_bytes(bytes: Box<[u8]>) -> Box<[u8]> {
+    let mut bytes = bytes.into_vec();
+
+    bytes.reverse();
+    bytes.into_boxed_slice()
+}
+
+#[neon::export]
+fn optional_greeting(name: Option<String>) -> Option<String> {
+    name.map(|name| format!("Hello, {}!", name))
+}
+
+#[neon::export]
+fn checked_add_u8<'a>(cx: &mut FunctionContext<'a>, a: u8, b: u8) -> NeonResult<u8> {
+    match a.checked_add(b) {
+        Some(sum) => Ok(sum),
+        None => cx.throw_range_error("overflow"),
+    }
+}
+
+#[neon::export]
+fn unit() {}
+
+pub struct Named {
+    name: String,
+}
+
+impl Finalize for Named {}
+
+#[neon::export]
+fn create_named<'a>(cx: &mut FunctionContext<'a>, name: String) -> Handle<'a, JsBox<Named>> {
+    cx.boxed(Named { name })
+}
+
+#[neon::export]
+fn named_name(named: Handle<JsBox<Named>>) -> String {
+    named.name.clone()
+}
+
+#[neon::export]
+fn shout<'a>(cx: &mut FunctionContext<'a>, s: Handle<JsString>) -> JsResult<'a, JsString> {
+    let s = s.value(cx).to_uppercase();
+    Ok(cx.string(s))
+}
+
+#[neon::export]
+fn array_length(
+    cx: &mut FunctionContext,
+    array: Handle<JsArray>,
+    _buffer: Handle<JsBuffer>,
+) -> u32 {
+    array.len(cx)
+}
+
+#[derive(FromJs, IntoJs)]
+pub struct Options {
+    name: String,
+    #[neon(name = "retries")]
+    retry: Retry,
+    label: Option<String>,
+    #[neon(default)]
+    verbose: bool,
+    #[neon(skip)]
+    calls: u32,
+}
+
+#[derive(FromJs, IntoJs)]
+pub struct Retry {
+    max_attempts: u32,
+    #[neon(default = "default_delay")]
+    delay_ms: f64,
+}
+
+fn default_delay() -> f64 {
+    100.0
+}
+
+#[neon::export]
+fn normalize_options(mut options: Options) -> Options {
+    options.calls += 1;
+    options
+}
+
+#[derive(FromJs, IntoJs)]
+#[neon(rename_all = "snake_case")]
+pub struct Point(f64, f64);
+
+#[derive(FromJs, IntoJs)]
+pub struct Meters(f64);
+
+#[neon::export]
+fn midpoint(a: Point, b: Point) -> Point {
+    Point((a.0 + b.0) / 2.0, (a.1 + b.1) / 2.0)
+}
+
+#[neon::export]
+fn double_meters(meters: Meters) -> Meters {
+    Meters(meters.0 * 2.0)
+}
+
+#[derive(FromJs, IntoJs)]
+pub enum Shape {
+    Empty,
+    Circle(f64),
+    Rectangle {
+        width: f64,
+        height: f64,
+    },
+    #[neon(name = "segment")]
+    Line(Point, Point),
+}
+
+#[derive(FromJs, IntoJs)]
+#[neon(tag = "kind", rename_all = "kebab-case")]
+pub enum Event {
+    KeyPress { key: String },
+    MouseClick { x: f64, y: f64 },
+    Resized(Meters),
+    Closed,
+}
+
+#[derive(FromJs, IntoJs)]
+#[neon(tag = "type", content = "data")]
+pub enum Message {
+    Text(String),
+    Move(Point),
+    Quit,
+}
+
+#[neon::export]
+fn scale_shape(shape: Shape, factor: f64) -> Shape {
+    match shape {
+        Shape::Empty => Shape::Empty,
+        Shape::Circle(radius) => Shape::Circle(radius * factor),
+        Shape::Rectangle { width, height } => Shape::Rectangle {
+            width: width * factor,
+            height: height * factor,
+        },
+        Shape::Line(a, b) => Shape::Line(
+            Point(a.0 * factor, a.1 * factor),
+            Point(b.0 * factor, b.1 * factor),
+        ),
+    }
+}
+
+#[neon::export]
+fn echo_event(event: Event) -> Event {
+    event
+}
+
+#[neon::export]
+fn echo_message(message: Message) -> Message {
+    message
+}
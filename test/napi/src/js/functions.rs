@@ -1,5 +1,6 @@
 use neon::object::This;
 use neon::prelude::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 fn add1(mut cx: FunctionContext) -> JsResult<JsNumber> {
     let x = cx.argument::<JsNumber>(0)?.value(&mut cx);
@@ -147,3 +148,108 @@ pub fn is_construct(mut cx: FunctionContext) -> JsResult<JsObject> {
     this.set(&mut cx, "wasConstructed", construct)?;
     Ok(this)
 }
+
+pub fn return_js_closure(mut cx: FunctionContext) -> JsResult<JsFunction> {
+    let prefix = cx.argument::<JsString>(0)?.value(&mut cx);
+    let count = std::cell::Cell::new(0);
+
+    JsFunction::from_closure(&mut cx, move |mut cx| {
+        let suffix = cx.argument::<JsString>(0)?.value(&mut cx);
+
+        count.set(count.get() + 1);
+
+        Ok(cx.string(format!("{}{} ({})", prefix, suffix, count.get())))
+    })
+}
+
+pub fn return_panicking_closure(mut cx: FunctionContext) -> JsResult<JsFunction> {
+    let msg = cx.argument::<JsString>(0)?.value(&mut cx);
+
+    JsFunction::from_closure(
+        &mut cx,
+        move |_: FunctionContext| -> JsResult<JsUndefined> { panic!("{}", msg) },
+    )
+}
+
+static CLOSURES_DROPPED: AtomicUsize = AtomicUsize::new(0);
+
+struct DropCounter;
+
+impl Drop for DropCounter {
+    fn drop(&mut self) {
+        CLOSURES_DROPPED.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+pub fn return_counted_closure(mut cx: FunctionContext) -> JsResult<JsFunction> {
+    let counter = DropCounter;
+
+    JsFunction::from_closure(&mut cx, move |mut cx| {
+        let _ = &counter;
+        Ok(cx.undefined())
+    })
+}
+
+pub fn count_dropped_closures(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    Ok(cx.number(CLOSURES_DROPPED.load(Ordering::SeqCst) as f64))
+}
+
+pub fn return_named_js_function(mut cx: FunctionContext) -> JsResult<JsFunction> {
+    let name = cx.argument::<JsString>(0)?.value(&mut cx);
+    let length = cx.argument::<JsNumber>(1)?.value_u32(&mut cx)?;
+    let f = JsFunction::with_name(&mut cx, &name, add1)?;
+
+    f.set_length(&mut cx, length)?;
+
+    Ok(f)
+}
+
+pub fn set_function_length(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let f = cx.argument::<JsFunction>(0)?;
+    let length = cx.argument::<JsNumber>(1)?.value_u32(&mut cx)?;
+
+    f.set_length(&mut cx, length)?;
+
+    Ok(cx.undefined())
+}
+
+pub fn return_named_js_closure(mut cx: FunctionContext) -> JsResult<JsFunction> {
+    let name = cx.argument::<JsString>(0)?.value(&mut cx);
+    let greeting = format!("Hello from {}", name);
+
+    JsFunction::from_closure_with_name(&mut cx, &name, move |mut cx| Ok(cx.string(&greeting)))
+}
+
+pub fn add_with_length(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let a = cx.argument::<JsNumber>(0)?.value(&mut cx);
+    let b = cx.argument::<JsNumber>(1)?.value(&mut cx);
+
+    Ok(cx.number(a + b))
+}
+
+#[neon::export]
+fn exported_function(mut cx: FunctionContext) -> JsResult<JsString> {
+    Ok(cx.string("exported"))
+}
+
+#[neon::export(name = "exportedWithName")]
+fn exported_with_name(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let x = cx.argument::<JsNumber>(0)?.value(&mut cx);
+
+    Ok(cx.number(x * 2.0))
+}
+
+// Replaced by the `#[neon::main]` function
+#[neon::export]
+fn overridden_export(mut cx: FunctionContext) -> JsResult<JsString> {
+    Ok(cx.string("export"))
+}
+
+mod nested {
+    use neon::prelude::*;
+
+    #[neon::export]
+    fn nested_export(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+        Ok(cx.boolean(true))
+    }
+}
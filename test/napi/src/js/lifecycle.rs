@@ -0,0 +1,64 @@
+use std::sync::Mutex;
+use std::time::Duration;
+
+use neon::prelude::*;
+
+/// Messages logged by cleanup hooks of every instance of the module
+static CLEANUP_LOG: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+fn log(message: String) {
+    CLEANUP_LOG.lock().unwrap().push(message);
+}
+
+#[neon::export]
+fn on_cleanup_log(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let message = cx.argument::<JsString>(0)?.value(&mut cx);
+
+    cx.on_cleanup(move || log(message));
+
+    Ok(cx.undefined())
+}
+
+#[neon::export]
+fn on_cleanup_removed(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let message = cx.argument::<JsString>(0)?.value(&mut cx);
+    let hook = cx.on_cleanup(move || log(message));
+    let removed = hook.remove(&mut cx);
+
+    Ok(cx.boolean(removed))
+}
+
+#[neon::export]
+fn on_async_cleanup_log(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let message = cx.argument::<JsString>(0)?.value(&mut cx);
+
+    cx.on_async_cleanup(move || async move {
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        log(message);
+    });
+
+    Ok(cx.undefined())
+}
+
+#[neon::export]
+fn on_async_cleanup_removed(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let message = cx.argument::<JsString>(0)?.value(&mut cx);
+    let hook = cx.on_async_cleanup(move || async move { log(message) });
+    let removed = hook.remove(&mut cx);
+
+    Ok(cx.boolean(removed))
+}
+
+#[neon::export]
+fn take_cleanup_log(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let messages = std::mem::take(&mut *CLEANUP_LOG.lock().unwrap());
+    let log = cx.empty_array();
+
+    for (i, message) in messages.into_iter().enumerate() {
+        let message = cx.string(message);
+
+        log.set(&mut cx, i as u32, message)?;
+    }
+
+    Ok(log)
+}
@@ -39,3 +39,55 @@ pub fn accept_and_return_negative_js_number(mut cx: FunctionContext) -> JsResult
     let number: Handle<JsNumber> = cx.argument(0)?;
     Ok(number)
 }
+
+pub fn return_js_number_from_i32(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    Ok(JsNumber::from_i32(&mut cx, i32::MIN))
+}
+
+pub fn return_js_number_from_u32(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    Ok(JsNumber::from_u32(&mut cx, u32::MAX))
+}
+
+pub fn return_js_number_from_i64(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    Ok(JsNumber::from_i64(&mut cx, -(1 << 53)))
+}
+
+pub fn number_value_i32(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let n = cx.argument::<JsNumber>(0)?.value_i32(&mut cx)?;
+    Ok(cx.number(n))
+}
+
+pub fn number_value_u32(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let n = cx.argument::<JsNumber>(0)?.value_u32(&mut cx)?;
+    Ok(cx.number(n))
+}
+
+pub fn number_value_i64(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let n = cx.argument::<JsNumber>(0)?.value_i64(&mut cx)?;
+    Ok(JsNumber::from_i64(&mut cx, n))
+}
+
+pub fn number_value_i32_saturating(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let n = cx.argument::<JsNumber>(0)?.value_i32_saturating(&mut cx);
+    Ok(cx.number(n))
+}
+
+pub fn number_value_u32_saturating(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let n = cx.argument::<JsNumber>(0)?.value_u32_saturating(&mut cx);
+    Ok(cx.number(n))
+}
+
+pub fn number_value_i32_truncating(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let n = cx.argument::<JsNumber>(0)?.value_i32_truncating(&mut cx);
+    Ok(cx.number(n))
+}
+
+pub fn number_value_u32_truncating(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let n = cx.argument::<JsNumber>(0)?.value_u32_truncating(&mut cx);
+    Ok(cx.number(n))
+}
+
+pub fn number_value_i64_truncating(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let n = cx.argument::<JsNumber>(0)?.value_i64_truncating(&mut cx);
+    Ok(JsNumber::from_i64(&mut cx, n))
+}
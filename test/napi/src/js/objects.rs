@@ -1,4 +1,5 @@
 use neon::prelude::*;
+use neon::types::{JsDataView, JsTypedArray};
 
 pub fn return_js_global_object(mut cx: FunctionContext) -> JsResult<JsObject> {
     Ok(cx.global())
@@ -171,3 +172,98 @@ pub fn increment_buffer_with_borrow_mut(mut cx: FunctionContext) -> JsResult<JsU
     });
     Ok(cx.undefined())
 }
+
+pub fn return_typed_array(mut cx: FunctionContext) -> JsResult<JsTypedArray<u16>> {
+    JsTypedArray::new(&mut cx, 4)
+}
+
+pub fn return_typed_array_from_buffer(mut cx: FunctionContext) -> JsResult<JsTypedArray<u32>> {
+    let buf: Handle<JsArrayBuffer> = cx.argument(0)?;
+    let offset = cx.argument::<JsNumber>(1)?.value(&mut cx) as usize;
+    let len = cx.argument::<JsNumber>(2)?.value(&mut cx) as usize;
+
+    JsTypedArray::from_buffer(&mut cx, buf, offset, len)
+}
+
+pub fn read_typed_array_with_borrow(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let arr: Handle<JsTypedArray<f32>> = cx.argument(0)?;
+    let values = cx.borrow(&arr, |data| data.as_slice::<f32>().to_vec());
+    let result = cx.empty_array();
+
+    for (i, value) in values.into_iter().enumerate() {
+        let value = cx.number(value);
+        result.set(&mut cx, i as u32, value)?;
+    }
+
+    Ok(result)
+}
+
+pub fn increment_typed_array_with_borrow_mut(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let mut arr: Handle<JsTypedArray<i32>> = cx.argument(0)?;
+    cx.borrow_mut(&mut arr, |data| {
+        data.as_mut_slice::<i32>().iter_mut().for_each(|x| *x += 1);
+    });
+    Ok(cx.undefined())
+}
+
+pub fn get_typed_array_info(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let arr: Handle<JsTypedArray<u8>> = cx.argument(0)?;
+    let info = cx.empty_object();
+    let len = arr.len(&mut cx);
+    let offset = arr.offset(&mut cx);
+    let buffer = arr.buffer(&mut cx);
+    let len = cx.number(len as f64);
+    let offset = cx.number(offset as f64);
+
+    info.set(&mut cx, "length", len)?;
+    info.set(&mut cx, "offset", offset)?;
+    info.set(&mut cx, "buffer", buffer)?;
+
+    Ok(info)
+}
+
+pub fn return_data_view(mut cx: FunctionContext) -> JsResult<JsDataView> {
+    let buf: Handle<JsArrayBuffer> = cx.argument(0)?;
+    let offset = cx.argument::<JsNumber>(1)?.value(&mut cx) as usize;
+    let len = cx.argument::<JsNumber>(2)?.value(&mut cx) as usize;
+
+    JsDataView::new(&mut cx, buf, offset, len)
+}
+
+pub fn read_data_view_with_borrow(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let view: Handle<JsDataView> = cx.argument(0)?;
+    let bytes = cx.borrow(&view, |data| data.as_slice::<u8>().to_vec());
+    let result = cx.empty_array();
+
+    for (i, byte) in bytes.into_iter().enumerate() {
+        let byte = cx.number(byte);
+        result.set(&mut cx, i as u32, byte)?;
+    }
+
+    Ok(result)
+}
+
+pub fn write_data_view_with_borrow_mut(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let mut view: Handle<JsDataView> = cx.argument(0)?;
+    let x = cx.argument::<JsNumber>(1)?.value(&mut cx) as u16;
+    cx.borrow_mut(&mut view, |data| {
+        data.as_mut_slice::<u8>()[..2].copy_from_slice(&x.to_be_bytes());
+    });
+    Ok(cx.undefined())
+}
+
+pub fn get_data_view_info(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let view: Handle<JsDataView> = cx.argument(0)?;
+    let info = cx.empty_object();
+    let len = view.len(&mut cx);
+    let offset = view.offset(&mut cx);
+    let buffer = view.buffer(&mut cx);
+    let len = cx.number(len as f64);
+    let offset = cx.number(offset as f64);
+
+    info.set(&mut cx, "length", len)?;
+    info.set(&mut cx, "offset", offset)?;
+    info.set(&mut cx, "buffer", buffer)?;
+
+    Ok(info)
+}
@@ -0,0 +1,129 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::task::{self, Poll};
+use std::thread;
+use std::time::Duration;
+
+use neon::prelude::*;
+
+pub fn resolve_promise(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let value = cx.argument::<JsValue>(0)?;
+    let (deferred, promise) = cx.promise();
+
+    deferred.resolve(&mut cx, value);
+
+    Ok(promise)
+}
+
+pub fn reject_promise(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let value = cx.argument::<JsValue>(0)?;
+    let (deferred, promise) = cx.promise();
+
+    deferred.reject(&mut cx, value);
+
+    Ok(promise)
+}
+
+pub fn is_promise(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let value = cx.argument::<JsValue>(0)?;
+    let result = value.is_a::<JsPromise, _>(&mut cx);
+
+    Ok(cx.boolean(result))
+}
+
+pub fn promise_thread(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let n = cx.argument::<JsNumber>(0)?.value(&mut cx);
+    let channel = cx.channel();
+    let (deferred, promise) = cx.promise();
+
+    std::thread::spawn(move || {
+        let result = n * 2.0;
+
+        deferred.settle_with(&channel, move |mut cx| Ok(cx.number(result)));
+    });
+
+    Ok(promise)
+}
+
+pub fn promise_thread_throw(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let msg = cx.argument::<JsString>(0)?.value(&mut cx);
+    let channel = cx.channel();
+    let (deferred, promise) = cx.promise();
+
+    std::thread::spawn(move || {
+        deferred.settle_with(&channel, move |mut cx| {
+            cx.throw_error::<_, Handle<JsValue>>(msg)
+        });
+    });
+
+    Ok(promise)
+}
+
+pub fn leak_deferred(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let (deferred, promise) = cx.promise();
+
+    std::thread::spawn(move || drop(deferred));
+
+    Ok(promise)
+}
+
+/// A future that completes after sleeping on another thread.
+struct Sleep {
+    duration: Duration,
+    done: Option<Arc<AtomicBool>>,
+}
+
+fn sleep(duration: Duration) -> Sleep {
+    Sleep {
+        duration,
+        done: None,
+    }
+}
+
+impl Future for Sleep {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut task::Context) -> Poll<()> {
+        if let Some(done) = &self.done {
+            return if done.load(Ordering::SeqCst) {
+                Poll::Ready(())
+            } else {
+                Poll::Pending
+            };
+        }
+
+        let done = Arc::new(AtomicBool::new(false));
+        let duration = self.duration;
+        let waker = cx.waker().clone();
+
+        self.done = Some(done.clone());
+
+        thread::spawn(move || {
+            thread::sleep(duration);
+            done.store(true, Ordering::SeqCst);
+            waker.wake();
+        });
+
+        Poll::Pending
+    }
+}
+
+#[neon::export]
+async fn async_add(a: f64, b: f64) -> f64 {
+    sleep(Duration::from_millis(10)).await;
+    a + b
+}
+
+#[neon::export]
+async fn async_parse(text: String) -> Result<u32, std::num::ParseIntError> {
+    sleep(Duration::from_millis(10)).await;
+    text.parse()
+}
+
+#[neon::export]
+async fn async_panic(message: String) {
+    sleep(Duration::from_millis(10)).await;
+    panic!("{}", message)
+}
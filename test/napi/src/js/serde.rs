@@ -0,0 +1,87 @@
+use std::collections::{BTreeMap, HashMap};
+
+use neon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Order {
+    id: u64,
+    customer: Customer,
+    items: Vec<Item>,
+    notes: Option<String>,
+    tags: BTreeMap<String, String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Customer {
+    name: String,
+    vip: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Item(String, u32, f64);
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Shape {
+    Empty,
+    Circle(f64),
+    Line(f64, f64),
+    Rectangle { width: f64, height: f64 },
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Blob {
+    #[serde(with = "serde_bytes")]
+    data: Vec<u8>,
+}
+
+fn round_trip<'a, T: Serialize + for<'de> Deserialize<'de>>(
+    mut cx: FunctionContext<'a>,
+) -> JsResult<'a, JsValue> {
+    let value = cx.argument::<JsValue>(0)?;
+    let value: T = neon::serde::from_value(&mut cx, value)?;
+
+    neon::serde::to_value(&mut cx, &value)
+}
+
+#[neon::export]
+fn serde_round_trip_order(cx: FunctionContext) -> JsResult<JsValue> {
+    round_trip::<Order>(cx)
+}
+
+#[neon::export]
+fn serde_round_trip_shapes(cx: FunctionContext) -> JsResult<JsValue> {
+    round_trip::<Vec<Shape>>(cx)
+}
+
+#[neon::export]
+fn serde_round_trip_integers(cx: FunctionContext) -> JsResult<JsValue> {
+    round_trip::<Vec<i128>>(cx)
+}
+
+#[neon::export]
+fn serde_reverse_blob(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let value = cx.argument::<JsValue>(0)?;
+    let mut blob: Blob = neon::serde::from_value(&mut cx, value)?;
+
+    blob.data.reverse();
+    neon::serde::to_value(&mut cx, &blob)
+}
+
+#[neon::export]
+fn serde_large_integers(mut cx: FunctionContext) -> JsResult<JsValue> {
+    neon::serde::to_value(
+        &mut cx,
+        &(u64::MAX, i64::MIN, 1u64 << 53, -(1i64 << 53) + 1),
+    )
+}
+
+#[neon::export]
+fn serde_invalid_keys(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let mut map = HashMap::new();
+
+    map.insert((1, 2), "point");
+    neon::serde::to_value(&mut cx, &map)
+}
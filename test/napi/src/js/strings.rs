@@ -9,3 +9,116 @@ pub fn run_string_as_script(mut cx: FunctionContext) -> JsResult<JsValue> {
     let string_script = cx.argument::<JsString>(0)?;
     eval(&mut cx, string_script)
 }
+
+fn code_units<'a, C: Context<'a>, T: Into<f64> + Copy>(
+    cx: &mut C,
+    units: &[T],
+) -> JsResult<'a, JsArray> {
+    let arr = cx.empty_array();
+
+    for (i, unit) in units.iter().enumerate() {
+        let unit = cx.number(*unit);
+        arr.set(cx, i as u32, unit)?;
+    }
+
+    Ok(arr)
+}
+
+fn array_to_vec<'a, C: Context<'a>>(cx: &mut C, arr: Handle<JsArray>) -> NeonResult<Vec<f64>> {
+    arr.to_vec(cx)?
+        .into_iter()
+        .map(|v| Ok(v.downcast_or_throw::<JsNumber, _>(cx)?.value(cx)))
+        .collect()
+}
+
+pub fn string_from_utf16(mut cx: FunctionContext) -> JsResult<JsString> {
+    let arr = cx.argument::<JsArray>(0)?;
+    let units = array_to_vec(&mut cx, arr)?
+        .into_iter()
+        .map(|n| n as u16)
+        .collect::<Vec<_>>();
+
+    JsString::from_utf16(&mut cx, &units).or_throw(&mut cx)
+}
+
+pub fn string_from_latin1(mut cx: FunctionContext) -> JsResult<JsString> {
+    let arr = cx.argument::<JsArray>(0)?;
+    let bytes = array_to_vec(&mut cx, arr)?
+        .into_iter()
+        .map(|n| n as u8)
+        .collect::<Vec<_>>();
+
+    JsString::from_latin1(&mut cx, &bytes).or_throw(&mut cx)
+}
+
+pub fn string_value_utf16(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let units = cx.argument::<JsString>(0)?.value_utf16(&mut cx);
+
+    code_units(&mut cx, &units)
+}
+
+pub fn string_value_latin1(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let bytes = cx.argument::<JsString>(0)?.value_latin1(&mut cx);
+
+    code_units(&mut cx, &bytes)
+}
+
+pub fn string_write_utf16(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let s = cx.argument::<JsString>(0)?;
+    let size = cx.argument::<JsNumber>(1)?.value(&mut cx) as usize;
+    let mut buf = vec![0u16; size];
+    let len = s.write_utf16(&mut cx, &mut buf);
+
+    code_units(&mut cx, &buf[..len])
+}
+
+pub fn string_write_latin1(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let s = cx.argument::<JsString>(0)?;
+    let size = cx.argument::<JsNumber>(1)?.value(&mut cx) as usize;
+    let mut buf = vec![0u8; size];
+    let len = s.write_latin1(&mut cx, &mut buf);
+
+    code_units(&mut cx, &buf[..len])
+}
+
+pub fn string_size_utf16(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let size = cx.argument::<JsString>(0)?.size_utf16(&mut cx);
+
+    Ok(cx.number(size as f64))
+}
+
+pub fn string_write_utf8(mut cx: FunctionContext) -> JsResult<JsString> {
+    let s = cx.argument::<JsString>(0)?;
+    let size = cx.argument::<JsNumber>(1)?.value(&mut cx) as usize;
+    let mut buf = vec![0u8; size];
+    let len = s.write_utf8(&mut cx, &mut buf);
+    let written =
+        std::str::from_utf8(&buf[..len]).or_else(|err| cx.throw_error(err.to_string()))?;
+
+    Ok(cx.string(written))
+}
+
+pub fn string_value_into(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let strings = cx.argument::<JsArray>(0)?.to_vec(&mut cx)?;
+    let out = cx.empty_array();
+    let mut buf = String::new();
+
+    for (i, s) in strings.into_iter().enumerate() {
+        s.downcast_or_throw::<JsString, _>(&mut cx)?
+            .value_into(&mut cx, &mut buf);
+
+        let s = cx.string(&buf);
+        out.set(&mut cx, i as u32, s)?;
+    }
+
+    Ok(out)
+}
+
+pub fn string_value_small(mut cx: FunctionContext) -> JsResult<JsString> {
+    let buf = cx
+        .argument::<JsString>(0)?
+        .value_small::<_, [u8; 8]>(&mut cx);
+    let s = std::str::from_utf8(&buf).or_else(|err| cx.throw_error(err.to_string()))?;
+
+    Ok(cx.string(s))
+}
@@ -0,0 +1,73 @@
+use neon::prelude::*;
+use neon::types::JsSymbol;
+
+pub fn create_symbol(mut cx: FunctionContext) -> JsResult<JsSymbol> {
+    match cx.argument_opt(0) {
+        Some(desc) => {
+            let desc = desc
+                .downcast_or_throw::<JsString, _>(&mut cx)?
+                .value(&mut cx);
+            Ok(JsSymbol::with_description(&mut cx, desc))
+        }
+        None => Ok(JsSymbol::new(&mut cx)),
+    }
+}
+
+pub fn symbol_description(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let symbol = cx.argument::<JsSymbol>(0)?;
+
+    match symbol.description(&mut cx)? {
+        Some(desc) => Ok(cx.string(desc).upcast()),
+        None => Ok(cx.undefined().upcast()),
+    }
+}
+
+pub fn symbol_for(mut cx: FunctionContext) -> JsResult<JsSymbol> {
+    let key = cx.argument::<JsString>(0)?.value(&mut cx);
+
+    JsSymbol::for_key(&mut cx, key)
+}
+
+pub fn well_known_symbols(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let obj = cx.empty_object();
+    let iterator = JsSymbol::iterator(&mut cx)?;
+    let async_iterator = JsSymbol::async_iterator(&mut cx)?;
+    let to_string_tag = JsSymbol::to_string_tag(&mut cx)?;
+
+    obj.set(&mut cx, "iterator", iterator)?;
+    obj.set(&mut cx, "asyncIterator", async_iterator)?;
+    obj.set(&mut cx, "toStringTag", to_string_tag)?;
+
+    Ok(obj)
+}
+
+pub fn get_symbol_property(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let key = cx.argument::<JsSymbol>(1)?;
+
+    obj.get(&mut cx, key)
+}
+
+pub fn tagged_iterable(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let array = cx.argument::<JsArray>(0)?;
+    let tag = JsSymbol::to_string_tag(&mut cx)?;
+    let tag_value = cx.string("Counter");
+    let iterator = JsSymbol::iterator(&mut cx)?;
+    let values_iterator = array
+        .get(&mut cx, iterator)?
+        .downcast_or_throw::<JsFunction, _>(&mut cx)?;
+    let hidden = JsSymbol::with_description(&mut cx, "hidden");
+    let hidden_value = cx.boolean(true);
+
+    array.set(&mut cx, tag, tag_value)?;
+    array.set(&mut cx, iterator, values_iterator)?;
+    array.set(&mut cx, hidden, hidden_value)?;
+
+    Ok(array)
+}
+
+pub fn is_symbol(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let val: Handle<JsValue> = cx.argument(0)?;
+    let result = val.is_a::<JsSymbol, _>(&mut cx);
+    Ok(cx.boolean(result))
+}
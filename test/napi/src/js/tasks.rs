@@ -0,0 +1,265 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::Duration;
+
+use neon::prelude::*;
+use neon::task::CancellationToken;
+
+struct SuccessTask;
+
+impl Task for SuccessTask {
+    type Output = i32;
+    type Error = String;
+    type JsEvent = JsNumber;
+
+    fn perform(&self) -> Result<Self::Output, Self::Error> {
+        Ok(17)
+    }
+
+    fn complete(
+        self,
+        mut cx: TaskContext,
+        result: Result<Self::Output, Self::Error>,
+    ) -> JsResult<Self::JsEvent> {
+        Ok(cx.number(result.unwrap()))
+    }
+}
+
+#[neon::export]
+fn perform_async_task(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let f = cx.argument::<JsFunction>(0)?;
+    SuccessTask.schedule(f);
+    Ok(cx.undefined())
+}
+
+struct FailureTask;
+
+impl Task for FailureTask {
+    type Output = i32;
+    type Error = String;
+    type JsEvent = JsNumber;
+
+    fn perform(&self) -> Result<Self::Output, Self::Error> {
+        Err(String::from("I am a failing task"))
+    }
+
+    fn complete(
+        self,
+        mut cx: TaskContext,
+        result: Result<Self::Output, Self::Error>,
+    ) -> JsResult<Self::JsEvent> {
+        cx.throw_error(result.unwrap_err())
+    }
+}
+
+#[neon::export]
+fn perform_failing_task(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let f = cx.argument::<JsFunction>(0)?;
+    FailureTask.schedule(f);
+    Ok(cx.undefined())
+}
+
+struct PanicTask;
+
+impl Task for PanicTask {
+    type Output = ();
+    type Error = ();
+    type JsEvent = JsUndefined;
+
+    fn perform(&self) -> Result<Self::Output, Self::Error> {
+        panic!("I am a panicking task");
+    }
+
+    fn complete(
+        self,
+        mut cx: TaskContext,
+        _result: Result<Self::Output, Self::Error>,
+    ) -> JsResult<Self::JsEvent> {
+        Ok(cx.undefined())
+    }
+}
+
+#[neon::export]
+fn perform_panicking_task(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let f = cx.argument::<JsFunction>(0)?;
+    PanicTask.schedule(f);
+    Ok(cx.undefined())
+}
+
+/// Reports whether `perform` ran on a thread other than the JavaScript thread
+struct ThreadTask(thread::ThreadId);
+
+impl Task for ThreadTask {
+    type Output = bool;
+    type Error = ();
+    type JsEvent = JsBoolean;
+
+    fn perform(&self) -> Result<Self::Output, Self::Error> {
+        Ok(thread::current().id() != self.0)
+    }
+
+    fn complete(
+        self,
+        mut cx: TaskContext,
+        result: Result<Self::Output, Self::Error>,
+    ) -> JsResult<Self::JsEvent> {
+        Ok(cx.boolean(result.unwrap() && thread::current().id() == self.0))
+    }
+}
+
+#[neon::export]
+fn perform_thread_task(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let f = cx.argument::<JsFunction>(0)?;
+    ThreadTask(thread::current().id()).schedule(f);
+    Ok(cx.undefined())
+}
+
+fn fibonacci(n: u64) -> u64 {
+    match n {
+        0 => 0,
+        1 => 1,
+        n => fibonacci(n - 1) + fibonacci(n - 2),
+    }
+}
+
+#[neon::export]
+fn fibonacci_promise(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let n = cx.argument::<JsNumber>(0)?.value(&mut cx) as u64;
+    let promise = cx
+        .task(move || fibonacci(n))
+        .promise(|mut cx, n| Ok(cx.number(n as f64)));
+
+    Ok(promise)
+}
+
+#[neon::export]
+fn task_reject_promise(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let message = cx.argument::<JsString>(0)?.value(&mut cx);
+    let promise = cx
+        .task(move || message)
+        .promise(|mut cx, message| cx.throw_error::<_, Handle<JsValue>>(message));
+
+    Ok(promise)
+}
+
+#[neon::export]
+fn task_panic_promise(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let promise = cx
+        .task(|| panic!("I am a panicking closure"))
+        .promise(|mut cx, ()| Ok(cx.undefined()));
+
+    Ok(promise)
+}
+
+#[neon::export]
+fn fibonacci_and_then(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let n = cx.argument::<JsNumber>(0)?.value(&mut cx) as u64;
+    let callback = cx.argument::<JsFunction>(1)?.root(&mut cx);
+
+    cx.task(move || fibonacci(n)).and_then(move |mut cx, n| {
+        let callback = callback.into_inner(&mut cx);
+        let this = cx.undefined();
+        let args = vec![cx.null().upcast::<JsValue>(), cx.number(n as f64).upcast()];
+
+        callback.call(&mut cx, this, args)?;
+
+        Ok(())
+    });
+
+    Ok(cx.undefined())
+}
+
+/// Set when the task scheduled by `sleep_cancellable` starts executing
+static SLEEP_STARTED: AtomicBool = AtomicBool::new(false);
+
+/// Sleeps for `ms` milliseconds in steps of one millisecond, stopping early if aborted
+#[neon::export]
+fn sleep_cancellable(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let ms = cx.argument::<JsNumber>(0)?.value(&mut cx) as u64;
+    let signal = cx.argument::<JsObject>(1)?;
+    let token = CancellationToken::from_signal(&mut cx, signal)?;
+    let cancelled = token.clone();
+
+    SLEEP_STARTED.store(false, Ordering::SeqCst);
+
+    let promise = cx
+        .task(move || {
+            SLEEP_STARTED.store(true, Ordering::SeqCst);
+
+            (0..ms)
+                .take_while(|_| {
+                    thread::sleep(Duration::from_millis(1));
+                    !cancelled.is_cancelled()
+                })
+                .count()
+        })
+        .cancel_with(token)
+        .promise(|mut cx, slept| Ok(cx.number(slept as f64)));
+
+    Ok(promise)
+}
+
+struct SleepTask {
+    ms: u64,
+    token: CancellationToken,
+}
+
+impl Task for SleepTask {
+    type Output = usize;
+    type Error = ();
+    type JsEvent = JsNumber;
+
+    fn perform(&self) -> Result<Self::Output, Self::Error> {
+        SLEEP_STARTED.store(true, Ordering::SeqCst);
+
+        Ok((0..self.ms)
+            .take_while(|_| {
+                thread::sleep(Duration::from_millis(1));
+                !self.token.is_cancelled()
+            })
+            .count())
+    }
+
+    fn complete(
+        self,
+        mut cx: TaskContext,
+        result: Result<Self::Output, Self::Error>,
+    ) -> JsResult<Self::JsEvent> {
+        Ok(cx.number(result.unwrap() as f64))
+    }
+}
+
+/// Like `sleep_cancellable`, but scheduled as a `Task` that calls `callback`
+#[neon::export]
+fn sleep_task_cancellable(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let ms = cx.argument::<JsNumber>(0)?.value(&mut cx) as u64;
+    let signal = cx.argument::<JsObject>(1)?;
+    let callback = cx.argument::<JsFunction>(2)?;
+    let token = CancellationToken::from_signal(&mut cx, signal)?;
+
+    SLEEP_STARTED.store(false, Ordering::SeqCst);
+
+    SleepTask {
+        ms,
+        token: token.clone(),
+    }
+    .schedule_with(callback, token);
+
+    Ok(cx.undefined())
+}
+
+#[neon::export]
+fn sleep_started() -> bool {
+    SLEEP_STARTED.load(Ordering::SeqCst)
+}
+
+/// Blocks a thread of the libuv thread pool for `ms` milliseconds
+#[neon::export]
+fn block_thread_pool(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let ms = cx.argument::<JsNumber>(0)?.value(&mut cx) as u64;
+    let promise = cx
+        .task(move || thread::sleep(Duration::from_millis(ms)))
+        .promise(|mut cx, ()| Ok(cx.undefined()));
+
+    Ok(promise)
+}
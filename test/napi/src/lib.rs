@@ -2,27 +2,42 @@ use neon::prelude::*;
 
 mod js {
     pub mod arrays;
+    pub mod async_context;
+    pub mod bigint;
     pub mod boxed;
+    pub mod classes;
     pub mod coercions;
     pub mod date;
     pub mod errors;
+    pub mod executor;
+    pub mod extract;
     pub mod functions;
+    pub mod lifecycle;
     pub mod numbers;
     pub mod objects;
+    pub mod promises;
+    pub mod serde;
     pub mod strings;
+    pub mod symbols;
+    pub mod tasks;
     pub mod threads;
     pub mod types;
 }
 
 use js::arrays::*;
+use js::bigint::*;
 use js::boxed::*;
+use js::classes::*;
 use js::coercions::*;
 use js::date::*;
 use js::errors::*;
+use js::extract::*;
 use js::functions::*;
 use js::numbers::*;
 use js::objects::*;
+use js::promises::*;
 use js::strings::*;
+use js::symbols::*;
 use js::threads::*;
 use js::types::*;
 
@@ -116,8 +131,21 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
 
     cx.export_function("add1", add1)?;
 
+    let overridden_export = cx.string("main");
+    cx.export_value("overridden_export", overridden_export)?;
+
     cx.export_function("return_js_string", return_js_string)?;
     cx.export_function("run_string_as_script", run_string_as_script)?;
+    cx.export_function("string_from_utf16", string_from_utf16)?;
+    cx.export_function("string_from_latin1", string_from_latin1)?;
+    cx.export_function("string_value_utf16", string_value_utf16)?;
+    cx.export_function("string_value_latin1", string_value_latin1)?;
+    cx.export_function("string_write_utf16", string_write_utf16)?;
+    cx.export_function("string_write_latin1", string_write_latin1)?;
+    cx.export_function("string_size_utf16", string_size_utf16)?;
+    cx.export_function("string_write_utf8", string_write_utf8)?;
+    cx.export_function("string_value_into", string_value_into)?;
+    cx.export_function("string_value_small", string_value_small)?;
 
     cx.export_function("return_js_number", return_js_number)?;
     cx.export_function("return_large_js_number", return_large_js_number)?;
@@ -140,9 +168,28 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
         "accept_and_return_negative_js_number",
         accept_and_return_negative_js_number,
     )?;
+    cx.export_function("return_js_number_from_i32", return_js_number_from_i32)?;
+    cx.export_function("return_js_number_from_u32", return_js_number_from_u32)?;
+    cx.export_function("return_js_number_from_i64", return_js_number_from_i64)?;
+    cx.export_function("number_value_i32", number_value_i32)?;
+    cx.export_function("number_value_u32", number_value_u32)?;
+    cx.export_function("number_value_i64", number_value_i64)?;
+    cx.export_function("number_value_i32_saturating", number_value_i32_saturating)?;
+    cx.export_function("number_value_u32_saturating", number_value_u32_saturating)?;
+    cx.export_function("number_value_i32_truncating", number_value_i32_truncating)?;
+    cx.export_function("number_value_u32_truncating", number_value_u32_truncating)?;
+    cx.export_function("number_value_i64_truncating", number_value_i64_truncating)?;
 
     cx.export_function("return_js_function", return_js_function)?;
     cx.export_function("call_js_function", call_js_function)?;
+    cx.export_function("return_named_js_function", return_named_js_function)?;
+    cx.export_function("set_function_length", set_function_length)?;
+    cx.export_function_with_length("add_with_length", 2, add_with_length)?;
+    cx.export_function("return_js_closure", return_js_closure)?;
+    cx.export_function("return_named_js_closure", return_named_js_closure)?;
+    cx.export_function("return_panicking_closure", return_panicking_closure)?;
+    cx.export_function("return_counted_closure", return_counted_closure)?;
+    cx.export_function("count_dropped_closures", count_dropped_closures)?;
     cx.export_function("construct_js_function", construct_js_function)?;
     cx.export_function("num_arguments", num_arguments)?;
     cx.export_function("return_this", return_this)?;
@@ -199,6 +246,25 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
         increment_buffer_with_borrow_mut,
     )?;
 
+    cx.export_function("return_typed_array", return_typed_array)?;
+    cx.export_function(
+        "return_typed_array_from_buffer",
+        return_typed_array_from_buffer,
+    )?;
+    cx.export_function("read_typed_array_with_borrow", read_typed_array_with_borrow)?;
+    cx.export_function(
+        "increment_typed_array_with_borrow_mut",
+        increment_typed_array_with_borrow_mut,
+    )?;
+    cx.export_function("get_typed_array_info", get_typed_array_info)?;
+    cx.export_function("return_data_view", return_data_view)?;
+    cx.export_function("read_data_view_with_borrow", read_data_view_with_borrow)?;
+    cx.export_function(
+        "write_data_view_with_borrow_mut",
+        write_data_view_with_borrow_mut,
+    )?;
+    cx.export_function("get_data_view_info", get_data_view_info)?;
+
     cx.export_function("create_date", create_date)?;
     cx.export_function("get_date_value", get_date_value)?;
     cx.export_function("check_date_is_invalid", check_date_is_invalid)?;
@@ -209,8 +275,27 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("create_date_from_value", create_date_from_value)?;
     cx.export_function("create_and_get_invalid_date", create_and_get_invalid_date)?;
 
+    cx.export_function("bigint_from_i64", bigint_from_i64)?;
+    cx.export_function("bigint_from_u64", bigint_from_u64)?;
+    cx.export_function("bigint_from_i128", bigint_from_i128)?;
+    cx.export_function("bigint_from_u128", bigint_from_u128)?;
+    cx.export_function("bigint_from_digits_le", bigint_from_digits_le)?;
+    cx.export_function("bigint_to_i64", bigint_to_i64)?;
+    cx.export_function("bigint_to_u64", bigint_to_u64)?;
+    cx.export_function("bigint_to_i128", bigint_to_i128)?;
+    cx.export_function("bigint_to_u128", bigint_to_u128)?;
+    cx.export_function("bigint_to_digits_le", bigint_to_digits_le)?;
+
+    cx.export_function("create_symbol", create_symbol)?;
+    cx.export_function("symbol_description", symbol_description)?;
+    cx.export_function("symbol_for", symbol_for)?;
+    cx.export_function("well_known_symbols", well_known_symbols)?;
+    cx.export_function("get_symbol_property", get_symbol_property)?;
+    cx.export_function("tagged_iterable", tagged_iterable)?;
+
     cx.export_function("is_array", is_array)?;
     cx.export_function("is_array_buffer", is_array_buffer)?;
+    cx.export_function("is_bigint", is_bigint)?;
     cx.export_function("is_boolean", is_boolean)?;
     cx.export_function("is_buffer", is_buffer)?;
     cx.export_function("is_error", is_error)?;
@@ -218,12 +303,19 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("is_number", is_number)?;
     cx.export_function("is_object", is_object)?;
     cx.export_function("is_string", is_string)?;
+    cx.export_function("is_symbol", is_symbol)?;
     cx.export_function("is_undefined", is_undefined)?;
     cx.export_function("strict_equals", strict_equals)?;
 
     cx.export_function("new_error", new_error)?;
     cx.export_function("new_type_error", new_type_error)?;
     cx.export_function("new_range_error", new_range_error)?;
+    cx.export_function("new_syntax_error", new_syntax_error)?;
+    cx.export_function("new_error_with_code", new_error_with_code)?;
+    cx.export_function("throw_error_with_code", throw_error_with_code)?;
+    cx.export_function("throw_error_with_properties", throw_error_with_properties)?;
+    cx.export_function("capture_error_info", capture_error_info)?;
+    cx.export_function("rethrow_error_info", rethrow_error_info)?;
     cx.export_function("throw_error", throw_error)?;
     cx.export_function("downcast_error", downcast_error)?;
 
@@ -258,5 +350,24 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("leak_channel", leak_channel)?;
     cx.export_function("drop_global_queue", drop_global_queue)?;
 
+    cx.export_function("resolve_promise", resolve_promise)?;
+    cx.export_function("reject_promise", reject_promise)?;
+    cx.export_function("is_promise", is_promise)?;
+    cx.export_function("promise_thread", promise_thread)?;
+    cx.export_function("promise_thread_throw", promise_thread_throw)?;
+    cx.export_function("leak_deferred", leak_deferred)?;
+
+    cx.export_class::<JsUser>("User")?;
+    cx.export_class::<JsPanickyAllocator>("PanickyAllocator")?;
+    cx.export_class::<JsPanickyConstructor>("PanickyConstructor")?;
+    cx.export_class::<JsCounter>("Counter")?;
+    cx.export_class::<JsValidated>("Validated")?;
+    cx.export_class::<JsAccount>("Account")?;
+    cx.export_function("counter_value", counter_value)?;
+    cx.export_function("is_counter", is_counter)?;
+
+    cx.export_function("extract_values", extract_values)?;
+    cx.export_function("extract_from_value", extract_from_value)?;
+
     Ok(())
 }